@@ -12,6 +12,14 @@ struct SharedState {
     volume:    f32,
     phase:     f32,
     sample_rate: f32,
+    /// Band noise level, 0 = off
+    noise:     f32,
+    /// xorshift state for the noise and the low-pass that softens it
+    seed:      u32,
+    hiss:      f32,
+    /// QSB depth and the phase of its slow fade cycle
+    fading:    f32,
+    fade_phase: f32,
 }
 
 pub struct CpalAudio {
@@ -36,6 +44,11 @@ impl CpalAudio {
             volume,
             phase: 0.0,
             sample_rate: sr,
+            noise: 0.0,
+            seed: 0x2545_f491,
+            hiss: 0.0,
+            fading: 0.0,
+            fade_phase: 0.0,
         }));
 
         let st = Arc::clone(&state);
@@ -66,13 +79,29 @@ where S: cpal::Sample + cpal::SizedSample + cpal::FromSample<f32>
             for frame in data.chunks_mut(ch) {
                 let sample = if s.key_down {
                     // Sine with soft envelope (immediate for CW feel)
-                    let v = (s.phase * 2.0 * std::f32::consts::PI).sin() * s.volume;
+                    let mut v = (s.phase * 2.0 * std::f32::consts::PI).sin() * s.volume;
+                    if s.fading > 0.0 {
+                        // One fade about every 7 s
+                        s.fade_phase = (s.fade_phase + 0.15 / s.sample_rate) % 1.0;
+                        let dip = 0.5 - 0.5 * (s.fade_phase * 2.0 * std::f32::consts::PI).cos();
+                        v *= 1.0 - s.fading * dip;
+                    }
                     s.phase = (s.phase + step) % 1.0;
                     v
                 } else {
                     s.phase = 0.0;
                     0.0
                 };
+                let sample = if s.noise > 0.0 {
+                    s.seed ^= s.seed << 13;
+                    s.seed ^= s.seed >> 17;
+                    s.seed ^= s.seed << 5;
+                    let white = s.seed as f32 / u32::MAX as f32 * 2.0 - 1.0;
+                    s.hiss = 0.7 * s.hiss + 0.3 * white;
+                    (sample + s.hiss * s.noise).clamp(-1.0, 1.0)
+                } else {
+                    sample
+                };
                 let out = S::from_sample(sample);
                 for smp in frame.iter_mut() { *smp = out; }
             }
@@ -111,4 +140,10 @@ impl AudioOutput for CpalAudio {
     fn set_volume(&mut self, vol: f32) {
         self.state.lock().unwrap().volume = vol;
     }
+    fn set_noise(&mut self, level: f32) {
+        self.state.lock().unwrap().noise = level;
+    }
+    fn set_fading(&mut self, depth: f32) {
+        self.state.lock().unwrap().fading = depth;
+    }
 }
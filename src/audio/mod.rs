@@ -12,6 +12,10 @@ pub trait AudioOutput: Send {
     fn tone_off(&mut self) -> Result<()>;
     fn set_frequency(&mut self, hz: f32);
     fn set_volume(&mut self, vol: f32);
+    /// Band noise under the tone, 0 = silent (set while a SIM over plays)
+    fn set_noise(&mut self, level: f32);
+    /// QSB: slow fading of the tone, 0 = steady, 1 = fades out completely
+    fn set_fading(&mut self, depth: f32);
 }
 
 // ── cpal backend ─────────────────────────────────────────────────────────────
@@ -32,6 +36,8 @@ impl AudioOutput for NullAudio {
     fn tone_off(&mut self) -> Result<()> { Ok(()) }
     fn set_frequency(&mut self, _hz: f32)  {}
     fn set_volume(&mut self,    _vol: f32) {}
+    fn set_noise(&mut self,   _level: f32) {}
+    fn set_fading(&mut self,  _depth: f32) {}
 }
 
 /// Factory: returns the best available backend
@@ -1,5 +1,5 @@
 // src/config.rs  —  Runtime configuration (CLI + TOML)
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -8,6 +8,11 @@ use std::path::PathBuf;
 /// Users can write it out with:  cw-qso-sim --write-config
 pub const DEFAULT_CONFIG_TOML: &str = include_str!("../config.toml.example");
 
+/// A replay without a clock of its own runs at a minute of the year from
+/// 2024-01-01 00:00 UTC drawn from the seed, so its band conditions vary
+const SEED_EPOCH: u64 = 1_704_067_200;
+const SEED_MINUTES: u64 = 366 * 1440;
+
 // ── CLI ───────────────────────────────────────────────────────────────────────
 #[derive(Parser, Debug)]
 #[command(
@@ -45,10 +50,14 @@ pub struct Cli {
     #[arg(long)]
     pub who_starts: Option<WhoStarts>,
 
-    /// QSO style: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | random
+    /// QSO style: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | cq-ww-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | skcc-sprint | naqcc-sprint | fists-sprint | radiogram | vhf-contest | random
     #[arg(long)]
     pub style: Option<QsoStyle>,
 
+    /// Band: 160m | 80m | 60m | 40m | 30m | 20m | 17m | 15m | 12m | 10m (default: 20m)
+    #[arg(long)]
+    pub band: Option<Band>,
+
     /// Your operator name for cwt_contest exchange (e.g. HANS)
     #[arg(long)]
     pub cwt_name: Option<String>,
@@ -57,10 +66,54 @@ pub struct Cli {
     #[arg(long)]
     pub cwt_nr: Option<String>,
 
+    /// Your state, province or country for sst-contest and the SKCC / NAQCC / FISTS sprints (e.g. MA, ON, DL)
+    #[arg(long)]
+    pub my_spc: Option<String>,
+
     /// Your DARC DOK for darc-cw-contest (e.g. P53).  Use NM if not a DARC member.
     #[arg(long)]
     pub my_dok: Option<String>,
 
+    /// Your SKCC number incl. C/T/S suffix for skcc-sprint (e.g. 12345T).  Use NONE if not a member.
+    #[arg(long)]
+    pub my_skcc: Option<String>,
+
+    /// Your NAQCC number for naqcc-sprint (e.g. 4567).  Non-members send their power (e.g. 5W).
+    #[arg(long)]
+    pub my_naqcc: Option<String>,
+
+    /// Your FISTS number for fists-sprint (e.g. 17001).  Non-members send their power (e.g. 100W).
+    #[arg(long)]
+    pub my_fists: Option<String>,
+
+    /// Your 6-character Maidenhead locator (e.g. JO62QM) for vhf-contest exchanges and QRB
+    #[arg(long)]
+    pub my_locator: Option<String>,
+
+    /// Draw SIM stations from a MASTER.SCP or N1MM call-history file instead of generating them
+    #[arg(long, value_name = "FILE")]
+    pub call_pool: Option<PathBuf>,
+
+    /// Random seed: the same seed and settings give the same stations, exchanges, typos and delays
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Session clock, e.g. 2024-06-15T14:00 (UTC): sets the band conditions and radiogram dates
+    #[arg(long, value_name = "TIME")]
+    pub utc: Option<String>,
+
+    /// Exercise pack: a TOML file bundling a seed with style, speed and other settings
+    #[arg(long, value_name = "FILE")]
+    pub exercise: Option<PathBuf>,
+
+    /// Every SIM station sends at the configured speed, delays and typo rate (no operator personalities)
+    #[arg(long)]
+    pub no_personalities: bool,
+
+    /// Draw every character, word and station uniformly — ignore the review schedule
+    #[arg(long)]
+    pub no_adapt: bool,
+
     /// Keyer adapter: auto | vband | attiny85 | rp-pico2 | arduino-nano | arduino-uno | esp32 | esp8266 | winkeyer | keyboard
     #[arg(long)]
     pub adapter: Option<AdapterType>,
@@ -102,6 +155,14 @@ pub struct Cli {
     #[arg(long, action)]
     pub print_config: bool,
 
+    /// Print practice progress from the history of past sessions and exit
+    #[arg(long, action)]
+    pub stats: bool,
+
+    /// Export the practice history to FILE (CSV, or JSON for *.json) and exit
+    #[arg(long, value_name = "FILE")]
+    pub export: Option<PathBuf>,
+
     /// Demo mode: play a complete QSO automatically (no keyer needed), then
     /// wait for ESC to exit.  Useful to preview a contest style before practising.
     #[arg(long, action)]
@@ -113,11 +174,40 @@ pub struct Cli {
     #[arg(long, action)]
     pub no_decode: bool,
 
+    /// Head-copy training: hide the decode (--no-decode) and quiz the SIM's
+    /// name, QTH, rig, RST, serial and DOK when the session ends
+    #[arg(long, action)]
+    pub head_copy: bool,
+
     /// Keyer-only mode: display your decoded keying, no QSO simulation.
     /// All keyer, sidetone, and decoder options still apply.
     #[arg(long, action)]
     pub keyer_only: bool,
 
+    /// Practice mode instead of a QSO: koch | copy | rufz | send | icr
+    #[arg(long, value_name = "MODE")]
+    pub practice: Option<PracticeMode>,
+
+    /// What copy practice sends: calls | groups | numbers | phrases | text
+    #[arg(long, value_name = "KIND")]
+    pub copy_kind: Option<CopyKind>,
+
+    /// Koch trainer: start at this lesson (1 = K M) instead of the saved one
+    #[arg(long, value_name = "N")]
+    pub koch_lesson: Option<usize>,
+
+    /// What send practice shows to key: calls | groups | numbers | phrases | text
+    #[arg(long, value_name = "KIND")]
+    pub send_kind: Option<CopyKind>,
+
+    /// Send practice: key the lines of this text file instead
+    #[arg(long, value_name = "FILE")]
+    pub send_file: Option<PathBuf>,
+
+    /// What the ICR drill sends: chars | words
+    #[arg(long, value_name = "KIND")]
+    pub icr_kind: Option<IcrKind>,
+
     /// Print help (translated when --lang is set)
     #[arg(short = 'h', long = "help", action = clap::ArgAction::SetTrue)]
     pub help: bool,
@@ -130,7 +220,22 @@ pub enum WhoStarts { Me, Sim }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
 #[serde(rename_all = "snake_case")]
-pub enum QsoStyle { Ragchew, Contest, DxPileup, DarcCwContest, MwcContest, CwtContest, WwaContest, WpxContest, QttAward, SstContest, CqDx, Pota, Sota, Tota, Cota, Random }
+pub enum QsoStyle { Ragchew, Contest, DxPileup, DarcCwContest, MwcContest, CwtContest, WwaContest, WpxContest, CqWwContest, QttAward, SstContest, CqDx, Pota, Sota, Tota, Cota, SkccSprint, NaqccSprint, FistsSprint, Radiogram, VhfContest, Random }
+
+/// HF amateur band the QSOs take place on
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+pub enum Band {
+    #[serde(rename = "160m")] #[value(name = "160m")] B160,
+    #[serde(rename = "80m")]  #[value(name = "80m")]  B80,
+    #[serde(rename = "60m")]  #[value(name = "60m")]  B60,
+    #[serde(rename = "40m")]  #[value(name = "40m")]  B40,
+    #[serde(rename = "30m")]  #[value(name = "30m")]  B30,
+    #[serde(rename = "20m")]  #[value(name = "20m")]  B20,
+    #[serde(rename = "17m")]  #[value(name = "17m")]  B17,
+    #[serde(rename = "15m")]  #[value(name = "15m")]  B15,
+    #[serde(rename = "12m")]  #[value(name = "12m")]  B12,
+    #[serde(rename = "10m")]  #[value(name = "10m")]  B10,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
 #[serde(rename_all = "snake_case")]
@@ -179,6 +284,32 @@ pub enum AdapterType {
 #[serde(rename_all = "snake_case")]
 pub enum PaddleMode { IambicA, IambicB, Straight }
 
+/// Practice drills that run instead of a QSO
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum PracticeMode {
+    /// Koch method: copy random groups, one new character per lesson
+    Koch,
+    /// Copy random text and see a character-by-character diff
+    Copy,
+    /// Callsign speed trainer: 50 calls, speed follows your copy
+    Rufz,
+    /// Key a shown text on the paddle and see what was decoded
+    Send,
+    /// Instant character recognition: type each character as fast as you can
+    Icr,
+}
+
+/// What copy practice sends, or send practice shows
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyKind { Calls, Groups, Numbers, Phrases, Text }
+
+/// What the ICR drill sends
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum IcrKind { Chars, Words }
+
 // ── TOML file structure ───────────────────────────────────────────────────────
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FileConfig {
@@ -186,6 +317,18 @@ pub struct FileConfig {
     pub morse:   Option<MorseCfg>,
     pub keyer:   Option<KeyerCfg>,
     pub qso:     Option<QsoCfg>,
+    pub practice: Option<PracticeCfg>,
+    /// Only in exercise packs
+    pub exercise: Option<ExerciseCfg>,
+}
+
+/// `[exercise]` section of an exercise pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExerciseCfg {
+    pub title: Option<String>,
+    pub seed:  Option<u64>,
+    /// Session clock, "2024-06-15T14:00" (UTC)
+    pub utc:   Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -219,12 +362,29 @@ pub struct KeyerCfg {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QsoCfg {
     pub style:        Option<QsoStyle>,
+    pub band:         Option<Band>,
     pub min_delay_ms: Option<u64>,
     pub max_delay_ms: Option<u64>,
     pub typo_rate:    Option<f64>,
     pub cwt_name:     Option<String>,
     pub cwt_nr:       Option<String>,
+    pub my_spc:       Option<String>,
     pub my_dok:       Option<String>,
+    pub my_skcc:      Option<String>,
+    pub my_naqcc:     Option<String>,
+    pub my_fists:     Option<String>,
+    pub my_locator:   Option<String>,
+    pub call_pool:    Option<PathBuf>,
+    pub personalities: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PracticeCfg {
+    pub copy_kind: Option<CopyKind>,
+    pub send_kind: Option<CopyKind>,
+    pub send_file: Option<PathBuf>,
+    pub icr_kind:  Option<IcrKind>,
+    pub adapt:     Option<bool>,
 }
 
 // ── Resolved / merged config ──────────────────────────────────────────────────
@@ -247,6 +407,8 @@ pub struct AppConfig {
     pub port:           String,
     pub midi_port:      String,
     pub qso_style:      QsoStyle,
+    /// Band the QSOs take place on: propagation, station mix, speed and frequency
+    pub band:           Band,
     pub min_delay_ms:   u64,
     pub max_delay_ms:   u64,
     pub typo_rate:      f64,
@@ -256,12 +418,51 @@ pub struct AppConfig {
     pub demo:           bool,
     /// User's CWT member number or state/country (e.g. "1234" or "DL")
     pub cwt_nr:         String,
+    /// User's state, province or country for SST and the SKCC / NAQCC / FISTS
+    /// sprints (e.g. "MA", "DL"); filled in at load when not set
+    pub my_spc:         String,
     /// User's own DARC DOK for darc-cw-contest (e.g. "P53", or "NM" for non-members)
     pub my_dok:         String,
+    /// User's SKCC number with C/T/S suffix for skcc-sprint (e.g. "12345T", or "NONE")
+    pub my_skcc:        String,
+    /// User's NAQCC number for naqcc-sprint, or power for non-members (e.g. "5W")
+    pub my_naqcc:       String,
+    /// User's FISTS number for fists-sprint, or power for non-members (e.g. "100W")
+    pub my_fists:       String,
+    /// User's Maidenhead locator (e.g. "JO62QM"); empty = unknown, no QRB
+    pub my_locator:     String,
+    /// MASTER.SCP / N1MM call-history file to draw SIM stations from; None = generate
+    pub call_pool:      Option<PathBuf>,
+    /// SIM operators vary speed, delays, wording and fist (personalities.toml)
+    pub personalities:  bool,
+    /// Session seed — from --seed or the exercise pack, otherwise drawn at startup
+    pub seed:           u64,
+    /// Session clock (Unix time, whole minutes) for band conditions and
+    /// radiogram dates — from --utc or the pack, drawn from the seed on a
+    /// replay, otherwise the time the program started
+    pub utc:            u64,
+    /// Title of the loaded exercise pack; empty = none
+    pub exercise:       String,
     /// Suppress on-screen CW decoding display (QSO still advances normally)
     pub no_decode:      bool,
+    /// Quiz the user on what the SIM sent once the session ends
+    pub head_copy:      bool,
     /// Keyer-only mode: no QSO engine, just show decoded keying
     pub keyer_only:     bool,
+    /// Practice drill instead of a QSO; None = QSO
+    pub practice:       Option<PracticeMode>,
+    /// Koch lesson to start at; None = the saved one
+    pub koch_lesson:    Option<usize>,
+    /// What copy practice sends
+    pub copy_kind:      CopyKind,
+    /// What send practice shows to key
+    pub send_kind:      CopyKind,
+    /// Text file whose lines send practice shows instead; None = generate
+    pub send_file:      Option<PathBuf>,
+    /// What the ICR drill sends
+    pub icr_kind:       IcrKind,
+    /// Weak characters and words come up more often (the review schedule)
+    pub adapt:          bool,
 }
 
 impl Default for AppConfig {
@@ -282,15 +483,34 @@ impl Default for AppConfig {
             port:           String::new(),
             midi_port:      String::new(),
             qso_style:      QsoStyle::Ragchew,
+            band:           Band::B20,
             min_delay_ms:   800,
             max_delay_ms:   2500,
             typo_rate:      0.05,
             cwt_name:       "OP".into(),
             cwt_nr:         "NM".into(),
+            my_spc:         String::new(),
             my_dok:         "NM".into(),
+            my_skcc:        "NONE".into(),
+            my_naqcc:       "5W".into(),
+            my_fists:       "100W".into(),
+            my_locator:     String::new(),
+            call_pool:      None,
+            personalities:  true,
+            seed:           0,
+            utc:            0,
+            exercise:       String::new(),
             demo:           false,
             no_decode:      false,
+            head_copy:      false,
             keyer_only:     false,
+            practice:       None,
+            koch_lesson:    None,
+            copy_kind:      CopyKind::Groups,
+            send_kind:      CopyKind::Phrases,
+            send_file:      None,
+            icr_kind:       IcrKind::Chars,
+            adapt:          true,
         }
     }
 }
@@ -329,8 +549,68 @@ impl AppConfig {
             );
         }
 
-        // 2. Apply CLI overrides
+        // 2. Exercise pack — same sections as the config file plus [exercise];
+        //    a fresh seed unless the pack (or --seed) fixes one
+        cfg.seed = rand::random();
+        let mut pack_utc = None;
+        if let Some(path) = &cli.exercise {
+            let raw = std::fs::read_to_string(path)
+                .with_context(|| format!("Reading exercise {:?}", path))?;
+            let fc: FileConfig = toml::from_str(&raw)
+                .with_context(|| format!("Parsing exercise {:?}", path))?;
+            let Some(ex) = &fc.exercise else {
+                bail!("{:?} is not an exercise pack — it has no [exercise] section", path);
+            };
+            let Some(seed) = ex.seed.or(cli.seed) else {
+                bail!("Exercise {:?} has no seed — set seed in [exercise]", path);
+            };
+            cfg.apply_file(&fc);
+            cfg.seed = seed;
+            pack_utc = ex.utc.clone();
+            cfg.exercise = ex.title.clone()
+                .unwrap_or_else(|| path.file_stem().unwrap_or_default().to_string_lossy().into_owned());
+        }
+
+        // 3. Apply CLI overrides
         cfg.apply_cli(cli);
+        // A replay must not depend on the user's history
+        let replay = cli.seed.is_some() || cli.exercise.is_some();
+        if replay {
+            cfg.adapt = false;
+        }
+        // The same goes for the clock: a replay without a time of its own
+        // runs at one drawn from the seed
+        cfg.utc = match cli.utc.as_ref().or(pack_utc.as_ref()) {
+            Some(t) => crate::report::parse_utc(t)
+                .with_context(|| format!("Invalid time {:?} — expected UTC like 2024-06-15T14:00", t))?,
+            None if replay => SEED_EPOCH + cfg.seed % SEED_MINUTES * 60,
+            None => {
+                let now = crate::history::now();
+                now - now % 60
+            }
+        };
+
+        // 4. The locator drives the VHF exchange and every QRB — reject typos early
+        if !cfg.my_locator.is_empty() && crate::qso::locator::normalize(&cfg.my_locator).is_none() {
+            bail!("Invalid locator {:?} — expected a Maidenhead locator like JO62QM", cfg.my_locator);
+        }
+        if cfg.qso_style == QsoStyle::VhfContest && cfg.my_locator.is_empty() {
+            bail!("vhf-contest needs your locator: set --my-locator JO62QM or my_locator in [qso]");
+        }
+        // 5. The SPC when not set: a state or country given as cwt_nr (where it
+        //    used to go), else the country of the callsign
+        if cfg.my_spc.is_empty() {
+            cfg.my_spc = if cfg.cwt_nr.parse::<u32>().is_err() && cfg.cwt_nr != "NM" {
+                cfg.cwt_nr.to_uppercase()
+            } else {
+                crate::qso::cty::dxcc_prefix(&cfg.mycall).to_string()
+            };
+        }
+        // 6. No contests on the WARC bands
+        if crate::qso::band::is_warc(cfg.band) && crate::qso::band::is_contest(cfg.qso_style) {
+            bail!("{} is a WARC band — there are no contests on 60, 30, 17 or 12 m; pick another --band",
+                  crate::qso::band::label(cfg.band, cfg.qso_style));
+        }
         Ok(cfg)
     }
 
@@ -356,12 +636,27 @@ impl AppConfig {
         }
         if let Some(q) = &fc.qso {
             if let Some(v) = q.style        { self.qso_style    = v; }
+            if let Some(v) = q.band         { self.band         = v; }
             if let Some(v) = q.min_delay_ms { self.min_delay_ms = v; }
             if let Some(v) = q.max_delay_ms { self.max_delay_ms = v; }
             if let Some(v) = q.typo_rate    { self.typo_rate    = v; }
             if let Some(v) = &q.cwt_name    { self.cwt_name     = v.clone(); }
             if let Some(v) = &q.cwt_nr      { self.cwt_nr       = v.clone(); }
+            if let Some(v) = &q.my_spc      { self.my_spc       = v.to_uppercase(); }
             if let Some(v) = &q.my_dok      { self.my_dok       = v.clone(); }
+            if let Some(v) = &q.my_skcc     { self.my_skcc      = v.clone(); }
+            if let Some(v) = &q.my_naqcc    { self.my_naqcc     = v.clone(); }
+            if let Some(v) = &q.my_fists    { self.my_fists     = v.clone(); }
+            if let Some(v) = &q.my_locator  { self.my_locator   = v.to_uppercase(); }
+            if let Some(v) = &q.call_pool   { self.call_pool    = Some(v.clone()); }
+            if let Some(v) = q.personalities { self.personalities = v; }
+        }
+        if let Some(p) = &fc.practice {
+            if let Some(v) = p.copy_kind    { self.copy_kind    = v; }
+            if let Some(v) = p.send_kind    { self.send_kind    = v; }
+            if let Some(v) = &p.send_file   { self.send_file    = Some(v.clone()); }
+            if let Some(v) = p.icr_kind     { self.icr_kind     = v; }
+            if let Some(v) = p.adapt        { self.adapt        = v; }
         }
     }
 
@@ -373,6 +668,7 @@ impl AppConfig {
         if let Some(v) = cli.tone        { self.tone_hz        = v; }
         if let Some(v) = cli.who_starts  { self.who_starts  = v; }
         if let Some(v) = cli.style       { self.qso_style   = v; }
+        if let Some(v) = cli.band        { self.band        = v; }
         if let Some(v) = cli.adapter     { self.adapter     = v; }
         if let Some(v) = &cli.port       { self.port        = v.clone(); }
         if let Some(v) = &cli.midi_port  { self.midi_port   = v.clone(); }
@@ -381,15 +677,36 @@ impl AppConfig {
         if let Some(v) = &cli.lang       { self.language    = v.clone(); }
         if let Some(v) = &cli.cwt_name   { self.cwt_name    = v.clone(); }
         if let Some(v) = &cli.cwt_nr     { self.cwt_nr      = v.clone(); }
+        if let Some(v) = &cli.my_spc     { self.my_spc      = v.to_uppercase(); }
         if let Some(v) = &cli.my_dok     { self.my_dok      = v.clone(); }
+        if let Some(v) = &cli.my_skcc    { self.my_skcc     = v.clone(); }
+        if let Some(v) = &cli.my_naqcc   { self.my_naqcc    = v.clone(); }
+        if let Some(v) = &cli.my_fists   { self.my_fists    = v.clone(); }
+        if let Some(v) = &cli.my_locator { self.my_locator  = v.to_uppercase(); }
+        if let Some(v) = &cli.call_pool  { self.call_pool   = Some(v.clone()); }
+        if let Some(v) = cli.seed        { self.seed        = v; }
+        if cli.no_personalities          { self.personalities = false; }
+        if cli.no_adapt                  { self.adapt       = false; }
         if cli.demo                      { self.demo        = true; }
         if cli.no_decode                 { self.no_decode   = true; }
+        if cli.head_copy                 { self.head_copy   = true; self.no_decode = true; }
         if cli.keyer_only                { self.keyer_only  = true; }
+        if let Some(v) = cli.practice    { self.practice    = Some(v); }
+        if let Some(v) = cli.koch_lesson { self.koch_lesson = Some(v); }
+        if let Some(v) = cli.copy_kind   { self.copy_kind   = v; }
+        if let Some(v) = cli.send_kind   { self.send_kind   = v; }
+        if let Some(v) = &cli.send_file  { self.send_file   = Some(v.clone()); }
+        if let Some(v) = cli.icr_kind    { self.icr_kind    = v; }
     }
 }
 
 fn default_config_path() -> PathBuf {
-    dirs_next().join("cw-qso-sim").join("config.toml")
+    config_dir().join("config.toml")
+}
+
+/// Per-user config directory — also holds optional data files such as cty.dat.
+pub fn config_dir() -> PathBuf {
+    dirs_next().join("cw-qso-sim")
 }
 
 fn dirs_next() -> PathBuf {
@@ -424,9 +741,21 @@ pub fn print_help(lang: &crate::i18n::I18n) {
         ("      --tone <HZ>",           "cli.help.tone"),
         ("      --who-starts <WHO>",    "cli.help.who_starts"),
         ("      --style <STYLE>",         "cli.help.style"),
+        ("      --band <BAND>",         "cli.help.band"),
         ("      --cwt-name <NAME>",     "cli.help.cwt_name"),
         ("      --cwt-nr <NR>",         "cli.help.cwt_nr"),
+        ("      --my-spc <SPC>",        "cli.help.my_spc"),
         ("      --my-dok <DOK>",        "cli.help.my_dok"),
+        ("      --my-skcc <NR>",        "cli.help.my_skcc"),
+        ("      --my-naqcc <NR>",       "cli.help.my_naqcc"),
+        ("      --my-fists <NR>",       "cli.help.my_fists"),
+        ("      --my-locator <LOC>",    "cli.help.my_locator"),
+        ("      --call-pool <FILE>",    "cli.help.call_pool"),
+        ("      --seed <N>",            "cli.help.seed"),
+        ("      --exercise <FILE>",     "cli.help.exercise"),
+        ("      --utc <TIME>",          "cli.help.utc"),
+        ("      --no-personalities",    "cli.help.no_personalities"),
+        ("      --no-adapt",            "cli.help.no_adapt"),
         ("      --adapter <ADAPTER>",   "cli.help.adapter"),
         ("      --port <PORT>",         "cli.help.port"),
         ("      --midi-port <PORT>",    "cli.help.midi_port"),
@@ -437,9 +766,18 @@ pub fn print_help(lang: &crate::i18n::I18n) {
         ("      --check-adapter",       "cli.help.check_adapter"),
         ("      --write-config",        "cli.help.write_config"),
         ("      --print-config",        "cli.help.print_config"),
+        ("      --stats",               "cli.help.stats"),
+        ("      --export <FILE>",       "cli.help.export"),
         ("      --demo",                "cli.help.demo"),
         ("      --no-decode",           "cli.help.no_decode"),
+        ("      --head-copy",           "cli.help.head_copy"),
         ("      --keyer-only",          "cli.help.keyer_only"),
+        ("      --practice <MODE>",     "cli.help.practice"),
+        ("      --koch-lesson <N>",     "cli.help.koch_lesson"),
+        ("      --copy-kind <KIND>",    "cli.help.copy_kind"),
+        ("      --send-kind <KIND>",    "cli.help.send_kind"),
+        ("      --send-file <FILE>",    "cli.help.send_file"),
+        ("      --icr-kind <KIND>",     "cli.help.icr_kind"),
         ("  -h, --help",                "cli.help.help"),
         ("  -V, --version",             "cli.help.version"),
     ];
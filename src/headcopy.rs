@@ -0,0 +1,154 @@
+// src/headcopy.rs  —  Head-copy quiz after QSOs run with the decode hidden
+//
+// With --head-copy the SIM's text stays off the screen (--no-decode) and,
+// once the session ends, every QSO is quizzed on the terminal: the SIM's
+// name, QTH, rig, report, serial number and DOK.  A question is only asked
+// when the SIM actually sent that field — a contest QSO has no name, a
+// rag-chew no serial.  A field that went out mangled by a slip the SIM left
+// uncorrected is still asked: the intended value is the answer, and the
+// feedback names the slip.  Reports and serials may be answered cut or in
+// digits.  Each QSO ends with the score and what the SIM sent.
+use std::io::{self, BufRead, Write};
+use crate::qso::cutnum;
+use crate::qso::exchanges::SimExchange;
+use crate::qso::typo::{Correction, SendError};
+
+/// How an answer is compared
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// Letters and digits only: "IC-7300" is "IC 7300"
+    Text,
+    /// Digit by digit, cut numbers expanded: "5NN" is "599"
+    Digits,
+    /// By value, cut numbers expanded: "TT7" and "7" are 007
+    Number,
+}
+
+struct Question {
+    label:  &'static str,
+    answer: String,
+    kind:   Kind,
+    /// The field as it went out when only an uncorrected slip of it was sent
+    slip:   Option<String>,
+}
+
+/// The questions on one QSO, and what the SIM sent in it
+pub struct Quiz {
+    call:       String,
+    questions:  Vec<Question>,
+    transcript: Vec<String>,
+}
+
+impl Quiz {
+    pub fn new(ex: &SimExchange, mistakes: &[SendError], transcript: Vec<String>) -> Self {
+        let words: Vec<&str> = transcript.iter().flat_map(|l| l.split_whitespace()).collect();
+        let rst    = ex.rst_to_me.clone();
+        let serial = ex.sim_serial;
+        let fields = [
+            ("Name",   ex.sim_name.clone(),  Kind::Text,   vec![ex.sim_name.clone()]),
+            ("QTH",    ex.sim_qth.clone(),   Kind::Text,   vec![ex.sim_qth.clone()]),
+            ("Rig",    ex.rig.clone(),       Kind::Text,   vec![ex.rig.clone()]),
+            ("RST",    rst.clone(),          Kind::Digits, vec![ex.cut.rst(&rst), rst]),
+            ("Serial", serial.to_string(),   Kind::Number,
+                vec![ex.cut.number(serial, 3), format!("{serial:03}"), serial.to_string()]),
+            ("DOK",    ex.dok.clone(),       Kind::Text,   vec![ex.dok.clone()]),
+        ];
+        let slips: Vec<&SendError> = mistakes.iter().filter(|m| m.correction == Correction::None).collect();
+        let questions = fields.into_iter()
+            .filter(|(_, answer, ..)| !answer.is_empty())
+            .filter_map(|(label, answer, kind, forms)| {
+                if forms.iter().any(|f| contains(&words, f)) {
+                    return Some(Question { label, answer, kind, slip: None });
+                }
+                let slip = forms.iter()
+                    .flat_map(|f| slips.iter().filter_map(move |m| slipped(f, m)))
+                    .find(|s| contains(&words, s))?;
+                Some(Question { label, answer, kind, slip: Some(slip) })
+            })
+            .collect();
+        Self { call: ex.sim_call.clone(), questions, transcript }
+    }
+}
+
+/// `phrase` appears in `words` as whole words
+fn contains(words: &[&str], phrase: &str) -> bool {
+    let p: Vec<&str> = phrase.split_whitespace().collect();
+    !p.is_empty() && words.windows(p.len()).any(|w| w == p.as_slice())
+}
+
+/// `form` as it went out with the slip `m`; None when the slip is not in it
+fn slipped(form: &str, m: &SendError) -> Option<String> {
+    let words: Vec<&str> = form.split_whitespace().collect();
+    words.contains(&m.intended.as_str()).then(|| {
+        words.iter().map(|&w| if w == m.intended { m.sent.as_str() } else { w })
+            .collect::<Vec<_>>().join(" ")
+    })
+}
+
+fn right(kind: Kind, answer: &str, given: &str) -> bool {
+    let compact: String = given.split_whitespace().collect();
+    match kind {
+        Kind::Text => {
+            let norm = |s: &str| -> String { s.chars().filter(char::is_ascii_alphanumeric).collect::<String>().to_uppercase() };
+            norm(given) == norm(answer)
+        }
+        Kind::Digits => cutnum::expand(&compact).is_some_and(|d| d == answer),
+        Kind::Number => cutnum::expand(&compact).and_then(|d| d.parse::<u32>().ok())
+                            .is_some_and(|n| answer.parse() == Ok(n)),
+    }
+}
+
+/// Ask every quiz on the terminal and print the results
+pub fn run(quizzes: &[Quiz]) -> io::Result<()> {
+    if quizzes.is_empty() { return Ok(()); }
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let (mut total, mut total_right) = (0, 0);
+
+    println!("\nHead-copy quiz — answer from memory, Enter to skip");
+    for (n, q) in quizzes.iter().enumerate() {
+        println!("\nQSO {} with {}", n + 1, q.call);
+        if q.questions.is_empty() {
+            println!("  The SIM sent nothing to ask about.");
+        }
+        let mut answers = vec![];
+        for question in &q.questions {
+            print!("  {:<7}? ", question.label);
+            io::stdout().flush()?;
+            let given = match lines.next() {
+                Some(l) => l?.trim().to_uppercase(),
+                None    => { println!(); String::new() }
+            };
+            answers.push(given);
+        }
+
+        let mut score = 0;
+        for (question, given) in q.questions.iter().zip(&answers) {
+            let slip = question.slip.as_ref()
+                .map_or(String::new(), |s| format!("  (went out as {s}, not corrected)"));
+            if right(question.kind, &question.answer, given) {
+                score += 1;
+                println!("  {:<7}  {:<20} ✓{slip}", question.label, given);
+            } else {
+                let given = if given.is_empty() { "—" } else { given.as_str() };
+                println!("  {:<7}  {:<20} ✗  sent: {}{slip}", question.label, given, question.answer);
+            }
+        }
+        if !q.questions.is_empty() {
+            println!("  Score {score}/{}  ({:.0}%)", q.questions.len(),
+                     100.0 * f64::from(score) / q.questions.len() as f64);
+        }
+        total += q.questions.len();
+        total_right += score;
+
+        println!("\n  What {} sent:", q.call);
+        for l in &q.transcript {
+            println!("    {l}");
+        }
+    }
+    if quizzes.len() > 1 && total > 0 {
+        println!("\nHead copy: {total_right}/{total} right over {} QSOs  ({:.0}%)",
+                 quizzes.len(), 100.0 * f64::from(total_right) / total as f64);
+    }
+    Ok(())
+}
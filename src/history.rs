@@ -0,0 +1,429 @@
+// src/history.rs  —  Practice history across runs: store, --stats, CSV/JSON export
+//
+// Every practice session (not demos, not keyer-only) appends one record to
+// <config dir>/history.toml: style, band, speeds, QSO counts, exchange
+// accuracy, QSO time, reply latency and which characters were sent wrong.
+// Practice drills (--practice) add a shorter `[[drill]]` record of their own.
+// Both note the characters and words practised and missed, which the review
+// schedule (schedule.rs) is built from.
+// `--stats` compares the last 30 days with what came before ("average QSO
+// time dropped from 14s to 8s this month"); `--export FILE` writes the QSO
+// session records as CSV, or JSON when the file name ends in .json.
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::config::{AppConfig, Band, PracticeMode, QsoStyle};
+use crate::practice::CharStats;
+use crate::qso::typo::Catch;
+use crate::report::{self, SessionReport};
+use crate::schedule::Schedule;
+
+/// One practice session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Unix time the session ended
+    pub time:         u64,
+    pub style:        QsoStyle,
+    pub band:         Band,
+    /// Configured SIM and decoder speeds
+    pub sim_wpm:      u8,
+    pub user_wpm:     u8,
+    /// Measured sending speed, when the user keyed
+    pub sent_wpm:     Option<f64>,
+    /// QSOs completed, and started (including the one quit in)
+    pub qsos:         u32,
+    pub attempted:    u32,
+    /// Exchange fields checked and fields busted
+    pub fields:       u32,
+    pub busted:       u32,
+    pub avg_qso_secs: Option<f64>,
+    pub avg_latency_secs: Option<f64>,
+    pub repeats:      u32,
+    pub undecodable:  u32,
+    /// Slips the SIM left uncorrected that the user sent back, and those
+    /// they sent back right
+    #[serde(default)]
+    pub slips:        u32,
+    #[serde(default)]
+    pub slips_caught: u32,
+    /// Character → times sent wrong
+    #[serde(default)]
+    pub char_errors:  BTreeMap<String, u32>,
+    /// Word of the exchange (name, QTH, call …) → times sent wrong
+    #[serde(default)]
+    pub word_errors:  BTreeMap<String, u32>,
+    /// Characters and words of the checked exchange fields → times sent
+    #[serde(default)]
+    pub seen:         BTreeMap<String, u32>,
+}
+
+impl SessionRecord {
+    pub fn new(cfg: &AppConfig, r: &SessionReport) -> Self {
+        let qs = &r.qsos;
+        let mut char_errors = BTreeMap::new();
+        for c in qs.iter().flat_map(|q| &q.char_errors) {
+            *char_errors.entry(c.to_string()).or_insert(0) += 1;
+        }
+        let (mut word_errors, mut seen) = (BTreeMap::new(), BTreeMap::new());
+        for (value, busted) in qs.iter().flat_map(|q| &q.checked) {
+            for c in value.chars() {
+                *seen.entry(c.to_string()).or_insert(0) += 1;
+            }
+            // Serials and reports are numbers, not words
+            if value.len() > 1 && value.chars().any(|c| c.is_ascii_alphabetic()) {
+                *seen.entry(value.clone()).or_insert(0) += 1;
+                if *busted { *word_errors.entry(value.clone()).or_insert(0) += 1; }
+            }
+        }
+        Self {
+            time:         now(),
+            style:        cfg.qso_style,
+            band:         cfg.band,
+            sim_wpm:      cfg.sim_wpm,
+            user_wpm:     cfg.user_wpm,
+            sent_wpm:     report::mean(qs.iter().filter_map(|q| q.keying.wpm)),
+            qsos:         qs.iter().filter(|q| q.completed).count() as u32,
+            attempted:    qs.len() as u32,
+            fields:       qs.iter().map(|q| q.fields as u32).sum(),
+            busted:       qs.iter().map(|q| q.busted.len() as u32).sum(),
+            avg_qso_secs: report::mean(qs.iter().filter(|q| q.completed)
+                                         .map(|q| q.duration.as_secs_f64())),
+            avg_latency_secs: report::mean(qs.iter().filter_map(|q| q.mean_latency())),
+            repeats:      qs.iter().map(|q| u32::from(q.repeats)).sum(),
+            undecodable:  qs.iter().map(|q| q.keying.undecodable).sum(),
+            slips:        qs.iter().flat_map(|q| &q.slips).filter(|&&c| c != Catch::Unseen).count() as u32,
+            slips_caught: qs.iter().flat_map(|q| &q.slips).filter(|&&c| c == Catch::Caught).count() as u32,
+            char_errors,
+            word_errors,
+            seen,
+        }
+    }
+
+    /// Share of exchange fields right, None when nothing was checked
+    fn accuracy(&self) -> Option<f64> {
+        (self.fields > 0).then(|| 1.0 - f64::from(self.busted) / f64::from(self.fields))
+    }
+
+    /// Share of the SIM's uncorrected slips caught, None when the user sent
+    /// none of them back
+    fn slips_caught(&self) -> Option<f64> {
+        (self.slips > 0).then(|| f64::from(self.slips_caught) / f64::from(self.slips))
+    }
+}
+
+/// One run of a practice drill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrillRecord {
+    /// Unix time the run ended
+    pub time:     u64,
+    pub mode:     PracticeMode,
+    /// What was practised within the mode ("groups", "calls"); empty when
+    /// the mode has only one kind
+    #[serde(default)]
+    pub kind:     String,
+    /// Character speed
+    pub wpm:      u8,
+    pub rounds:   u32,
+    /// Characters copied right, when the drill scores copy
+    pub accuracy: Option<f64>,
+    /// Where the run ended up — the Koch lesson
+    pub level:    Option<u32>,
+    /// Points, for drills that score a run — the callsign trainer
+    #[serde(default)]
+    pub score:    Option<u32>,
+    /// Mean reaction time in ms, for drills that time answers — the ICR drill
+    #[serde(default)]
+    pub reaction_ms: Option<u32>,
+    /// Character → times missed
+    #[serde(default)]
+    pub char_errors: BTreeMap<String, u32>,
+    /// Word → times missed, for drills that send words
+    #[serde(default)]
+    pub word_errors: BTreeMap<String, u32>,
+    /// Characters and words sent → times
+    #[serde(default)]
+    pub seen:        BTreeMap<String, u32>,
+}
+
+impl DrillRecord {
+    /// A record ending now; the drill fills in the rest
+    pub fn new(mode: PracticeMode, wpm: u8, chars: &CharStats) -> Self {
+        Self {
+            time: now(),
+            mode,
+            kind: String::new(),
+            wpm,
+            rounds: 0,
+            accuracy: None,
+            level: None,
+            score: None,
+            reaction_ms: None,
+            char_errors: chars.errors(),
+            word_errors: chars.word_errors(),
+            seen: chars.seen(),
+        }
+    }
+
+    fn label(&self) -> String {
+        if self.kind.is_empty() { value_name(self.mode) } else { format!("{} ({})", value_name(self.mode), self.kind) }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub session: Vec<SessionRecord>,
+    #[serde(default)]
+    pub drill:   Vec<DrillRecord>,
+}
+
+impl History {
+    pub fn path() -> PathBuf {
+        crate::config::config_dir().join("history.toml")
+    }
+
+    /// The stored history; empty when there is none yet
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() { return Ok(Self::default()); }
+        let raw = std::fs::read_to_string(&path)
+            .with_context(|| format!("Reading history {:?}", path))?;
+        toml::from_str(&raw).with_context(|| format!("Parsing history {:?}", path))
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Creating {:?}", dir))?;
+        }
+        std::fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Writing history {:?}", path))
+    }
+
+    /// Add one session to the stored history
+    pub fn append(rec: SessionRecord) -> Result<()> {
+        let mut h = Self::load()?;
+        h.session.push(rec);
+        h.save()
+    }
+
+    /// Add one drill run to the stored history
+    pub fn append_drill(rec: DrillRecord) -> Result<()> {
+        let mut h = Self::load()?;
+        h.drill.push(rec);
+        h.save()
+    }
+
+    // ── --stats ──────────────────────────────────────────────────────────────
+
+    /// Progress per style and drill, most practised first
+    pub fn stats(&self) -> String {
+        let mut out = String::new();
+        if self.session.is_empty() && self.drill.is_empty() {
+            let _ = writeln!(out, "No practice history yet — it is recorded at the end of every session.");
+            return out;
+        }
+        let mut styles: Vec<QsoStyle> = vec![];
+        for s in &self.session {
+            if !styles.contains(&s.style) { styles.push(s.style); }
+        }
+        let count = |st: QsoStyle| self.session.iter().filter(|s| s.style == st).count();
+        styles.sort_by_key(|&st| std::cmp::Reverse(count(st)));
+
+        for st in styles {
+            let runs: Vec<&SessionRecord> = self.session.iter().filter(|s| s.style == st).collect();
+            let qsos: u32 = runs.iter().map(|s| s.qsos).sum();
+            let _ = writeln!(out, "\n{}  —  {}, {}", style_name(st), count_of(runs.len(), "session"), count_of(qsos as usize, "QSO"));
+            let Some((before, after, when)) = split(&runs, |s| s.time) else {
+                let _ = writeln!(out, "  one session so far: {}", summary_line(runs[0]));
+                continue;
+            };
+            let lines = [
+                change("average QSO time", before, after, |s| s.avg_qso_secs, |v| format!("{v:.0}s")),
+                change("exchange accuracy", before, after, SessionRecord::accuracy, |v| format!("{:.0}%", v * 100.0)),
+                change("sending speed", before, after, |s| s.sent_wpm, |v| format!("{v:.1} WPM")),
+                change("reply latency", before, after, |s| s.avg_latency_secs, |v| format!("{v:.1}s")),
+                change("SIM slips caught", before, after, SessionRecord::slips_caught, |v| format!("{:.0}%", v * 100.0)),
+                change("SIM speed", before, after, |s| Some(f64::from(s.sim_wpm)), |v| format!("{v:.0} WPM")),
+            ];
+            for l in lines.into_iter().flatten() {
+                let _ = writeln!(out, "  {l} {when}");
+            }
+        }
+
+        let mut drills: Vec<String> = vec![];
+        for d in &self.drill {
+            if !drills.contains(&d.label()) { drills.push(d.label()); }
+        }
+        for label in drills {
+            let runs: Vec<&DrillRecord> = self.drill.iter().filter(|d| d.label() == label).collect();
+            let rounds: u32 = runs.iter().map(|d| d.rounds).sum();
+            let _ = writeln!(out, "\n{label}  —  {}, {}", count_of(runs.len(), "run"), count_of(rounds as usize, "round"));
+            let Some((before, after, when)) = split(&runs, |d| d.time) else {
+                let d = runs[0];
+                let mut parts = vec![];
+                if let Some(l) = d.level    { parts.push(format!("lesson {l}")); }
+                if let Some(a) = d.accuracy { parts.push(format!("{:.0}% accuracy", a * 100.0)); }
+                if let Some(p) = d.score    { parts.push(format!("score {p}")); }
+                if let Some(r) = d.reaction_ms { parts.push(format!("reaction {r} ms")); }
+                parts.push(format!("{} WPM", d.wpm));
+                let _ = writeln!(out, "  one run so far: {}", parts.join(", "));
+                continue;
+            };
+            let lines = [
+                change("lesson", before, after, |d| d.level.map(f64::from), |v| format!("{v:.0}")),
+                change("accuracy", before, after, |d| d.accuracy, |v| format!("{:.0}%", v * 100.0)),
+                change("score", before, after, |d| d.score.map(f64::from), |v| format!("{v:.0}")),
+                change("reaction time", before, after, |d| d.reaction_ms.map(f64::from), |v| format!("{v:.0} ms")),
+                change("speed", before, after, |d| Some(f64::from(d.wpm)), |v| format!("{v:.0} WPM")),
+            ];
+            for l in lines.into_iter().flatten() {
+                let _ = writeln!(out, "  {l} {when}");
+            }
+        }
+
+        let mut errors: BTreeMap<&str, u32> = BTreeMap::new();
+        let all = self.session.iter().flat_map(|s| &s.char_errors)
+            .chain(self.drill.iter().flat_map(|d| &d.char_errors));
+        for (c, n) in all {
+            *errors.entry(c.as_str()).or_insert(0) += n;
+        }
+        if !errors.is_empty() {
+            let mut worst: Vec<(&str, u32)> = errors.into_iter().collect();
+            worst.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+            let list: Vec<String> = worst.iter().take(8).map(|(c, n)| format!("{c} ×{n}")).collect();
+            let _ = writeln!(out, "\nMost missed characters: {}", list.join("  "));
+        }
+        out.push_str(&Schedule::from_history(self, now()).report());
+        out
+    }
+
+    // ── --export ─────────────────────────────────────────────────────────────
+
+    /// Write the history to `path`: JSON for *.json, CSV otherwise
+    pub fn export(&self, path: &Path) -> Result<()> {
+        let json = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("json"));
+        let text = if json { self.to_json() } else { self.to_csv() };
+        std::fs::write(path, text).with_context(|| format!("Writing {:?}", path))
+    }
+
+    fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "date,style,band,sim_wpm,user_wpm,sent_wpm,qsos,attempted,fields,busted,\
+             accuracy,avg_qso_secs,avg_latency_secs,repeats,undecodable,char_errors,slips,slips_caught\n");
+        for s in &self.session {
+            let errors: Vec<String> = s.char_errors.iter().map(|(c, n)| format!("{c}:{n}")).collect();
+            let _ = writeln!(out, "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                date(s.time), style_name(s.style), band_name(s.band), s.sim_wpm, s.user_wpm,
+                num(s.sent_wpm), s.qsos, s.attempted, s.fields, s.busted, num(s.accuracy()),
+                num(s.avg_qso_secs), num(s.avg_latency_secs), s.repeats, s.undecodable,
+                errors.join(" "), s.slips, s.slips_caught);
+        }
+        out
+    }
+
+    fn to_json(&self) -> String {
+        let rows: Vec<String> = self.session.iter().map(|s| {
+            let errors: Vec<String> = s.char_errors.iter()
+                .map(|(c, n)| format!("{}: {n}", json_str(c)))
+                .collect();
+            format!(
+                "  {{\"date\": {}, \"style\": {}, \"band\": {}, \"sim_wpm\": {}, \"user_wpm\": {}, \
+                 \"sent_wpm\": {}, \"qsos\": {}, \"attempted\": {}, \"fields\": {}, \"busted\": {}, \
+                 \"accuracy\": {}, \"avg_qso_secs\": {}, \"avg_latency_secs\": {}, \"repeats\": {}, \
+                 \"undecodable\": {}, \"char_errors\": {{{}}}, \"slips\": {}, \"slips_caught\": {}}}",
+                json_str(&date(s.time)), json_str(&style_name(s.style)), json_str(&band_name(s.band)),
+                s.sim_wpm, s.user_wpm, json_num(s.sent_wpm), s.qsos, s.attempted, s.fields,
+                s.busted, json_num(s.accuracy()), json_num(s.avg_qso_secs),
+                json_num(s.avg_latency_secs), s.repeats, s.undecodable, errors.join(", "),
+                s.slips, s.slips_caught)
+        }).collect();
+        format!("[\n{}\n]\n", rows.join(",\n"))
+    }
+}
+
+/// Earlier records, later records, and how to say "later"
+type Halves<'a, T> = (&'a [&'a T], &'a [&'a T], String);
+
+/// Records from before the last 30 days and within them; when all fall on one
+/// side, the first half against the second.  None for a single record.
+fn split<'a, T>(runs: &'a [&'a T], time: impl Fn(&T) -> u64) -> Option<Halves<'a, T>> {
+    let month_ago = now().saturating_sub(30 * 86_400);
+    let at = runs.iter().position(|r| time(r) >= month_ago).unwrap_or(runs.len());
+    if at > 0 && at < runs.len() {
+        Some((&runs[..at], &runs[at..], "this month".to_string()))
+    } else if runs.len() >= 2 {
+        let half = runs.len() / 2;
+        Some((&runs[..half], &runs[half..], format!("over {} sessions", runs.len())))
+    } else {
+        None
+    }
+}
+
+/// "average QSO time dropped from 14s to 8s" — None when either side has no value
+fn change<T>(what: &str, before: &[&T], after: &[&T],
+             value: impl Fn(&T) -> Option<f64>, show: impl Fn(f64) -> String)
+    -> Option<String>
+{
+    let a = report::mean(before.iter().filter_map(|s| value(s)))?;
+    let b = report::mean(after.iter().filter_map(|s| value(s)))?;
+    Some(if show(a) == show(b) {
+        format!("{what} held at {}", show(b))
+    } else {
+        let verb = if b < a { "dropped" } else { "rose" };
+        format!("{what} {verb} from {} to {}", show(a), show(b))
+    })
+}
+
+/// "1 run", "3 runs"
+fn count_of(n: usize, what: &str) -> String {
+    format!("{n} {what}{}", if n == 1 { "" } else { "s" })
+}
+
+fn summary_line(s: &SessionRecord) -> String {
+    let mut parts = vec![format!("{} QSOs", s.qsos)];
+    if let Some(t) = s.avg_qso_secs { parts.push(format!("{t:.0}s per QSO")); }
+    if let Some(a) = s.accuracy() { parts.push(format!("{:.0}% exchange accuracy", a * 100.0)); }
+    if let Some(w) = s.sent_wpm { parts.push(format!("sending {w:.1} WPM")); }
+    if s.slips > 0 { parts.push(format!("{} of {} SIM slips caught", s.slips_caught, s.slips)); }
+    parts.join(", ")
+}
+
+/// The style as written on the command line ("cwt-contest")
+fn style_name(st: QsoStyle) -> String {
+    value_name(st)
+}
+
+fn band_name(b: Band) -> String {
+    value_name(b)
+}
+
+fn value_name(v: impl clap::ValueEnum) -> String {
+    v.to_possible_value().map_or_else(|| "?".to_string(), |p| p.get_name().to_string())
+}
+
+/// Unix time, seconds
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// "2026-10-18 13:46" (UTC)
+fn date(secs: u64) -> String {
+    let (y, mo, d, h, mi, _) = report::civil_utc(secs);
+    format!("{y:04}-{mo:02}-{d:02} {h:02}:{mi:02}")
+}
+
+fn num(v: Option<f64>) -> String {
+    v.map_or(String::new(), |x| format!("{x:.2}"))
+}
+
+fn json_num(v: Option<f64>) -> String {
+    v.map_or("null".to_string(), |x| format!("{x:.2}"))
+}
+
+fn json_str(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -23,6 +23,12 @@ impl I18n {
         Self { inner }
     }
     pub fn t<'a>(&self, key: &'a str) -> &'a str { self.inner.get(key) }
+    /// `t` with `{0}`, `{1}`, … replaced by `args`, so a language can put
+    /// them in its own order
+    pub fn f(&self, key: &str, args: &[&dyn std::fmt::Display]) -> String {
+        args.iter().enumerate()
+            .fold(self.t(key).to_string(), |s, (i, a)| s.replace(&format!("{{{i}}}"), &a.to_string()))
+    }
     pub fn lang_name(&self) -> &str    { self.inner.name() }
 }
 
@@ -81,10 +87,22 @@ lang_map!(En, "English", [
     "cli.help.farnsworth"    => "Farnsworth effective WPM — stretches inter-character gaps; 0 = off (default: 0)",
     "cli.help.tone"          => "Sidetone frequency in Hz",
     "cli.help.who_starts"    => "Who starts the QSO: me | sim",
-    "cli.help.style"         => "QSO style: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | random",
+    "cli.help.style"         => "QSO style: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | cq-ww-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | skcc-sprint | naqcc-sprint | fists-sprint | radiogram | vhf-contest | random",
+    "cli.help.band"          => "Band: 160m | 80m | 60m | 40m | 30m | 20m | 17m | 15m | 12m | 10m (default: 20m)",
     "cli.help.cwt_name"      => "Your operator name for CWT contest exchange (e.g. HANS)",
     "cli.help.cwt_nr"        => "Your CWT member number or state/country (e.g. 1234 or DL)",
+    "cli.help.my_spc"        => "Your state, province or country for sst-contest and the SKCC/NAQCC/FISTS sprints (e.g. MA or DL). Default: your callsign's country.",
     "cli.help.my_dok"        => "Your DARC DOK for darc-cw-contest (e.g. P53). Use NM if not a DARC member.",
+    "cli.help.my_skcc"       => "Your SKCC number with C/T/S suffix for skcc-sprint (e.g. 12345T). Use NONE if not a member.",
+    "cli.help.my_naqcc"      => "Your NAQCC number for naqcc-sprint (e.g. 4567). Non-members send their power (e.g. 5W).",
+    "cli.help.my_fists"      => "Your FISTS number for fists-sprint (e.g. 17001). Non-members send their power (e.g. 100W).",
+    "cli.help.my_locator"    => "Your 6-character Maidenhead locator (e.g. JO62QM) for vhf-contest and QRB/bearing.",
+    "cli.help.call_pool"     => "Draw SIM stations from a MASTER.SCP or N1MM call-history file instead of generated calls.",
+    "cli.help.seed"          => "Random seed — the same seed and settings replay the same stations, exchanges, typos and delays.",
+    "cli.help.exercise"      => "Exercise pack: TOML file bundling seed, style, speed and other settings for a class.",
+    "cli.help.utc"           => "Session clock, e.g. 2024-06-15T14:00 (UTC): band conditions and radiogram dates. Default: now, or drawn from the seed on a replay.",
+    "cli.help.no_personalities" => "Every SIM station uses the configured speed, delays and typo rate — no operator personalities.",
+    "cli.help.no_adapt"      => "Draw characters, words and stations uniformly — ignore the review schedule of weak characters",
     "cli.help.adapter"       => "Keyer adapter: auto | vband | attiny85 | arduino-nano | arduino-uno | esp32 | esp8266 | winkeyer | keyboard",
     "cli.help.port"          => "Serial port for arduino-nano, arduino-uno, esp32, esp8266 or winkeyer (e.g. /dev/ttyUSB0, COM3)",
     "cli.help.midi_port"     => "MIDI port name or substring for ATtiny85 adapter (overrides --port)",
@@ -95,11 +113,109 @@ lang_map!(En, "English", [
     "cli.help.check_adapter" => "Test the configured adapter: press DIT then DAH when prompted",
     "cli.help.write_config"  => "Write the built-in default config.toml to the config path and exit",
     "cli.help.print_config"  => "Print the built-in default config.toml to stdout and exit",
+    "cli.help.stats"         => "Print practice progress from past sessions and exit",
+    "cli.help.export"        => "Export the practice history to FILE (CSV, or JSON for *.json) and exit",
     "cli.help.demo"          => "Demo mode: play a complete QSO automatically (no keyer needed), then wait for ESC to exit",
     "cli.help.no_decode"     => "Hide decoded CW text on screen — QSO still advances; useful for self-testing without a cheat-sheet",
+    "cli.help.head_copy"     => "Head-copy training: hide the decode, then quiz the SIM's name, QTH, rig, RST, serial and DOK",
     "cli.help.keyer_only"    => "Keyer-only mode: display decoded keying with sidetone, no QSO simulation — ESC to quit",
+    "cli.help.practice"      => "Practice drill instead of a QSO: koch | copy | rufz | send | icr",
+    "cli.help.koch_lesson"   => "Koch trainer: start at lesson N (1 = K M) instead of the saved one",
+    "cli.help.copy_kind"     => "What copy practice sends: calls | groups | numbers | phrases | text (default: groups)",
+    "cli.help.send_kind"     => "What send practice shows to key: calls | groups | numbers | phrases | text (default: phrases)",
+    "cli.help.send_file"     => "Send practice: key the lines of this text file instead",
+    "cli.help.icr_kind"      => "What the ICR drill sends: chars | words (default: chars)",
     "cli.help.version"       => "Print version",
     "cli.help.help"          => "Print help",
+    // ── Practice drills ───────────────────────────────────────────────────────
+    "practice.sent"          => "Sent",
+    "practice.copy"          => "Copy",
+    "practice.finish"        => "Finish your copy — Enter when done",
+    "practice.most_missed"   => "most missed: {0}",
+    "koch.header"            => "Koch lesson {0} of {1}",
+    "koch.title"             => "KOCH — LESSON {0}",
+    "koch.characters"        => "Characters",
+    "koch.new_char"          => "New character",
+    "koch.listen"            => "Listen to the new character…",
+    "koch.copy"              => "Copy the groups as they come…",
+    "koch.scored"            => "Round scored",
+    "koch.unlocked"          => "{0} — lesson {1} unlocked, new character {2}",
+    "koch.all_learnt"        => "{0} — all {1} characters learnt; keep practising or raise the speed",
+    "koch.again"             => "{0} — {1}% moves you on; same lesson again",
+    "koch.next"              => "Enter = next round   Esc = quit",
+    "koch.none"              => "Koch lesson {0} ({1}) — no round finished",
+    "koch.summary"           => "Koch trainer: {0} rounds  {1}",
+    "koch.lesson"            => "lesson {0} → {1}",
+    "koch.next_time"         => "next time: lesson {0} ({1})",
+    "practice.correct"       => "Correct {0}",
+    "practice.wrong"         => "Wrong {0}",
+    "practice.missed"        => "Missed {0}",
+    "practice.extra"         => "Extra {0}",
+    "practice.lines"         => "Lines",
+    "practice.accuracy"      => "accuracy",
+    "practice.worst"         => "Most missed",
+    "practice.get_ready"     => "Get ready…",
+    "practice.line_scored"   => "Line scored",
+    "practice.next_line"     => "Enter = next line   Esc = quit",
+    "copy.header"            => "Copy practice: {0}",
+    "copy.title"             => "COPY — {0}",
+    "copy.listen"            => "Copy what you hear…",
+    "copy.none"              => "Copy practice ({0}) — no line finished",
+    "copy.summary"           => "Copy practice ({0}): {1} lines at {2}, {3}% accuracy",
+    "copy.counts"            => "correct {0}  wrong {1}  missed {2}  extra {3}",
+    "practice.listen"        => "Listen…",
+    "practice.you"           => "you: {0}",
+    "rufz.header"            => "Callsign trainer  |  {0} WPM  |  score {1}",
+    "rufz.title"             => "CALLSIGN TRAINER",
+    "rufz.call"              => "Call",
+    "rufz.score"             => "Score",
+    "rufz.speed"             => "Speed",
+    "rufz.best_label"        => "Best so far",
+    "rufz.next"              => "Next call…",
+    "rufz.type"              => "Type the call — Enter",
+    "rufz.final"             => "Final score {0}  —  top speed {1} WPM",
+    "rufz.complete"          => "Run complete — Esc to quit",
+    "rufz.none"              => "Callsign trainer — no call answered",
+    "rufz.summary"           => "Callsign trainer: score {0}",
+    "rufz.unfinished"        => "(run not finished)",
+    "rufz.right"             => "{0} of {1} calls right, {2} → {3} WPM, top {4} WPM",
+    "rufz.new_best"          => "new best — previous {0}",
+    "rufz.best"              => "best so far {0}",
+    "send.header"            => "Send practice: {0}  |  keyer {1} WPM",
+    "send.title"             => "SEND — {0}",
+    "send.keyed"             => "Keyed",
+    "send.spacing"           => "Spacing {0}",
+    "send.eff_speed"         => "Effective speed",
+    "send.ratio"             => "dah/dit",
+    "send.char_gap"          => "character gap",
+    "send.units"             => "{0} units",
+    "send.blue"              => "Blue: keyed right, spaced wrong",
+    "send.mistakes"          => "spacing mistakes",
+    "send.speed"             => "speed",
+    "send.key_this"          => "Key this:",
+    "send.start"             => "Start keying…",
+    "send.enter"             => "Enter when done",
+    "send.none"              => "Send practice ({0}) — no line finished",
+    "send.summary"           => "Send practice ({0}): {1} lines, {2}% accuracy",
+    "send.summary_speed"     => ", effective {0} WPM",
+    "send.counts"            => "correct {0}  wrong {1}  missed {2}  extra {3}  spacing mistakes {4}",
+    "send.most_wrong"        => "most often wrong: {0}",
+    "icr.header"             => "ICR drill: {0}",
+    "icr.title"              => "INSTANT RECOGNITION — {0}",
+    "icr.answers"            => "Answers",
+    "icr.right"              => "right",
+    "icr.reaction"           => "reaction",
+    "icr.times"              => "ø {0} ms  median {1} ms",
+    "icr.slow_over"          => "slow >",
+    "icr.coming_back"        => "Coming back",
+    "icr.slow"               => "slow",
+    "icr.next"               => "Next…",
+    "icr.now"                => "Type it — now!",
+    "icr.enter"              => "Type it — Enter",
+    "icr.none"               => "ICR drill ({0}) — nothing answered",
+    "icr.summary"            => "ICR drill ({0}): {1} answered at {2}, {3}% right, {4} too late",
+    "icr.reaction_time"      => "reaction time: mean {0} ms, median {1} ms",
+    "icr.slowest"            => "slowest: {0}",
 ]);
 
 // ── German ────────────────────────────────────────────────────────────────────
@@ -138,10 +254,22 @@ lang_map!(De, "Deutsch", [
     "cli.help.farnsworth"    => "Farnsworth-WPM — streckt Zeichenzwischenräume; 0 = deaktiviert (Standard: 0)",
     "cli.help.tone"          => "Mithörton-Frequenz in Hz",
     "cli.help.who_starts"    => "Wer beginnt das QSO: me | sim",
-    "cli.help.style"         => "QSO-Stil: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | random",
+    "cli.help.style"         => "QSO-Stil: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | cq-ww-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | skcc-sprint | naqcc-sprint | fists-sprint | radiogram | vhf-contest | random",
+    "cli.help.band"          => "Band: 160m | 80m | 60m | 40m | 30m | 20m | 17m | 15m | 12m | 10m (Standard: 20m)",
     "cli.help.cwt_name"      => "Dein Rufname für den CWT-Contest-Austausch (z.B. HANS)",
     "cli.help.cwt_nr"        => "Deine CWT-Mitgliedsnummer oder DXCC-Kürzel (z.B. 1234 oder DL)",
+    "cli.help.my_spc"        => "Dein Bundesstaat, deine Provinz oder dein Land für sst-contest und die SKCC/NAQCC/FISTS-Sprints (z.B. MA oder DL). Standard: Land deines Rufzeichens.",
     "cli.help.my_dok"        => "Dein DARC-DOK für darc-cw-contest (z.B. P53). NM wenn kein DARC-Mitglied.",
+    "cli.help.my_skcc"       => "Deine SKCC-Nummer mit C/T/S-Suffix für skcc-sprint (z.B. 12345T). NONE wenn kein Mitglied.",
+    "cli.help.my_naqcc"      => "Deine NAQCC-Nummer für naqcc-sprint (z.B. 4567). Nichtmitglieder senden ihre Leistung (z.B. 5W).",
+    "cli.help.my_fists"      => "Deine FISTS-Nummer für fists-sprint (z.B. 17001). Nichtmitglieder senden ihre Leistung (z.B. 100W).",
+    "cli.help.my_locator"    => "Dein 6-stelliger Maidenhead-Locator (z.B. JO62QM) für vhf-contest und QRB/Richtung.",
+    "cli.help.call_pool"     => "SIM-Stationen aus einer MASTER.SCP- oder N1MM-Call-History-Datei statt generierter Rufzeichen.",
+    "cli.help.seed"          => "Zufalls-Seed — gleicher Seed und gleiche Einstellungen ergeben dieselben Stationen, Rapporte, Tippfehler und Pausen.",
+    "cli.help.exercise"      => "Übungspaket: TOML-Datei mit Seed, Stil, Tempo und weiteren Einstellungen für einen Kurs.",
+    "cli.help.utc"           => "Sitzungsuhr, z. B. 2024-06-15T14:00 (UTC): Ausbreitung und Datum der Radiogramme. Standard: jetzt, bei Wiederholung aus dem Seed.",
+    "cli.help.no_personalities" => "Jede SIM-Station nutzt eingestelltes Tempo, Pausen und Tippfehlerrate — keine Operator-Persönlichkeiten.",
+    "cli.help.no_adapt"      => "Zeichen, Wörter und Stationen gleichverteilt ziehen — Wiederholungsplan schwacher Zeichen ignorieren",
     "cli.help.adapter"       => "Keyer-Adapter: auto | vband | attiny85 | arduino-nano | arduino-uno | esp32 | esp8266 | winkeyer | keyboard",
     "cli.help.port"          => "Serieller Port für arduino-nano, arduino-uno, esp32, esp8266 oder winkeyer (z.B. /dev/ttyUSB0, COM3)",
     "cli.help.midi_port"     => "MIDI-Portname oder -Teil für den ATtiny85-Adapter (überschreibt --port)",
@@ -152,11 +280,109 @@ lang_map!(De, "Deutsch", [
     "cli.help.check_adapter" => "Konfigurierten Adapter testen: DIT dann DAH drücken wenn aufgefordert",
     "cli.help.write_config"  => "Standard-config.toml in den Konfigurationspfad schreiben und beenden",
     "cli.help.print_config"  => "Eingebaute Standard-config.toml auf stdout ausgeben und beenden",
+    "cli.help.stats"         => "Übungsfortschritt aus früheren Sitzungen ausgeben und beenden",
+    "cli.help.export"        => "Übungsverlauf nach FILE exportieren (CSV, JSON bei *.json) und beenden",
     "cli.help.demo"          => "Demo-Modus: vollständiges QSO automatisch spielen (kein Keyer nötig), dann auf ESC warten",
     "cli.help.no_decode"     => "CW-Dekodierung ausblenden — QSO läuft weiter; nützlich zum Selbsttest ohne Spickzettel",
+    "cli.help.head_copy"     => "Kopfmitschrift-Training: Dekodierung ausblenden, danach Name, QTH, Rig, RST, Nummer und DOK der SIM abfragen",
     "cli.help.keyer_only"    => "Nur-Taster-Modus: dekodierte Tastereingabe mit Mithörton anzeigen, keine QSO-Simulation — ESC zum Beenden",
+    "cli.help.practice"      => "Übungsmodus statt QSO: koch | copy | rufz | send | icr",
+    "cli.help.koch_lesson"   => "Koch-Trainer: bei Lektion N beginnen (1 = K M) statt der gespeicherten",
+    "cli.help.copy_kind"     => "Was die Hörübung sendet: calls | groups | numbers | phrases | text (Standard: groups)",
+    "cli.help.send_kind"     => "Was die Gebeübung zum Geben anzeigt: calls | groups | numbers | phrases | text (Standard: phrases)",
+    "cli.help.send_file"     => "Gebeübung: stattdessen die Zeilen dieser Textdatei geben",
+    "cli.help.icr_kind"      => "Was der ICR-Drill sendet: chars | words (Standard: chars)",
     "cli.help.version"       => "Version anzeigen",
     "cli.help.help"          => "Hilfe anzeigen",
+    // ── Practice drills ───────────────────────────────────────────────────────
+    "practice.sent"          => "Gesendet",
+    "practice.copy"          => "Mitschrift",
+    "practice.finish"        => "Mitschrift fertigstellen — Enter, wenn fertig",
+    "practice.most_missed"   => "am häufigsten verpasst: {0}",
+    "koch.header"            => "Koch-Lektion {0} von {1}",
+    "koch.title"             => "KOCH — LEKTION {0}",
+    "koch.characters"        => "Zeichen",
+    "koch.new_char"          => "Neues Zeichen",
+    "koch.listen"            => "Hör dir das neue Zeichen an…",
+    "koch.copy"              => "Schreib die Gruppen mit…",
+    "koch.scored"            => "Runde ausgewertet",
+    "koch.unlocked"          => "{0} — Lektion {1} freigeschaltet, neues Zeichen {2}",
+    "koch.all_learnt"        => "{0} — alle {1} Zeichen gelernt; übe weiter oder erhöhe das Tempo",
+    "koch.again"             => "{0} — ab {1}% geht es weiter; gleiche Lektion noch einmal",
+    "koch.next"              => "Enter = nächste Runde   Esc = Beenden",
+    "koch.none"              => "Koch-Lektion {0} ({1}) — keine Runde beendet",
+    "koch.summary"           => "Koch-Trainer: {0} Runden  {1}",
+    "koch.lesson"            => "Lektion {0} → {1}",
+    "koch.next_time"         => "nächstes Mal: Lektion {0} ({1})",
+    "practice.correct"       => "Richtig {0}",
+    "practice.wrong"         => "Falsch {0}",
+    "practice.missed"        => "Verpasst {0}",
+    "practice.extra"         => "Zusätzlich {0}",
+    "practice.lines"         => "Zeilen",
+    "practice.accuracy"      => "Genauigkeit",
+    "practice.worst"         => "Am häufigsten verpasst",
+    "practice.get_ready"     => "Mach dich bereit…",
+    "practice.line_scored"   => "Zeile ausgewertet",
+    "practice.next_line"     => "Enter = nächste Zeile   Esc = Beenden",
+    "copy.header"            => "Hörübung: {0}",
+    "copy.title"             => "MITSCHRIFT — {0}",
+    "copy.listen"            => "Schreib mit, was du hörst…",
+    "copy.none"              => "Hörübung ({0}) — keine Zeile beendet",
+    "copy.summary"           => "Hörübung ({0}): {1} Zeilen bei {2}, {3}% Genauigkeit",
+    "copy.counts"            => "richtig {0}  falsch {1}  verpasst {2}  zusätzlich {3}",
+    "practice.listen"        => "Hör zu…",
+    "practice.you"           => "du: {0}",
+    "rufz.header"            => "Rufzeichentrainer  |  {0} WPM  |  Punkte {1}",
+    "rufz.title"             => "RUFZEICHENTRAINER",
+    "rufz.call"              => "Rufzeichen",
+    "rufz.score"             => "Punkte",
+    "rufz.speed"             => "Tempo",
+    "rufz.best_label"        => "Bisher bestes",
+    "rufz.next"              => "Nächstes Rufzeichen…",
+    "rufz.type"              => "Rufzeichen eingeben — Enter",
+    "rufz.final"             => "Endstand {0}  —  Höchsttempo {1} WPM",
+    "rufz.complete"          => "Durchgang beendet — Esc zum Beenden",
+    "rufz.none"              => "Rufzeichentrainer — kein Rufzeichen beantwortet",
+    "rufz.summary"           => "Rufzeichentrainer: {0} Punkte",
+    "rufz.unfinished"        => "(Durchgang nicht beendet)",
+    "rufz.right"             => "{0} von {1} Rufzeichen richtig, {2} → {3} WPM, Spitze {4} WPM",
+    "rufz.new_best"          => "neuer Bestwert — vorher {0}",
+    "rufz.best"              => "bisher bestes {0}",
+    "send.header"            => "Gebeübung: {0}  |  Keyer {1} WPM",
+    "send.title"             => "GEBEN — {0}",
+    "send.keyed"             => "Gegeben",
+    "send.spacing"           => "Abstand {0}",
+    "send.eff_speed"         => "Effektives Tempo",
+    "send.ratio"             => "Strich/Punkt",
+    "send.char_gap"          => "Zeichenabstand",
+    "send.units"             => "{0} Einheiten",
+    "send.blue"              => "Blau: richtig gegeben, Abstand falsch",
+    "send.mistakes"          => "Abstandsfehler",
+    "send.speed"             => "Tempo",
+    "send.key_this"          => "Gib das:",
+    "send.start"             => "Fang an zu geben…",
+    "send.enter"             => "Enter, wenn fertig",
+    "send.none"              => "Gebeübung ({0}) — keine Zeile beendet",
+    "send.summary"           => "Gebeübung ({0}): {1} Zeilen, {2}% Genauigkeit",
+    "send.summary_speed"     => ", effektiv {0} WPM",
+    "send.counts"            => "richtig {0}  falsch {1}  verpasst {2}  zusätzlich {3}  Abstandsfehler {4}",
+    "send.most_wrong"        => "am häufigsten falsch: {0}",
+    "icr.header"             => "ICR-Übung: {0}",
+    "icr.title"              => "SOFORTERKENNUNG — {0}",
+    "icr.answers"            => "Antworten",
+    "icr.right"              => "richtig",
+    "icr.reaction"           => "Reaktion",
+    "icr.times"              => "ø {0} ms  Median {1} ms",
+    "icr.slow_over"          => "langsam >",
+    "icr.coming_back"        => "Kommt wieder",
+    "icr.slow"               => "langsam",
+    "icr.next"               => "Weiter…",
+    "icr.now"                => "Tipp es — jetzt!",
+    "icr.enter"              => "Tipp es — Enter",
+    "icr.none"               => "ICR-Übung ({0}) — nichts beantwortet",
+    "icr.summary"            => "ICR-Übung ({0}): {1} beantwortet bei {2}, {3}% richtig, {4} zu spät",
+    "icr.reaction_time"      => "Reaktionszeit: Mittel {0} ms, Median {1} ms",
+    "icr.slowest"            => "am langsamsten: {0}",
 ]);
 
 // ── French ────────────────────────────────────────────────────────────────────
@@ -195,10 +421,22 @@ lang_map!(Fr, "Français", [
     "cli.help.farnsworth"    => "MPM Farnsworth effectif — étire les espaces entre caractères ; 0 = désactivé (défaut : 0)",
     "cli.help.tone"          => "Fréquence de l'écoute de contrôle en Hz",
     "cli.help.who_starts"    => "Qui commence le QSO : me | sim",
-    "cli.help.style"         => "Style du QSO : ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | random",
+    "cli.help.style"         => "Style du QSO : ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | cq-ww-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | skcc-sprint | naqcc-sprint | fists-sprint | radiogram | vhf-contest | random",
+    "cli.help.band"          => "Bande : 160m | 80m | 60m | 40m | 30m | 20m | 17m | 15m | 12m | 10m (défaut : 20m)",
     "cli.help.cwt_name"      => "Votre nom d'opérateur pour l'échange CWT (ex. HANS)",
     "cli.help.cwt_nr"        => "Votre numéro de membre CWT ou pays/état (ex. 1234 ou DL)",
+    "cli.help.my_spc"        => "Votre état, province ou pays pour sst-contest et les sprints SKCC/NAQCC/FISTS (ex. MA ou DL). Défaut : pays de votre indicatif.",
     "cli.help.my_dok"        => "Votre DOK DARC pour darc-cw-contest (ex. P53). Utilisez NM si non-membre.",
+    "cli.help.my_skcc"       => "Votre numéro SKCC avec suffixe C/T/S pour skcc-sprint (ex. 12345T). NONE si non-membre.",
+    "cli.help.my_naqcc"      => "Votre numéro NAQCC pour naqcc-sprint (ex. 4567). Les non-membres envoient leur puissance (ex. 5W).",
+    "cli.help.my_fists"      => "Votre numéro FISTS pour fists-sprint (ex. 17001). Les non-membres envoient leur puissance (ex. 100W).",
+    "cli.help.my_locator"    => "Votre locator Maidenhead à 6 caractères (ex. JO62QM) pour vhf-contest et QRB/azimut.",
+    "cli.help.call_pool"     => "Stations SIM tirées d'un fichier MASTER.SCP ou call-history N1MM au lieu d'indicatifs générés.",
+    "cli.help.seed"          => "Graine aléatoire — même graine et mêmes réglages : mêmes stations, échanges, fautes et pauses.",
+    "cli.help.exercise"      => "Pack d'exercice : fichier TOML réunissant graine, style, vitesse et autres réglages pour une classe.",
+    "cli.help.utc"           => "Horloge de la session, p. ex. 2024-06-15T14:00 (UTC) : propagation et date des radiogrammes. Défaut : maintenant, ou tirée de la graine en rejeu.",
+    "cli.help.no_personalities" => "Chaque station SIM utilise vitesse, délais et taux de fautes configurés — sans personnalités d'opérateur.",
+    "cli.help.no_adapt"      => "Tirer caractères, mots et stations uniformément — ignorer le plan de révision des caractères faibles",
     "cli.help.adapter"       => "Adaptateur manipulateur : auto | vband | attiny85 | arduino-nano | arduino-uno | esp32 | esp8266 | winkeyer | keyboard",
     "cli.help.port"          => "Port série pour arduino-nano, arduino-uno, esp32, esp8266 ou winkeyer (ex. /dev/ttyUSB0, COM3)",
     "cli.help.midi_port"     => "Nom ou fragment du port MIDI pour l'adaptateur ATtiny85 (remplace --port)",
@@ -209,11 +447,109 @@ lang_map!(Fr, "Français", [
     "cli.help.check_adapter" => "Tester l'adaptateur configuré : appuyer sur DIT puis DAH quand demandé",
     "cli.help.write_config"  => "Écrire le config.toml par défaut dans le chemin de configuration et quitter",
     "cli.help.print_config"  => "Afficher le config.toml intégré sur stdout et quitter",
+    "cli.help.stats"         => "Afficher la progression des sessions passées et quitter",
+    "cli.help.export"        => "Exporter l'historique vers FILE (CSV, ou JSON pour *.json) et quitter",
     "cli.help.demo"          => "Mode démo : jouer un QSO complet automatiquement (pas de manipulateur requis), puis attendre ESC",
     "cli.help.no_decode"     => "Masquer le décodage CW à l'écran — le QSO avance quand même ; utile pour s'auto-évaluer sans aide",
+    "cli.help.head_copy"     => "Lecture au son : masquer le décodage, puis interroger sur le nom, QTH, rig, RST, numéro et DOK de la SIM",
     "cli.help.keyer_only"    => "Mode manipulateur seul : affiche le décodage avec écoute de contrôle, sans simulation QSO — ESC pour quitter",
+    "cli.help.practice"      => "Exercice au lieu d'un QSO : koch | copy | rufz | send | icr",
+    "cli.help.koch_lesson"   => "Entraîneur Koch : commencer à la leçon N (1 = K M) au lieu de la leçon enregistrée",
+    "cli.help.copy_kind"     => "Ce que l'exercice de lecture envoie : calls | groups | numbers | phrases | text (défaut : groups)",
+    "cli.help.send_kind"     => "Ce que l'exercice de manipulation affiche : calls | groups | numbers | phrases | text (défaut : phrases)",
+    "cli.help.send_file"     => "Exercice de manipulation : manipuler plutôt les lignes de ce fichier texte",
+    "cli.help.icr_kind"      => "Ce que l'exercice ICR envoie : chars | words (défaut : chars)",
     "cli.help.version"       => "Afficher la version",
     "cli.help.help"          => "Afficher l'aide",
+    // ── Practice drills ───────────────────────────────────────────────────────
+    "practice.sent"          => "Envoyé",
+    "practice.copy"          => "Copie",
+    "practice.finish"        => "Terminez votre copie — Entrée quand c'est fini",
+    "practice.most_missed"   => "les plus manqués : {0}",
+    "koch.header"            => "Leçon Koch {0} sur {1}",
+    "koch.title"             => "KOCH — LEÇON {0}",
+    "koch.characters"        => "Caractères",
+    "koch.new_char"          => "Nouveau caractère",
+    "koch.listen"            => "Écoutez le nouveau caractère…",
+    "koch.copy"              => "Copiez les groupes au fur et à mesure…",
+    "koch.scored"            => "Manche notée",
+    "koch.unlocked"          => "{0} — leçon {1} débloquée, nouveau caractère {2}",
+    "koch.all_learnt"        => "{0} — les {1} caractères sont appris ; continuez ou augmentez la vitesse",
+    "koch.again"             => "{0} — il faut {1}% pour avancer ; même leçon encore",
+    "koch.next"              => "Entrée = manche suivante   Esc = quitter",
+    "koch.none"              => "Leçon Koch {0} ({1}) — aucune manche terminée",
+    "koch.summary"           => "Entraîneur Koch : {0} manches  {1}",
+    "koch.lesson"            => "leçon {0} → {1}",
+    "koch.next_time"         => "la prochaine fois : leçon {0} ({1})",
+    "practice.correct"       => "Justes {0}",
+    "practice.wrong"         => "Fausses {0}",
+    "practice.missed"        => "Manqués {0}",
+    "practice.extra"         => "En trop {0}",
+    "practice.lines"         => "Lignes",
+    "practice.accuracy"      => "précision",
+    "practice.worst"         => "Les plus manqués",
+    "practice.get_ready"     => "Préparez-vous…",
+    "practice.line_scored"   => "Ligne notée",
+    "practice.next_line"     => "Entrée = ligne suivante   Esc = quitter",
+    "copy.header"            => "Exercice de copie : {0}",
+    "copy.title"             => "COPIE — {0}",
+    "copy.listen"            => "Copiez ce que vous entendez…",
+    "copy.none"              => "Exercice de copie ({0}) — aucune ligne terminée",
+    "copy.summary"           => "Exercice de copie ({0}) : {1} lignes à {2}, {3}% de précision",
+    "copy.counts"            => "justes {0}  fausses {1}  manqués {2}  en trop {3}",
+    "practice.listen"        => "Écoutez…",
+    "practice.you"           => "vous : {0}",
+    "rufz.header"            => "Entraîneur d'indicatifs  |  {0} WPM  |  score {1}",
+    "rufz.title"             => "ENTRAÎNEUR D'INDICATIFS",
+    "rufz.call"              => "Indicatif",
+    "rufz.score"             => "Score",
+    "rufz.speed"             => "Vitesse",
+    "rufz.best_label"        => "Meilleur score",
+    "rufz.next"              => "Indicatif suivant…",
+    "rufz.type"              => "Tapez l'indicatif — Entrée",
+    "rufz.final"             => "Score final {0}  —  vitesse max {1} WPM",
+    "rufz.complete"          => "Série terminée — Esc pour quitter",
+    "rufz.none"              => "Entraîneur d'indicatifs — aucun indicatif répondu",
+    "rufz.summary"           => "Entraîneur d'indicatifs : score {0}",
+    "rufz.unfinished"        => "(série non terminée)",
+    "rufz.right"             => "{0} indicatifs justes sur {1}, {2} → {3} WPM, max {4} WPM",
+    "rufz.new_best"          => "nouveau record — précédent {0}",
+    "rufz.best"              => "meilleur score {0}",
+    "send.header"            => "Exercice de manipulation : {0}  |  manipulateur {1} WPM",
+    "send.title"             => "MANIPULATION — {0}",
+    "send.keyed"             => "Manipulé",
+    "send.spacing"           => "Espacement {0}",
+    "send.eff_speed"         => "Vitesse effective",
+    "send.ratio"             => "ta/ti",
+    "send.char_gap"          => "espace entre caractères",
+    "send.units"             => "{0} unités",
+    "send.blue"              => "Bleu : bien manipulé, mal espacé",
+    "send.mistakes"          => "fautes d'espacement",
+    "send.speed"             => "vitesse",
+    "send.key_this"          => "Manipulez ceci :",
+    "send.start"             => "Commencez à manipuler…",
+    "send.enter"             => "Entrée quand c'est fini",
+    "send.none"              => "Exercice de manipulation ({0}) — aucune ligne terminée",
+    "send.summary"           => "Exercice de manipulation ({0}) : {1} lignes, {2}% de précision",
+    "send.summary_speed"     => ", {0} WPM effectifs",
+    "send.counts"            => "justes {0}  fausses {1}  manqués {2}  en trop {3}  fautes d'espacement {4}",
+    "send.most_wrong"        => "le plus souvent faux : {0}",
+    "icr.header"             => "Exercice ICR : {0}",
+    "icr.title"              => "RECONNAISSANCE INSTANTANÉE — {0}",
+    "icr.answers"            => "Réponses",
+    "icr.right"              => "justes",
+    "icr.reaction"           => "réaction",
+    "icr.times"              => "ø {0} ms  médiane {1} ms",
+    "icr.slow_over"          => "lent >",
+    "icr.coming_back"        => "Reviennent",
+    "icr.slow"               => "lent",
+    "icr.next"               => "Suivant…",
+    "icr.now"                => "Tapez-le — maintenant !",
+    "icr.enter"              => "Tapez-le — Entrée",
+    "icr.none"               => "Exercice ICR ({0}) — aucune réponse",
+    "icr.summary"            => "Exercice ICR ({0}) : {1} réponses à {2}, {3}% justes, {4} trop tard",
+    "icr.reaction_time"      => "temps de réaction : moyenne {0} ms, médiane {1} ms",
+    "icr.slowest"            => "les plus lents : {0}",
 ]);
 
 // ── Italian ───────────────────────────────────────────────────────────────────
@@ -252,10 +588,22 @@ lang_map!(It, "Italiano", [
     "cli.help.farnsworth"    => "WPM Farnsworth effettivo — allunga gli spazi tra caratteri; 0 = disattivato (predefinito: 0)",
     "cli.help.tone"          => "Frequenza del tono di ascolto in Hz",
     "cli.help.who_starts"    => "Chi inizia il QSO: me | sim",
-    "cli.help.style"         => "Stile QSO: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | random",
+    "cli.help.style"         => "Stile QSO: ragchew | contest | dx-pileup | darc-cw-contest | mwc-contest | cwt-contest | wwa-contest | wpx-contest | cq-ww-contest | qtt-award | sst-contest | cq-dx | pota | sota | tota | cota | skcc-sprint | naqcc-sprint | fists-sprint | radiogram | vhf-contest | random",
+    "cli.help.band"          => "Banda: 160m | 80m | 60m | 40m | 30m | 20m | 17m | 15m | 12m | 10m (predefinita: 20m)",
     "cli.help.cwt_name"      => "Il tuo nome operatore per lo scambio CWT (es. HANS)",
     "cli.help.cwt_nr"        => "Il tuo numero di membro CWT o stato/paese (es. 1234 o DL)",
+    "cli.help.my_spc"        => "Il tuo stato, provincia o paese per sst-contest e gli sprint SKCC/NAQCC/FISTS (es. MA o DL). Predefinito: paese del tuo nominativo.",
     "cli.help.my_dok"        => "Il tuo DOK DARC per darc-cw-contest (es. P53). Usa NM se non sei membro.",
+    "cli.help.my_skcc"       => "Il tuo numero SKCC con suffisso C/T/S per skcc-sprint (es. 12345T). NONE se non sei membro.",
+    "cli.help.my_naqcc"      => "Il tuo numero NAQCC per naqcc-sprint (es. 4567). I non membri inviano la potenza (es. 5W).",
+    "cli.help.my_fists"      => "Il tuo numero FISTS per fists-sprint (es. 17001). I non membri inviano la potenza (es. 100W).",
+    "cli.help.my_locator"    => "Il tuo locatore Maidenhead a 6 caratteri (es. JO62QM) per vhf-contest e QRB/azimut.",
+    "cli.help.call_pool"     => "Stazioni SIM prese da un file MASTER.SCP o call-history N1MM invece di nominativi generati.",
+    "cli.help.seed"          => "Seme casuale — stesso seme e stesse impostazioni: stesse stazioni, scambi, errori e pause.",
+    "cli.help.exercise"      => "Pacchetto esercizio: file TOML con seme, stile, velocità e altre impostazioni per una classe.",
+    "cli.help.utc"           => "Orologio della sessione, es. 2024-06-15T14:00 (UTC): propagazione e data dei radiogrammi. Predefinito: adesso, o dal seme in una ripetizione.",
+    "cli.help.no_personalities" => "Ogni stazione SIM usa velocità, ritardi e tasso di errori configurati — senza personalità degli operatori.",
+    "cli.help.no_adapt"      => "Estrai caratteri, parole e stazioni in modo uniforme — ignora il piano di ripasso dei caratteri deboli",
     "cli.help.adapter"       => "Adattatore manipolatore: auto | vband | attiny85 | arduino-nano | arduino-uno | esp32 | esp8266 | winkeyer | keyboard",
     "cli.help.port"          => "Porta seriale per arduino-nano, arduino-uno, esp32, esp8266 o winkeyer (es. /dev/ttyUSB0, COM3)",
     "cli.help.midi_port"     => "Nome o frammento della porta MIDI per l'adattatore ATtiny85 (sovrascrive --port)",
@@ -266,9 +614,107 @@ lang_map!(It, "Italiano", [
     "cli.help.check_adapter" => "Testa l'adattatore configurato: premi DIT poi DAH quando richiesto",
     "cli.help.write_config"  => "Scrivi il config.toml predefinito nel percorso di configurazione ed esci",
     "cli.help.print_config"  => "Stampa il config.toml integrato su stdout ed esci",
+    "cli.help.stats"         => "Mostra i progressi delle sessioni precedenti ed esci",
+    "cli.help.export"        => "Esporta lo storico in FILE (CSV, o JSON per *.json) ed esci",
     "cli.help.demo"          => "Modalità demo: esegui un QSO completo automaticamente (nessun manipolatore necessario), poi attendi ESC",
     "cli.help.no_decode"     => "Nasconde la decodifica CW a schermo — il QSO avanza normalmente; utile per auto-valutarsi senza supporto",
+    "cli.help.head_copy"     => "Ricezione a mente: nasconde la decodifica, poi chiede nome, QTH, rig, RST, numero e DOK della SIM",
     "cli.help.keyer_only"    => "Modalità solo tasto: mostra la decodifica con tono di ascolto, senza simulazione QSO — ESC per uscire",
+    "cli.help.practice"      => "Esercizio invece di un QSO: koch | copy | rufz | send | icr",
+    "cli.help.koch_lesson"   => "Allenatore Koch: inizia dalla lezione N (1 = K M) invece di quella salvata",
+    "cli.help.copy_kind"     => "Cosa invia l'esercizio di ricezione: calls | groups | numbers | phrases | text (predefinito: groups)",
+    "cli.help.send_kind"     => "Cosa mostra l'esercizio di trasmissione: calls | groups | numbers | phrases | text (predefinito: phrases)",
+    "cli.help.send_file"     => "Esercizio di trasmissione: trasmetti invece le righe di questo file di testo",
+    "cli.help.icr_kind"      => "Cosa invia l'esercizio ICR: chars | words (predefinito: chars)",
     "cli.help.version"       => "Mostra la versione",
     "cli.help.help"          => "Mostra l'aiuto",
+    // ── Practice drills ───────────────────────────────────────────────────────
+    "practice.sent"          => "Inviato",
+    "practice.copy"          => "Copia",
+    "practice.finish"        => "Completa la copia — Invio quando hai finito",
+    "practice.most_missed"   => "più mancati: {0}",
+    "koch.header"            => "Lezione Koch {0} di {1}",
+    "koch.title"             => "KOCH — LEZIONE {0}",
+    "koch.characters"        => "Caratteri",
+    "koch.new_char"          => "Nuovo carattere",
+    "koch.listen"            => "Ascolta il nuovo carattere…",
+    "koch.copy"              => "Copia i gruppi man mano che arrivano…",
+    "koch.scored"            => "Turno valutato",
+    "koch.unlocked"          => "{0} — lezione {1} sbloccata, nuovo carattere {2}",
+    "koch.all_learnt"        => "{0} — tutti i {1} caratteri imparati; continua a esercitarti o aumenta la velocità",
+    "koch.again"             => "{0} — serve il {1}% per avanzare; stessa lezione ancora",
+    "koch.next"              => "Invio = turno successivo   Esc = uscita",
+    "koch.none"              => "Lezione Koch {0} ({1}) — nessun turno completato",
+    "koch.summary"           => "Trainer Koch: {0} turni  {1}",
+    "koch.lesson"            => "lezione {0} → {1}",
+    "koch.next_time"         => "la prossima volta: lezione {0} ({1})",
+    "practice.correct"       => "Giusti {0}",
+    "practice.wrong"         => "Sbagliati {0}",
+    "practice.missed"        => "Mancati {0}",
+    "practice.extra"         => "In più {0}",
+    "practice.lines"         => "Righe",
+    "practice.accuracy"      => "precisione",
+    "practice.worst"         => "Più mancati",
+    "practice.get_ready"     => "Preparati…",
+    "practice.line_scored"   => "Riga valutata",
+    "practice.next_line"     => "Invio = riga successiva   Esc = uscita",
+    "copy.header"            => "Esercizio di copia: {0}",
+    "copy.title"             => "COPIA — {0}",
+    "copy.listen"            => "Copia ciò che senti…",
+    "copy.none"              => "Esercizio di copia ({0}) — nessuna riga completata",
+    "copy.summary"           => "Esercizio di copia ({0}): {1} righe a {2}, precisione {3}%",
+    "copy.counts"            => "giusti {0}  sbagliati {1}  mancati {2}  in più {3}",
+    "practice.listen"        => "Ascolta…",
+    "practice.you"           => "tu: {0}",
+    "rufz.header"            => "Trainer nominativi  |  {0} WPM  |  punteggio {1}",
+    "rufz.title"             => "TRAINER NOMINATIVI",
+    "rufz.call"              => "Nominativo",
+    "rufz.score"             => "Punteggio",
+    "rufz.speed"             => "Velocità",
+    "rufz.best_label"        => "Record",
+    "rufz.next"              => "Prossimo nominativo…",
+    "rufz.type"              => "Scrivi il nominativo — Invio",
+    "rufz.final"             => "Punteggio finale {0}  —  velocità massima {1} WPM",
+    "rufz.complete"          => "Serie completata — Esc per uscire",
+    "rufz.none"              => "Trainer nominativi — nessun nominativo risposto",
+    "rufz.summary"           => "Trainer nominativi: punteggio {0}",
+    "rufz.unfinished"        => "(serie non completata)",
+    "rufz.right"             => "{0} nominativi giusti su {1}, {2} → {3} WPM, massima {4} WPM",
+    "rufz.new_best"          => "nuovo record — precedente {0}",
+    "rufz.best"              => "record finora {0}",
+    "send.header"            => "Esercizio di trasmissione: {0}  |  tasto {1} WPM",
+    "send.title"             => "TRASMISSIONE — {0}",
+    "send.keyed"             => "Trasmesso",
+    "send.spacing"           => "Spaziatura {0}",
+    "send.eff_speed"         => "Velocità effettiva",
+    "send.ratio"             => "linea/punto",
+    "send.char_gap"          => "spazio tra caratteri",
+    "send.units"             => "{0} unità",
+    "send.blue"              => "Blu: trasmesso giusto, spaziato male",
+    "send.mistakes"          => "errori di spaziatura",
+    "send.speed"             => "velocità",
+    "send.key_this"          => "Trasmetti questo:",
+    "send.start"             => "Inizia a trasmettere…",
+    "send.enter"             => "Invio quando hai finito",
+    "send.none"              => "Esercizio di trasmissione ({0}) — nessuna riga completata",
+    "send.summary"           => "Esercizio di trasmissione ({0}): {1} righe, precisione {2}%",
+    "send.summary_speed"     => ", effettivi {0} WPM",
+    "send.counts"            => "giusti {0}  sbagliati {1}  mancati {2}  in più {3}  errori di spaziatura {4}",
+    "send.most_wrong"        => "più spesso sbagliati: {0}",
+    "icr.header"             => "Esercizio ICR: {0}",
+    "icr.title"              => "RICONOSCIMENTO ISTANTANEO — {0}",
+    "icr.answers"            => "Risposte",
+    "icr.right"              => "giuste",
+    "icr.reaction"           => "reazione",
+    "icr.times"              => "ø {0} ms  mediana {1} ms",
+    "icr.slow_over"          => "lento >",
+    "icr.coming_back"        => "Ritornano",
+    "icr.slow"               => "lento",
+    "icr.next"               => "Avanti…",
+    "icr.now"                => "Scrivilo — subito!",
+    "icr.enter"              => "Scrivilo — Invio",
+    "icr.none"               => "Esercizio ICR ({0}) — nessuna risposta",
+    "icr.summary"            => "Esercizio ICR ({0}): {1} risposte a {2}, {3}% giuste, {4} in ritardo",
+    "icr.reaction_time"      => "tempo di reazione: media {0} ms, mediana {1} ms",
+    "icr.slowest"            => "i più lenti: {0}",
 ]);
@@ -11,8 +11,14 @@ pub mod nano;
 #[cfg(feature = "keyer-winkeyer")]
 pub mod winkeyer;
 
+use crate::audio::AudioOutput;
+use crate::morse::Decoder;
 use crate::morse::decoder::PaddleEvent;
 use anyhow::Result;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 #[cfg(feature = "keyer-vband")]
 use hidapi;
 
@@ -341,3 +347,73 @@ pub fn create_keyer(
         }
     }
 }
+
+// ── Keying: polling thread + sidetone ────────────────────────────────────────
+
+/// A keyer polled on its own thread.  Every dit and dah it reports sounds the
+/// sidetone and goes to the decoder; the QSO loop and the practice drills
+/// both key through this.
+pub struct Keying {
+    rx_key:      Receiver<(bool, Duration)>,
+    /// None when the sidetone is off
+    tx_sidetone: Option<Sender<bool>>,
+}
+
+impl Keying {
+    /// Poll `keyer` every 2 ms; a dit lasts `dot`, a dah three.  With
+    /// `sidetone` the elements sound on `audio`.
+    pub fn start(mut keyer: Box<dyn KeyerInput>, dot: Duration,
+                 audio: &Arc<Mutex<Box<dyn AudioOutput>>>, sidetone: bool) -> Self {
+        // For the keyboard stub this thread runs but sends nothing (poll() = None)
+        let (tx_key, rx_key) = mpsc::channel::<(bool, Duration)>();
+        thread::spawn(move || {
+            loop {
+                match keyer.poll() {
+                    PaddleEvent::DitDown => { let _ = tx_key.send((false, dot)); }
+                    PaddleEvent::DahDown => { let _ = tx_key.send((true,  dot * 3)); }
+                    _ => {}
+                }
+                thread::sleep(Duration::from_millis(2));
+            }
+        });
+
+        // The sidetone uses its OWN lock attempt so it never blocks the loop:
+        // while the SIM's playback holds the audio mutex, try_lock fails and
+        // the sidetone command is simply dropped.
+        let tx_sidetone = sidetone.then(|| {
+            let (tx, rx) = mpsc::channel::<bool>();
+            let audio = Arc::clone(audio);
+            thread::spawn(move || {
+                while let Ok(on) = rx.recv() {
+                    if let Ok(mut a) = audio.try_lock() {
+                        if on { let _ = a.tone_on();  }
+                        else  { let _ = a.tone_off(); }
+                    }
+                }
+            });
+            tx
+        });
+        Self { rx_key, tx_sidetone }
+    }
+
+    /// Sound and decode the elements keyed since the last call; returns how
+    /// many there were
+    pub fn drain(&self, decoder: &mut Decoder) -> usize {
+        let mut n = 0;
+        while let Ok((is_dah, el_dur)) = self.rx_key.try_recv() {
+            log::debug!("[keying] element: is_dah={} el_dur={:?}", is_dah, el_dur);
+            if let Some(tx) = &self.tx_sidetone {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    let _ = tx.send(true);
+                    thread::sleep(el_dur);
+                    let _ = tx.send(false);
+                });
+            }
+            // Pass el_dur so the decoder measures char_gap from the element END
+            decoder.push_element(is_dah, el_dur);
+            n += 1;
+        }
+        n
+    }
+}
@@ -1,21 +1,31 @@
 // src/main.rs  —  cw-qso-sim  entry point
 mod audio;
 mod config;
+mod headcopy;
+mod history;
 mod i18n;
 mod keyer;
 mod morse;
+mod practice;
 mod qso;
+mod report;
+mod schedule;
 mod tui;
 
 use anyhow::Result;
 use clap::Parser;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
 use config::{AppConfig, Cli};
-use morse::{Timing, Decoder};
+use morse::{Timing, Decoder, Fist};
 use qso::{QsoEngine, QsoEvent};
+use qso::log::SessionLog;
+use qso::propagation::Signal;
+use qso::scoring::{Scorer, ScoreSummary};
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 // ── Translated status messages ────────────────────────────────────────────────
 struct StatusMsg {
@@ -29,6 +39,7 @@ struct StatusMsg {
     listening:           &'static str,
     demo_complete:       &'static str,
     qso_complete:        &'static str,
+    next_station:        &'static str,
     repeating:           &'static str,
     keyer_only_listening: &'static str,
 }
@@ -47,6 +58,7 @@ impl StatusMsg {
                 listening:             "Warte auf dein Signal…",
                 demo_complete:         "DEMO ABGESCHLOSSEN — ESC zum Beenden",
                 qso_complete:          "QSO beendet — 73!",
+                next_station:          "QSO geloggt — nächste Station…",
                 repeating:             "Letzte Sendung wird wiederholt…",
                 keyer_only_listening:  "NUR TASTER — Dekodierung läuft… (ESC zum Beenden)",
             },
@@ -61,6 +73,7 @@ impl StatusMsg {
                 listening:             "En attente de votre signal…",
                 demo_complete:         "DÉMO TERMINÉE — ESC pour quitter",
                 qso_complete:          "QSO terminé — 73!",
+                next_station:          "QSO enregistré — station suivante…",
                 repeating:             "Répétition de la dernière émission…",
                 keyer_only_listening:  "MANIPULATEUR SEUL — Décodage en cours… (ESC pour quitter)",
             },
@@ -75,6 +88,7 @@ impl StatusMsg {
                 listening:             "In attesa del tuo segnale…",
                 demo_complete:         "DEMO COMPLETATA — ESC per uscire",
                 qso_complete:          "QSO terminato — 73!",
+                next_station:          "QSO registrato — prossima stazione…",
                 repeating:             "Ripetizione ultima trasmissione…",
                 keyer_only_listening:  "SOLO TASTO — Decodifica in corso… (ESC per uscire)",
             },
@@ -89,6 +103,7 @@ impl StatusMsg {
                 listening:             "Listening for your key…",
                 demo_complete:         "DEMO COMPLETE — Press ESC to exit",
                 qso_complete:          "QSO complete — 73!",
+                next_station:          "QSO logged — next station…",
                 repeating:             "Repeating last TX…",
                 keyer_only_listening:  "KEYER ONLY — Decoding your keying… (ESC to quit)",
             },
@@ -101,6 +116,8 @@ impl StatusMsg {
 pub struct AppState {
     pub mycall:       String,
     pub sim_call:     String,
+    /// DXCC entity, continent and zones of the sim station ("" if unknown)
+    pub sim_dxcc:     String,
     pub sim_wpm:      u8,
     pub user_wpm:     u8,
     pub tone_hz:      u32,
@@ -112,6 +129,14 @@ pub struct AppState {
     pub text_mode:    bool,
     pub demo:         bool,
     pub no_decode:    bool,
+    /// Distance (km) and beam heading (°) to the sim station, when both locators are known
+    pub qrb:          Option<(u32, u32)>,
+    /// Band and frequency of the QSO ("20m 14032.5kHz"), empty in keyer-only mode
+    pub qrg:          String,
+    /// Live contest score — None for styles that are not scored
+    pub score:        Option<ScoreSummary>,
+    /// The user's recent keying when the keying panel is shown (Tab)
+    pub keying:       Option<morse::timeline::Timeline>,
 }
 
 fn main() -> Result<()> {
@@ -143,6 +168,19 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    // ── --stats / --export  ───────────────────────────────────────────────────
+    if cli.stats || cli.export.is_some() {
+        let history = history::History::load()?;
+        if let Some(path) = &cli.export {
+            history.export(path)?;
+            println!("{} sessions exported to {}", history.session.len(), path.display());
+        }
+        if cli.stats {
+            print!("{}", history.stats());
+        }
+        return Ok(());
+    }
+
     // ── --list-ports  ─────────────────────────────────────────────────────────
     if cli.list_ports {
         let ports = keyer::list_ports();
@@ -231,6 +269,18 @@ fn main() -> Result<()> {
 
     // ── Load config ───────────────────────────────────────────────────────────
     let cfg = AppConfig::load(&cli)?;
+    if let Some(path) = &cfg.call_pool {
+        let n = qso::callpool::init(path)?;
+        log::info!("call pool: {n} calls from {}", path.display());
+    }
+    if cfg.adapt {
+        schedule::init();
+    }
+
+    // ── Practice drills run instead of a QSO ─────────────────────────────────
+    if let Some(mode) = cfg.practice {
+        return practice::run(&cfg, mode);
+    }
 
     // ── i18n / status messages ────────────────────────────────────────────────
     let _lang = i18n::I18n::new(&cfg.language);
@@ -255,23 +305,31 @@ fn main() -> Result<()> {
     // ── QSO engine ────────────────────────────────────────────────────────────
     // In --keyer-only mode no QSO engine is created — the sim station and all
     // exchange logic are simply absent.
-    // my_qso_serial starts at 1 and would increment across multiple QSOs in
-    // a future multi-QSO session.  For now one process = one QSO.
-    let my_qso_serial: u32 = 1;
+    // Scored (contest) styles run a session: after each QSO the next station
+    // is generated with the following serial number until the user quits.
+    // Unscored styles keep the one-process-one-QSO behaviour.
+    let mut session_log = SessionLog::new(cfg.seed);
+    let mut session_report = report::SessionReport::default();
+    let mut scorer = Scorer::new(cfg.qso_style, cfg.band, &cfg.mycall, cfg.who_starts == config::WhoStarts::Me);
     let mut engine: Option<QsoEngine> = if cfg.keyer_only {
         None
     } else {
-        Some(QsoEngine::new(&cfg, my_qso_serial))
+        Some(QsoEngine::new(&cfg, session_log.next_serial()))
     };
+    if let Some(e) = &engine {
+        sim_wpm_shared.store(e.sim_wpm(), Ordering::Relaxed);
+    }
 
     // ── Decoder (your keying) ─────────────────────────────────────────────────
     let mut decoder = Decoder::new(user_timing);
 
     // ── Shared app state ──────────────────────────────────────────────────────
+    let qrb = engine.as_ref().and_then(|e| e.qrb());
     let state = Arc::new(Mutex::new(AppState {
         mycall:    cfg.mycall.clone(),
         sim_call:  engine.as_ref().map_or(String::new(), |e| e.sim_callsign().to_string()),
-        sim_wpm:   cfg.sim_wpm,
+        sim_dxcc:  engine.as_ref().map_or(String::new(), |e| dxcc_line(e.sim_callsign())),
+        sim_wpm:   sim_wpm_shared.load(Ordering::Relaxed),
         user_wpm:  cfg.user_wpm,
         tone_hz:   cfg.tone_hz,
         status:    if cfg.keyer_only { sm.keyer_only_listening.into() }
@@ -280,6 +338,9 @@ fn main() -> Result<()> {
         text_mode: is_keyboard,
         demo:      cfg.demo,
         no_decode: cfg.no_decode,
+        qrb:       qrb.map(round_qrb),
+        qrg:       engine.as_ref().map_or(String::new(), qrg_line),
+        score:     scorer.as_ref().map(|s| s.summary().clone()),
         ..Default::default()
     }));
 
@@ -298,13 +359,16 @@ fn main() -> Result<()> {
     // knows exactly when the SIM has finished speaking.
     let audio_busy = Arc::new(AtomicBool::new(false));
     let audio_busy_audio = Arc::clone(&audio_busy);
-    let (tx_audio,      rx_audio)      = std::sync::mpsc::channel::<String>();
+    let (tx_audio,      rx_audio)      = std::sync::mpsc::channel::<(String, Fist, Signal)>();
     let (tx_audio_done, rx_audio_done) = std::sync::mpsc::channel::<()>();
     let audio_arc        = Arc::clone(&audio);
     let sim_wpm_audio    = Arc::clone(&sim_wpm_shared);
     let farnsworth_wpm   = cfg.farnsworth_wpm;   // fixed at startup; captured by audio thread
+    let volume           = cfg.volume;
+    // Fist jitter comes from the session seed too, so a replayed session sounds the same
+    let mut fist_rng     = SmallRng::seed_from_u64(cfg.seed);
     thread::spawn(move || {
-        while let Ok(text) = rx_audio.recv() {
+        while let Ok((text, fist, signal)) = rx_audio.recv() {
             let wpm    = sim_wpm_audio.load(Ordering::Relaxed);
             // Apply Farnsworth timing when requested: characters play at full `wpm`
             // speed but inter-character / word gaps are stretched to the slower
@@ -314,31 +378,24 @@ fn main() -> Result<()> {
             } else {
                 Timing::from_wpm(wpm)
             };
-            let seq    = morse::encode(&text, &timing);
+            let mut seq = morse::encode(&text, &timing);
+            fist.apply(&mut seq, &timing, &mut fist_rng);
+            // The SIM is as loud as its signal, with the band noise and QSB;
+            // the sidetone goes back to the configured volume afterwards
             let mut a = audio_arc.lock().unwrap();
+            a.set_volume(volume * signal.amplitude());
+            a.set_noise(volume * signal.noise());
+            a.set_fading(signal.qsb);
             let _ = a.play_sequence(&seq);
+            a.set_volume(volume);
+            a.set_noise(0.0);
+            a.set_fading(0.0);
             drop(a); // release mutex before signalling
             audio_busy_audio.store(false, Ordering::Relaxed);
             let _ = tx_audio_done.send(());
         }
     });
 
-    // ── Sidetone thread ───────────────────────────────────────────────────────
-    // Uses its OWN lock attempt so it never blocks the main loop.
-    // Sends (true=on, false=off).  The audio mutex may be held by the playback
-    // thread, so we use try_lock and simply drop the sidetone command if busy.
-    let (tx_sidetone, rx_sidetone) = std::sync::mpsc::channel::<bool>();
-    let audio_st = Arc::clone(&audio);
-    thread::spawn(move || {
-        while let Ok(on) = rx_sidetone.recv() {
-            // try_lock: if playback holds the mutex, skip sidetone silently
-            if let Ok(mut a) = audio_st.try_lock() {
-                if on { let _ = a.tone_on();  }
-                else  { let _ = a.tone_off(); }
-            }
-        }
-    });
-
     // ── Text-input state (adapter = text) ────────────────────────────────────
     // ── Keyboard text buffer (keyboard fallback mode) ─────────────────────────
     // When is_keyboard=true the user types characters directly.
@@ -348,25 +405,8 @@ fn main() -> Result<()> {
     let (tx_text, rx_text) = std::sync::mpsc::channel::<(String, bool)>();
     let mut kb_buf = String::new();  // accumulates typed chars between spaces/Enter
 
-    // ── Hardware keyer polling thread ─────────────────────────────────────────
-    // Sends (is_dah: bool, element_duration) to the main loop.
-    // For the keyboard stub this thread runs but sends nothing (poll() = None).
-    let (tx_key, rx_key) = std::sync::mpsc::channel::<(bool, std::time::Duration)>();
-    let tx_key_thread = tx_key.clone();
-    let mut keyer = keyer;
-    let dot_dur   = user_timing.dot;
-    thread::spawn(move || {
-        loop {
-            let ev = keyer.poll();
-            use morse::decoder::PaddleEvent::*;
-            match ev {
-                DitDown => { let _ = tx_key_thread.send((false, dot_dur)); }
-                DahDown => { let _ = tx_key_thread.send((true,  dot_dur * 3)); }
-                _ => {}
-            }
-            thread::sleep(Duration::from_millis(2));
-        }
-    });
+    // ── Hardware keyer polling thread + sidetone ─────────────────────────────
+    let keying = keyer::Keying::start(keyer, user_timing.dot, &audio, cfg.sidetone);
     // ── Main loop ─────────────────────────────────────────────────────────────
     let tick = Duration::from_millis(10);
     // Accumulates decoded chars across ticks.
@@ -391,6 +431,23 @@ fn main() -> Result<()> {
     // until the user presses ESC.
     let mut demo_complete: bool = false;
 
+    // ── Performance report state ──────────────────────────────────────────────
+    // Reply latency runs from the end of a SIM over to the user's first
+    // element or key; the current QSO is reported as unfinished on quit.
+    let mut qso_started                  = Instant::now();
+    let mut over_done_at: Option<Instant> = None;
+    let mut latencies: Vec<Duration>      = vec![];
+    let mut qso_open                      = engine.is_some();
+
+    // Head copy: what the SIM sent in the current QSO, and a quiz for each
+    // finished one, asked once the TUI has closed
+    let mut transcript: Vec<String>       = vec![];
+    let mut quizzes: Vec<headcopy::Quiz>  = vec![];
+
+    // Keying analysis panel: open from the start in keyer-only mode, Tab
+    // toggles it.  Typed text has no timing, so never in keyboard mode.
+    let mut show_keying = cfg.keyer_only && !is_keyboard;
+
     'main: loop {
         // ── Single crossterm event reader ─────────────────────────────────────
         // ALL events are read here — never in any other thread.
@@ -444,25 +501,17 @@ fn main() -> Result<()> {
                         if k.code == KeyCode::Char('q') || k.code == KeyCode::Char('Q') {
                             break 'main;
                         }
+                        if k.code == KeyCode::Tab {
+                            show_keying = !show_keying;
+                        }
                     }
                 }
             }
         }
 
         // Drain keyer events → sidetone + decoder
-        while let Ok((is_dah, el_dur)) = rx_key.try_recv() {
-            log::debug!("[main-loop] rx_key received: is_dah={} el_dur={:?}", is_dah, el_dur);
-            if cfg.sidetone {
-                let tx_st = tx_sidetone.clone();
-                thread::spawn(move || {
-                    let _ = tx_st.send(true);
-                    thread::sleep(el_dur);
-                    let _ = tx_st.send(false);
-                });
-            }
-            // Pass el_dur so the decoder measures char_gap from the element END
-            log::debug!("[main-loop] push_element: is_dah={} el_dur={:?}", is_dah, el_dur);
-            decoder.push_element(is_dah, el_dur);
+        if keying.drain(&mut decoder) > 0 {
+            if let Some(t) = over_done_at.take() { latencies.push(t.elapsed()); }
         }
 
         // Tick decoder — always run so the QSO engine can advance;
@@ -484,6 +533,7 @@ fn main() -> Result<()> {
         // Text-adapter injection — bypass CW decoder entirely
         let mut text_end_of_over = false;
         while let Ok((word, eoo)) = rx_text.try_recv() {
+            if let Some(t) = over_done_at.take() { latencies.push(t.elapsed()); }
             let entry = format!("{word} ");
             user_tx_acc.push_str(&entry);
             {
@@ -499,6 +549,8 @@ fn main() -> Result<()> {
             if eoo { text_end_of_over = true; }
         }
 
+        state.lock().unwrap().keying = show_keying.then(|| decoder.timeline().clone());
+
         // Update current_code display (suppressed when --no-decode is set)
         if !cfg.no_decode {
             let mut st = state.lock().unwrap();
@@ -510,12 +562,14 @@ fn main() -> Result<()> {
         }
 
         // ── QSO logic — skipped entirely in --keyer-only mode ─────────────────
-        if let Some(ref mut engine) = engine {
+        if let Some(ref mut eng) = engine {
             // ── Demo: audio-done → stage 2 ────────────────────────────────────────
             // Drain all done signals from the audio thread.  When we have a queued
             // response waiting (stage 1), promote it to stage 2 (timed delay).
-            if cfg.demo && !demo_complete {
-                while rx_audio_done.try_recv().is_ok() {
+            // Every done signal also starts the reply-latency clock.
+            while rx_audio_done.try_recv().is_ok() {
+                over_done_at = Some(Instant::now());
+                if cfg.demo && !demo_complete {
                     if let Some(resp) = demo_queued_response.take() {
                         let fire_at = std::time::Instant::now()
                             + Duration::from_millis(600);
@@ -533,6 +587,7 @@ fn main() -> Result<()> {
                 if let Some((ref fire_at, ref resp)) = demo_pending {
                     if std::time::Instant::now() >= *fire_at {
                         let resp = resp.clone();
+                        if let Some(t) = over_done_at.take() { latencies.push(t.elapsed()); }
                         // Show in the YOUR INPUT panel
                         {
                             let mut st = state.lock().unwrap();
@@ -621,6 +676,7 @@ fn main() -> Result<()> {
                         .any(|w| !filler.contains(&w));
                     if !has_content {
                         let ack = if has_qrs { "QRS QRS" } else { "QRQ QRQ" };
+                        transcript.push(ack.to_string());
                         {
                             let mut st = state.lock().unwrap();
                             if !cfg.no_decode {
@@ -630,18 +686,19 @@ fn main() -> Result<()> {
                             st.status = sm.transmitting.into();
                         }
                         audio_busy.store(true, Ordering::Relaxed);
-                        let _ = tx_audio.send(ack.to_string());
+                        let _ = tx_audio.send((ack.to_string(), eng.fist(), eng.signal()));
                     }
                 }
             }
 
-            let event = engine.tick(&input_to_pass);
+            let event = eng.tick(&input_to_pass);
             if end_of_over {
                 user_tx_acc.clear();
             }
 
             match event {
                 Some(QsoEvent::SimTransmit(text)) => {
+                    transcript.push(text.clone());
                     {
                         let mut st = state.lock().unwrap();
                         if !cfg.no_decode {
@@ -655,13 +712,13 @@ fn main() -> Result<()> {
                     // WaitingForUser (which fires on the very next tick) sees the
                     // correct state and knows to wait for the done signal.
                     audio_busy.store(true, Ordering::Relaxed);
-                    let _ = tx_audio.send(text);
+                    let _ = tx_audio.send((text, eng.fist(), eng.signal()));
                 }
                 Some(QsoEvent::WaitingForUser) => {
                     if cfg.demo && !demo_complete {
                         // Queue a response only once per waiting phase.
                         if demo_queued_response.is_none() && demo_pending.is_none() {
-                            if let Some(resp) = engine.demo_response() {
+                            if let Some(resp) = eng.demo_response() {
                                 if audio_busy.load(Ordering::Relaxed) {
                                     // SIM is still transmitting — park the response
                                     // here until rx_audio_done fires (stage 1).
@@ -685,12 +742,39 @@ fn main() -> Result<()> {
                     }
                 }
                 Some(QsoEvent::QsoComplete) => {
-                    if cfg.demo {
+                    let q = eng.logged_qso();
+                    let sent = std::mem::take(&mut transcript);
+                    if cfg.head_copy && !cfg.demo {
+                        quizzes.push(headcopy::Quiz::new(eng.exchange(), &q.mistakes, sent));
+                    }
+                    session_log.push(q.clone());
+                    session_report.push(report::QsoReport::new(
+                        &q, true, decoder.take_keying().summary(),
+                        std::mem::take(&mut latencies), qso_started.elapsed(),
+                    ));
+                    qso_started  = Instant::now();
+                    over_done_at = None;
+                    if let Some(sc) = scorer.as_mut() {
+                        // Contest session: log, score, and bring on the next station
+                        let summary = sc.add(&q).clone();
+                        *eng = QsoEngine::new(&cfg, session_log.next_serial());
+                        sim_wpm_shared.store(eng.sim_wpm(), Ordering::Relaxed);
+                        let mut st = state.lock().unwrap();
+                        st.score    = Some(summary);
+                        st.sim_wpm  = eng.sim_wpm();
+                        st.sim_call = eng.sim_callsign().to_string();
+                        st.sim_dxcc = dxcc_line(&st.sim_call);
+                        st.qrb      = eng.qrb().map(round_qrb);
+                        st.qrg      = qrg_line(eng);
+                        st.status   = sm.next_station.into();
+                    } else if cfg.demo {
                         // Keep the TUI alive — user reads the log then presses ESC
                         demo_complete = true;
+                        qso_open      = false;
                         let mut st = state.lock().unwrap();
                         st.status = sm.demo_complete.into();
                     } else {
+                        qso_open = false;
                         {
                             let mut st = state.lock().unwrap();
                             st.status = sm.qso_complete.into();
@@ -727,6 +811,96 @@ fn main() -> Result<()> {
     #[cfg(feature = "tui")]
     tui.cleanup();
 
+    // Head-copy quiz — the QSO the user quit in counts when the SIM sent something
+    if cfg.head_copy && !cfg.demo {
+        if let Some(eng) = engine.as_ref().filter(|_| qso_open && !transcript.is_empty()) {
+            quizzes.push(headcopy::Quiz::new(eng.exchange(), eng.mistakes(), std::mem::take(&mut transcript)));
+        }
+        if let Err(e) = headcopy::run(&quizzes) {
+            log::warn!("Head-copy quiz stopped: {e}");
+        }
+    }
+
+    if let Some(sc) = &scorer {
+        if !session_log.is_empty() {
+            println!("\nQSO log:");
+            for q in &session_log.qsos {
+                let busted = if q.busted.is_empty() { String::new() }
+                             else { format!("  ✗ {}", q.busted.join(" ")) };
+                println!("  {:03}  {:>4} {:>9.1}  {:<10} sent: {:<28} rcvd: {}{busted}",
+                         q.my_serial, q.band, q.qrg_khz, q.call, q.sent, q.rcvd);
+            }
+        }
+        let s = sc.summary();
+        println!("\nClaimed score: {}  ({} QSOs, {} points, {} mults)", s.claimed, s.qsos, s.points, s.mults);
+        if !s.mult_list.is_empty() {
+            println!("Multipliers:   {}", s.mult_list.join(" "));
+        }
+    }
+    // SIM sending mistakes — the uncorrected ones are what the user had to catch
+    let mistakes: Vec<_> = session_log.qsos.iter()
+        .flat_map(|q| q.mistakes.iter().map(move |m| (q.my_serial, &q.call, m)))
+        .collect();
+    if !mistakes.is_empty() {
+        println!("\nSIM sending errors:");
+        for (serial, call, m) in mistakes {
+            let how = match m.correction {
+                qso::typo::Correction::ErrorSign => "corrected with <HH>",
+                qso::typo::Correction::Dits      => "corrected with EEEEEEE",
+                qso::typo::Correction::None      => match m.catch() {
+                    Some(qso::typo::Catch::Caught) => "NOT corrected — you caught it",
+                    Some(qso::typo::Catch::Copied) => "NOT corrected — you copied it as sent",
+                    _                              => "NOT corrected — you did not send it back",
+                },
+            };
+            println!("  {serial:03}  {call:<10} {:<12} for {:<12} {how}", m.sent, m.intended);
+        }
+    }
+    // Performance report — the QSO the user quit in counts when they keyed in it
+    if let Some(eng) = engine.as_ref().filter(|_| qso_open) {
+        let keying = decoder.take_keying().summary();
+        if keying.chars + keying.undecodable > 0 || !latencies.is_empty() {
+            session_report.push(report::QsoReport::new(
+                &eng.logged_qso(), false, keying, latencies, qso_started.elapsed(),
+            ));
+        }
+    }
+    if !session_report.is_empty() {
+        println!("\n{}", session_report.render());
+        match session_report.write() {
+            Ok(path) => println!("Report written to {}", path.display()),
+            Err(e)   => log::warn!("Could not write the report: {e:#}"),
+        }
+        // Demos are not practice — only real sessions go into the history
+        if !cfg.demo {
+            if let Err(e) = history::History::append(history::SessionRecord::new(&cfg, &session_report)) {
+                log::warn!("Could not update the practice history: {e:#}");
+            }
+        }
+    }
+    if !cfg.keyer_only {
+        if !cfg.exercise.is_empty() {
+            println!("\nExercise: {}", cfg.exercise);
+        }
+        println!("\nSeed: {0}  (replay this session with --seed {0} --utc {1})",
+                 session_log.seed, report::utc_stamp(cfg.utc));
+    }
     println!("\n73 de cw-qso-sim! Good luck with the pile-ups.\n");
     Ok(())
 }
+
+/// "Fed. Rep. of Germany · EU · CQ 14 · ITU 28" for the SIM panel title.
+fn dxcc_line(call: &str) -> String {
+    qso::cty::lookup(call).map_or(String::new(), |i| format!(
+        "{} · {} · CQ {} · ITU {}", i.entity.name, i.continent, i.cq_zone, i.itu_zone))
+}
+
+/// "20m 14032.5kHz" for the header
+fn qrg_line(eng: &QsoEngine) -> String {
+    format!("{} {:.1}kHz", eng.band(), eng.qrg_khz())
+}
+
+/// Round a (km, bearing) pair for display; bearing 359.6° shows as 000°.
+fn round_qrb((km, deg): (f64, f64)) -> (u32, u32) {
+    (km.round() as u32, deg.round() as u32 % 360)
+}
@@ -0,0 +1,137 @@
+// src/morse/analysis.rs  —  Measure the user's keying: speed, dit/dah ratio, spacing
+//
+// The decoder reports every element as it starts and every character and
+// word boundary it finds.  From the start-to-start intervals we get:
+//   - the sending speed: nominal Morse units keyed ÷ time taken
+//   - the dit/dah ratio: a dit plus its gap is 2 units, a dah plus its gap 4,
+//     so ratio = 2 · dah period / dit period − 1 (3.0 is textbook)
+//   - character and word gaps in units, with their spread
+// Pauses longer than three word gaps (between overs) are not counted.
+use std::time::{Duration, Instant};
+use crate::report;
+
+/// Running measurements for one stretch of keying (usually one QSO)
+#[derive(Debug, Clone)]
+pub struct Keying {
+    /// Configured dit length — used for gaps until a dit has been measured
+    dot:         Duration,
+    /// Start, kind and nominal length of the last element
+    last:        Option<(Instant, bool, Duration)>,
+    /// Boundaries seen since the last element
+    char_ended:  bool,
+    word_ended:  bool,
+    dit_periods: Vec<f64>,
+    dah_periods: Vec<f64>,
+    /// Gaps in seconds, converted to units when summarised
+    char_gaps:   Vec<f64>,
+    word_gaps:   Vec<f64>,
+    /// Nominal units and seconds of everything counted for the speed
+    units:       f64,
+    secs:        f64,
+    chars:       u32,
+    undecodable: u32,
+}
+
+/// What `Keying` measured, ready for the report
+#[derive(Debug, Clone, Default)]
+pub struct KeyingSummary {
+    pub wpm:         Option<f64>,
+    pub ratio:       Option<f64>,
+    /// Mean and standard deviation in units (3 and 7 are textbook)
+    pub char_gap:    Option<(f64, f64)>,
+    pub word_gap:    Option<(f64, f64)>,
+    /// Characters decoded, and element patterns that were no character
+    pub chars:       u32,
+    pub undecodable: u32,
+}
+
+impl Keying {
+    pub fn new(dot: Duration) -> Self {
+        Self {
+            dot,
+            last:        None,
+            char_ended:  false,
+            word_ended:  false,
+            dit_periods: vec![],
+            dah_periods: vec![],
+            char_gaps:   vec![],
+            word_gaps:   vec![],
+            units:       0.0,
+            secs:        0.0,
+            chars:       0,
+            undecodable: 0,
+        }
+    }
+
+    /// An element started now; `dur` is its nominal length
+    pub fn element(&mut self, is_dash: bool, dur: Duration) {
+        let now = Instant::now();
+        if let Some((start, was_dash, was_dur)) = self.last {
+            let period = now.duration_since(start).as_secs_f64();
+            let gap    = (period - was_dur.as_secs_f64()).max(0.0);
+            let el     = if was_dash { 3.0 } else { 1.0 };
+            let pause  = gap > 21.0 * self.unit();
+            if !self.char_ended {
+                if was_dash { self.dah_periods.push(period) } else { self.dit_periods.push(period) }
+                self.count(el + 1.0, period);
+            } else if !self.word_ended {
+                self.char_gaps.push(gap);
+                self.count(el + 3.0, period);
+            } else if !pause {
+                self.word_gaps.push(gap);
+                self.count(el + 7.0, period);
+            }
+        }
+        self.last = Some((now, is_dash, dur));
+        self.char_ended = false;
+        self.word_ended = false;
+    }
+
+    /// The decoder closed a character; None when the pattern meant nothing
+    pub fn char_end(&mut self, decoded: Option<char>) {
+        self.char_ended = true;
+        match decoded {
+            Some(_) => self.chars += 1,
+            None    => self.undecodable += 1,
+        }
+    }
+
+    /// The decoder saw a word gap
+    pub fn word_end(&mut self) {
+        self.char_ended = true;
+        self.word_ended = true;
+    }
+
+    fn count(&mut self, units: f64, secs: f64) {
+        self.units += units;
+        self.secs  += secs;
+    }
+
+    /// One Morse unit: half the measured dit period, else the configured dit
+    fn unit(&self) -> f64 {
+        report::mean(self.dit_periods.iter().copied()).map_or(self.dot.as_secs_f64(), |p| p / 2.0)
+    }
+
+    pub fn summary(&self) -> KeyingSummary {
+        let unit = self.unit();
+        let in_units = |v: &[f64]| -> Vec<f64> { v.iter().map(|g| g / unit).collect() };
+        KeyingSummary {
+            // PARIS: 50 units per word, so 1 WPM is 1.2 s per unit
+            wpm:      (self.secs > 0.0).then(|| 1.2 * self.units / self.secs),
+            ratio:    report::mean(self.dit_periods.iter().copied())
+                          .zip(report::mean(self.dah_periods.iter().copied()))
+                          .map(|(dit, dah)| 2.0 * dah / dit - 1.0),
+            char_gap: spread(&in_units(&self.char_gaps)),
+            word_gap: spread(&in_units(&self.word_gaps)),
+            chars:       self.chars,
+            undecodable: self.undecodable,
+        }
+    }
+}
+
+/// Mean and standard deviation
+fn spread(v: &[f64]) -> Option<(f64, f64)> {
+    let m = report::mean(v.iter().copied())?;
+    let var = v.iter().map(|x| (x - m).powi(2)).sum::<f64>() / v.len() as f64;
+    Some((m, var.sqrt()))
+}
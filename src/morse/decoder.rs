@@ -1,5 +1,7 @@
 // src/morse/decoder.rs  —  Paddle timings → characters (Farnsworth aware)
 use crate::morse::Timing;
+use crate::morse::analysis::Keying;
+use crate::morse::timeline::Timeline;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +18,10 @@ pub struct Decoder {
     last_event:      Instant,
     last_char_flush: Option<Instant>,  // when the last char was flushed
     timing:          Timing,
+    /// Speed and spacing measurements of what has been keyed
+    keying:          Keying,
+    /// The last few hundred elements, for the keying panel
+    timeline:        Timeline,
 }
 
 impl Decoder {
@@ -25,6 +31,8 @@ impl Decoder {
             decoded_text:    String::new(),
             last_event:      Instant::now(),
             last_char_flush: None,
+            keying:          Keying::new(timing.dot),
+            timeline:        Timeline::new(timing.dot),
             timing,
         }
     }
@@ -39,6 +47,8 @@ impl Decoder {
     pub fn push_element(&mut self, is_dash: bool, el_dur: Duration) {
         if is_dash { self.current_code.push('-'); }
         else       { self.current_code.push('.'); }
+        self.keying.element(is_dash, el_dur);
+        self.timeline.element(is_dash, el_dur);
         // Advance last_event to the projected end of this element so that
         // char_gap / word_gap are measured from when the element finishes.
         self.last_event = Instant::now() + el_dur;
@@ -54,6 +64,8 @@ impl Decoder {
                 if flushed_at.elapsed() >= self.timing.word_gap {
                     self.last_char_flush = None;
                     self.decoded_text.push(' ');
+                    self.keying.word_end();
+                    self.timeline.word_end();
                     log::debug!("[decoder] word_gap → space");
                     return Some(" ".to_string());
                 }
@@ -68,6 +80,8 @@ impl Decoder {
             let c = self.flush_char();
             self.last_char_flush = None;
             self.decoded_text.push(' ');
+            self.keying.word_end();
+            self.timeline.word_end();
             return c.map(|ch| format!("{ch} "));
         }
         if elapsed >= self.timing.char_gap {
@@ -81,9 +95,19 @@ impl Decoder {
 
     fn flush_char(&mut self) -> Option<char> {
         let code = std::mem::take(&mut self.current_code);
-        decode_code(&code)
+        let c = decode_code(&code);
+        self.keying.char_end(c);
+        self.timeline.char_end();
+        c
     }
 
+    /// Measurements since the last call, and start measuring afresh
+    pub fn take_keying(&mut self) -> Keying {
+        std::mem::replace(&mut self.keying, Keying::new(self.timing.dot))
+    }
+
+    pub fn timeline(&self) -> &Timeline { &self.timeline }
+
     pub fn decoded_text(&self) -> &str { &self.decoded_text }
     pub fn current_code(&self) -> &str { &self.current_code }
 }
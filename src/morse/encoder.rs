@@ -37,6 +37,7 @@ pub fn prosign_to_morse(s: &str) -> Option<&'static str> {
         "<SK>"         => Some("...-.-"),
         "<KN>"         => Some("-.--."),
         "<BK>"         => Some("-...-.-"),
+        "<BT>"         => Some("-...-"),    // separator (radiogram text brackets)
         "<SOS>"        => Some("...---..."),
         "<HH>"         => Some("........"), // error
         _              => None,
@@ -0,0 +1,91 @@
+// src/morse/fist.rs  —  Human keying: how a SIM operator's timing deviates from PARIS
+//
+// `encode` produces perfect PARIS timing.  A `Fist` reworks that sequence the
+// way a hand on a key would: heavier or lighter weighting, a dah/dit ratio
+// other than 3:1, element jitter, the long dahs and machine-made dits of a
+// Vibroplex-style bug, and swing in the spacing between characters.
+use anyhow::{bail, Result};
+use rand::Rng;
+use serde::Deserialize;
+use std::time::Duration;
+use super::{Timing, ToneSeq};
+
+/// Keying of one operator.  All lengths are in dot units of the sending speed.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Fist {
+    /// Added to every mark and taken from the space after it:
+    /// +0.2 = heavy (fat dits, tight spacing), −0.2 = light / clipped
+    pub weight: f32,
+    /// Dah length in dots (3.0 = PARIS)
+    pub ratio:  f32,
+    /// Random spread of every mark and space, as a fraction of its length
+    pub jitter: f32,
+    /// Semi-automatic bug: dits and their spacing machine-perfect,
+    /// only the hand-made dahs carry ratio, weight and jitter
+    pub bug:    bool,
+    /// Random spread of character and word spacing (0.3 = ±30 %)
+    pub swing:  f32,
+}
+
+impl Default for Fist {
+    fn default() -> Self { Self::PERFECT }
+}
+
+impl Fist {
+    pub const PERFECT: Self = Self { weight: 0.0, ratio: 3.0, jitter: 0.0, bug: false, swing: 0.0 };
+
+    /// 1.0 = machine-perfect … 0.0 = very rough (±25 % on every element,
+    /// ±60 % on character spacing)
+    pub fn from_quality(quality: f32) -> Self {
+        let rough = 1.0 - quality.clamp(0.0, 1.0);
+        Self { jitter: rough * 0.25, swing: rough * 0.6, ..Self::PERFECT }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(-0.5..=0.5).contains(&self.weight)
+            || !(2.0..=5.0).contains(&self.ratio)
+            || !(0.0..=0.5).contains(&self.jitter)
+            || !(0.0..=1.0).contains(&self.swing)
+        {
+            bail!("fist: weight −0.5 – 0.5, ratio 2.0 – 5.0, jitter 0.0 – 0.5, swing 0.0 – 1.0");
+        }
+        Ok(())
+    }
+
+    /// Distort a sequence encoded with `timing` in place.  Marks are told
+    /// apart by length (dot / dash), spaces by theirs (element / char / word gap).
+    pub fn apply<R: Rng>(&self, seq: &mut ToneSeq, timing: &Timing, rng: &mut R) {
+        if *self == Self::PERFECT { return; }
+        let dot = timing.dot.as_secs_f32();
+        let mut jitter = |len: f32, spread: f32| {
+            len * (1.0 + rng.gen_range(-spread..=spread))
+        };
+        // Whether the previous mark was a dah — on a bug the gap after a
+        // dit is still made by the machine
+        let mut after_dah = false;
+        for (down, dur) in seq.iter_mut() {
+            let secs = if *down {
+                after_dah = *dur > timing.dot;
+                if after_dah {
+                    jitter((self.ratio + self.weight) * dot, self.jitter)
+                } else if self.bug {
+                    dot
+                } else {
+                    jitter((1.0 + self.weight) * dot, self.jitter)
+                }
+            } else if *dur == timing.elem_gap {
+                if self.bug && !after_dah {
+                    dot
+                } else {
+                    jitter((1.0 - self.weight) * dot, self.jitter)
+                }
+            } else {
+                // Character or word gap — the weight still comes out of it
+                jitter(dur.as_secs_f32() - self.weight * dot, self.swing.max(self.jitter))
+            };
+            // Never let a mark or space vanish entirely
+            *dur = Duration::from_secs_f32(secs.max(dot * 0.2));
+        }
+    }
+}
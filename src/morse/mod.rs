@@ -1,8 +1,12 @@
-// src/morse/mod.rs  —  Encoder, Decoder, Timing
+// src/morse/mod.rs  —  Encoder, Decoder, Timing, Fist, keying analysis and timeline
+pub mod analysis;
 pub mod encoder;
 pub mod decoder;
+pub mod fist;
+pub mod timeline;
 pub mod timing;
 
 pub use encoder::{encode, ToneSeq};
 pub use decoder::Decoder;
+pub use fist::Fist;
 pub use timing::Timing;
@@ -0,0 +1,172 @@
+// src/morse/timeline.rs  —  Rolling record of the user's keying for the TUI panel
+//
+// `Keying` boils a QSO down to a few averages; the timeline keeps the last
+// few hundred elements with the space before each, so the screen can draw
+// them against ideal PARIS timing and show how the lengths spread.  Lengths
+// are in Morse units at the configured keying speed (--user-wpm).
+//
+// The paddle reports when each element starts, and the keyer times the
+// element itself, so a dit or dah is measured start to start: the element
+// plus the space after it (2 and 4 units when textbook).  Spaces between
+// characters and words are measured from the end of one element to the
+// start of the next.  Pauses longer than three word gaps break the line.
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use crate::report;
+
+/// Elements kept
+const KEEP: usize = 400;
+/// Fewest measurements before a warning is given; word gaps come slower
+const MIN_SAMPLES: usize = 5;
+const MIN_WORDS:   usize = 3;
+
+/// What the decoder made of a space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gap {
+    /// Inside a character (1 unit)
+    Elem,
+    /// Between characters (3 units)
+    Char,
+    /// Between words (7 units)
+    Word,
+}
+
+impl Gap {
+    /// Textbook length in units
+    pub fn ideal(self) -> f64 {
+        match self { Gap::Elem => 1.0, Gap::Char => 3.0, Gap::Word => 7.0 }
+    }
+
+    /// Far enough off to mark on screen
+    pub fn off(self, units: f64) -> bool {
+        match self {
+            Gap::Elem => units >= 1.5,
+            Gap::Char => !(2.5..=4.5).contains(&units),
+            Gap::Word => !(5.5..=10.5).contains(&units),
+        }
+    }
+}
+
+/// Something off in the user's timing; the panel words it in their language
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Warning {
+    /// Gaps of 1.5 units or more inside a character, so many
+    Merged(usize),
+    /// Mean character spacing in units; `tight` when characters run together
+    CharSpacing { units: f64, tight: bool },
+    /// Mean word spacing in units
+    WordSpacing(f64),
+    /// Dah/dit ratio
+    Ratio(f64),
+}
+
+/// One keyed element
+#[derive(Debug, Clone, Copy)]
+pub struct Element {
+    pub dash: bool,
+    /// The space before it in units; None after a pause
+    pub gap:  Option<(Gap, f64)>,
+}
+
+impl Element {
+    /// Nominal length in units
+    pub fn units(&self) -> f64 { if self.dash { 3.0 } else { 1.0 } }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    dot:        Duration,
+    elements:   VecDeque<Element>,
+    /// Start and length of the last element
+    last:       Option<(Instant, Duration)>,
+    char_ended: bool,
+    word_ended: bool,
+}
+
+impl Timeline {
+    pub fn new(dot: Duration) -> Self {
+        Self { dot, elements: VecDeque::new(), last: None, char_ended: false, word_ended: false }
+    }
+
+    /// An element started now; `dur` is its nominal length
+    pub fn element(&mut self, dash: bool, dur: Duration) {
+        let now = Instant::now();
+        let dot = self.dot.as_secs_f64();
+        let gap = self.last.and_then(|(start, len)| {
+            let units = now.saturating_duration_since(start + len).as_secs_f64() / dot;
+            // The decoder only calls a word gap 10 units on (3 to close the
+            // character, 7 more), so a textbook 7 would pass for a character gap
+            let kind = if self.word_ended || (self.char_ended && units >= 5.0) { Gap::Word }
+                       else if self.char_ended { Gap::Char }
+                       else { Gap::Elem };
+            (units <= 21.0).then_some((kind, units))
+        });
+        if self.elements.len() == KEEP { self.elements.pop_front(); }
+        self.elements.push_back(Element { dash, gap });
+        self.last = Some((now, dur));
+        self.char_ended = false;
+        self.word_ended = false;
+    }
+
+    /// The decoder closed a character
+    pub fn char_end(&mut self) { self.char_ended = true; }
+
+    /// The decoder saw a word gap
+    pub fn word_end(&mut self) {
+        self.char_ended = true;
+        self.word_ended = true;
+    }
+
+    pub fn elements(&self) -> &VecDeque<Element> { &self.elements }
+
+    /// Dit and dah periods — element plus the space after it — in units
+    pub fn periods(&self) -> (Vec<f64>, Vec<f64>) {
+        let (mut dits, mut dahs) = (vec![], vec![]);
+        for (el, next) in self.elements.iter().zip(self.elements.iter().skip(1)) {
+            if let Some((Gap::Elem, g)) = next.gap {
+                if el.dash { dahs.push(el.units() + g) } else { dits.push(el.units() + g) }
+            }
+        }
+        (dits, dahs)
+    }
+
+    /// Spaces of one kind, in units
+    pub fn gaps(&self, kind: Gap) -> Vec<f64> {
+        self.elements.iter().filter_map(|e| e.gap).filter(|&(k, _)| k == kind).map(|(_, g)| g).collect()
+    }
+
+    /// What most needs fixing, worst first
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut out = vec![];
+        let (dits, dahs) = self.periods();
+        let chars = self.gaps(Gap::Char);
+        let words = self.gaps(Gap::Word);
+        let inner = self.gaps(Gap::Elem);
+
+        // A character gap the decoder missed shows up as a long gap inside a character
+        let merged = inner.iter().filter(|&&g| g >= 1.5).count();
+        if inner.len() >= MIN_SAMPLES && merged > 0 {
+            out.push(Warning::Merged(merged));
+        }
+        // Averages only once there are enough to go on
+        let avg = |v: &[f64], min: usize| report::mean(v.iter().copied()).filter(|_| v.len() >= min);
+        if let Some(m) = avg(&chars, MIN_SAMPLES) {
+            if !(2.5..=4.5).contains(&m) {
+                out.push(Warning::CharSpacing { units: m, tight: m < 2.5 });
+            }
+        }
+        if let Some(m) = avg(&words, MIN_WORDS) {
+            if !(5.5..=10.5).contains(&m) {
+                out.push(Warning::WordSpacing(m));
+            }
+        }
+        if let Some((dit, dah)) = avg(&dits, MIN_SAMPLES).zip(avg(&dahs, MIN_SAMPLES)) {
+            // Same measure as the QSO report: a dit period is 2 units, a dah period 4
+            let ratio = 2.0 * dah / dit - 1.0;
+            if !(2.5..=3.5).contains(&ratio) {
+                out.push(Warning::Ratio(ratio));
+            }
+        }
+        out
+    }
+}
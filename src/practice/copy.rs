@@ -0,0 +1,256 @@
+// src/practice/copy.rs  —  Receive-copy practice with a character-level diff
+//
+// The SIM sends a line of random text — callsigns, five-letter groups,
+// numbers, QSO phrases or a sentence from a small built-in corpus — and the
+// user types (or keys) what they heard.  The copy is aligned with what was
+// sent by edit distance, so a dropped character costs one miss instead of
+// shifting everything after it.  The diff shows right characters green,
+// wrong ones red, missed ones yellow and extra ones magenta; misses are
+// counted per character over the run.
+use anyhow::Result;
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+use crate::config::{AppConfig, CopyKind, PracticeMode};
+use crate::history::DrillRecord;
+use crate::i18n::I18n;
+use crate::morse::Timing;
+use crate::qso::callsigns::{self, RIGS, ANTENNAS, POWER};
+use crate::schedule;
+use super::{CharStats, Drill, Input, Line, Mark, Player, Tally, UserCopy, View};
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const DIGITS:  &[u8] = b"0123456789";
+
+/// Plain text to copy: short sentences using only characters Morse can send
+const CORPUS: &[&str] = &[
+    "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG.",
+    "THE BAND OPENED TO JAPAN JUST AFTER SUNSET.",
+    "MY FIRST RADIO WAS A KIT BUILT ON THE KITCHEN TABLE.",
+    "A GOOD FIST IS WORTH MORE THAN A HUNDRED WATTS.",
+    "THE STORM TOOK DOWN THE DIPOLE, SO WE USED A WIRE IN THE TREE.",
+    "LISTEN TWICE, SEND ONCE, AND ALWAYS SIGN YOUR CALL.",
+    "WE MET ON FORTY METRES AND HAVE TALKED EVERY SUNDAY SINCE.",
+    "THE CONTEST STARTS AT ZERO HUNDRED UTC ON SATURDAY.",
+    "COPY IN YOUR HEAD AND WRITE ONLY WHAT MATTERS.",
+    "THE LIGHTHOUSE KEEPER KNEW MORSE BETTER THAN ANYONE IN TOWN.",
+    "PLEASE SEND YOUR NAME AND QTH AGAIN, QSB IS BAD.",
+    "SHE WORKED ONE HUNDRED COUNTRIES WITH FIVE WATTS AND A LOOP.",
+    "THE SUMMIT WAS COLD, BUT THE PILE UP KEPT US WARM.",
+    "OLD TIMERS SAY THE CODE IS A LANGUAGE, NOT A PUZZLE.",
+    "IS THIS FREQUENCY IN USE? QRL?",
+    "THE KEY CLICKED ALL NIGHT WHILE THE RAIN FELL ON THE ROOF.",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Pause before the next line
+    Wait(Instant),
+    Copying,
+    Result,
+}
+
+pub struct CopyDrill {
+    kind:       CopyKind,
+    wpm:        u8,
+    timing:     Timing,
+    speed:      String,
+    rng:        SmallRng,
+    phase:      Phase,
+    sent:       String,
+    copy:       UserCopy,
+    /// Accuracy of every line this run
+    rounds:     Vec<f64>,
+    tally:      Tally,
+    chars_seen: CharStats,
+    result:     Vec<Line>,
+    i18n:       I18n,
+}
+
+impl CopyDrill {
+    pub fn new(cfg: &AppConfig) -> Self {
+        Self {
+            kind:       cfg.copy_kind,
+            wpm:        cfg.sim_wpm,
+            timing:     super::timing(cfg),
+            speed:      super::speed_label(cfg),
+            rng:        SmallRng::seed_from_u64(cfg.seed),
+            phase:      Phase::Wait(Instant::now() + Duration::from_secs(1)),
+            sent:       String::new(),
+            copy:       UserCopy::default(),
+            rounds:     vec![],
+            tally:      Tally::default(),
+            chars_seen: CharStats::default(),
+            result:     vec![],
+            i18n:       I18n::new(&cfg.language),
+        }
+    }
+
+    fn score(&mut self) {
+        let ops = super::align(&self.sent, &self.copy.text);
+        let t = Tally::of(&ops);
+        self.tally.add(t);
+        self.chars_seen.add(&ops);
+        if matches!(self.kind, CopyKind::Phrases | CopyKind::Text) { self.chars_seen.add_words(&ops); }
+        self.rounds.push(t.accuracy());
+
+        self.result = super::diff_lines(&ops, 60, &self.i18n);
+        self.result.extend([
+            vec![],
+            vec![
+                (format!("{}  ", self.i18n.f("practice.correct", &[&t.correct])), Mark::Good),
+                (format!("{}  ", self.i18n.f("practice.wrong", &[&t.substituted])), Mark::Bad),
+                (format!("{}  ", self.i18n.f("practice.missed", &[&t.missed])), Mark::Missed),
+                (format!("{}  ", self.i18n.f("practice.extra", &[&t.inserted])), Mark::Extra),
+                (format!("— {:.0}%", t.accuracy() * 100.0), Mark::Info),
+            ],
+            vec![(self.i18n.t("practice.next_line").into(), Mark::Dim)],
+        ]);
+        self.phase = Phase::Result;
+    }
+}
+
+/// One line of text of the given kind; characters and words due for review
+/// come up more often
+pub fn line<R: Rng>(kind: CopyKind, rng: &mut R) -> String {
+    let pick = |rng: &mut R, set: &[u8]| -> char {
+        schedule::choose(rng, set, |&b| char::from(b).to_string()).map_or('E', |&b| char::from(b))
+    };
+    match kind {
+        CopyKind::Calls => (0..5).map(|_| schedule::favour(rng, callsigns::random_station, |st| st.call.clone()).call)
+            .collect::<Vec<_>>().join(" "),
+        CopyKind::Groups => (0..5).map(|_| {
+            (0..5).map(|_| pick(rng, LETTERS)).collect::<String>()
+        }).collect::<Vec<_>>().join(" "),
+        CopyKind::Numbers => (0..6).map(|_| {
+            let len = rng.gen_range(2..=5);
+            (0..len).map(|_| pick(rng, DIGITS)).collect::<String>()
+        }).collect::<Vec<_>>().join(" "),
+        CopyKind::Phrases => {
+            let mut phrases: Vec<String> = (0..2).map(|_| schedule::favour(rng, phrase, String::clone)).collect();
+            phrases.dedup();
+            phrases.join(" ")
+        }
+        CopyKind::Text => schedule::choose(rng, CORPUS, |s| s.to_string()).copied().unwrap_or(CORPUS[0]).to_string(),
+    }
+}
+
+/// A QSO phrase with a random station's details
+fn phrase<R: Rng>(rng: &mut R) -> String {
+    let st = callsigns::random_station(rng);
+    let rst = ["599", "579", "559", "449", "339"].choose(rng).copied().unwrap_or("599");
+    match rng.gen_range(0..10) {
+        0 => format!("CQ CQ DE {} {} K", st.call, st.call),
+        1 => format!("UR RST {rst} {rst}"),
+        2 => format!("NAME IS {} {}", st.name, st.name),
+        3 => format!("QTH {} {}", st.qth, st.qth),
+        4 => format!("RIG {} ANT {}", RIGS.choose(rng).copied().unwrap_or("K3"),
+                     ANTENNAS.choose(rng).copied().unwrap_or("DIPOLE")),
+        5 => format!("PWR {}", POWER.choose(rng).copied().unwrap_or("100W")),
+        6 => "TNX FER CALL ES FB QSO".to_string(),
+        7 => format!("WX {} TEMP {}C", ["SUNNY", "CLOUDY", "RAIN", "SNOW", "WINDY"].choose(rng).copied().unwrap_or("SUNNY"),
+                     rng.gen_range(-5..32)),
+        8 => format!("{} DE {} GE OM", st.call, callsigns::random_station(rng).call),
+        _ => "73 ES GUD DX".to_string(),
+    }
+}
+
+pub fn kind_name(k: CopyKind) -> &'static str {
+    match k {
+        CopyKind::Calls   => "calls",
+        CopyKind::Groups  => "groups",
+        CopyKind::Numbers => "numbers",
+        CopyKind::Phrases => "phrases",
+        CopyKind::Text    => "text",
+    }
+}
+
+impl Drill for CopyDrill {
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()> {
+        match self.phase {
+            Phase::Wait(until) => if Instant::now() >= until {
+                self.sent = line(self.kind, &mut self.rng);
+                self.copy = UserCopy::default();
+                player.play(&self.sent, &self.timing);
+                self.phase = Phase::Copying;
+            },
+            Phase::Copying => if self.copy.step(player, input) {
+                self.score();
+            },
+            Phase::Result => if input.contains(&Input::Enter) {
+                self.phase = Phase::Wait(Instant::now() + Duration::from_millis(500));
+            },
+        }
+        Ok(())
+    }
+
+    fn view(&self) -> View {
+        let mut lines: Vec<Line> = vec![];
+        if !self.rounds.is_empty() {
+            lines.push(vec![
+                (format!("{} ", self.i18n.t("practice.lines")), Mark::Dim),
+                (format!("{}  ", self.rounds.len()), Mark::Plain),
+                (format!("{} ", self.i18n.t("practice.accuracy")), Mark::Dim),
+                (format!("{:.0}%", self.tally.accuracy() * 100.0), Mark::Info),
+            ]);
+        }
+        let worst = self.chars_seen.worst(8);
+        if !worst.is_empty() {
+            let mut l: Line = vec![(format!("{} ", self.i18n.t("practice.worst")), Mark::Dim)];
+            for (c, miss, sent) in worst {
+                l.push((format!("{c} "), Mark::Missed));
+                l.push((format!("{miss}/{sent}  "), Mark::Dim));
+            }
+            lines.push(l);
+        }
+        if !lines.is_empty() { lines.push(vec![]); }
+        let status = match self.phase {
+            Phase::Wait(_)                            => "practice.get_ready",
+            Phase::Copying if !self.copy.heard()      => "copy.listen",
+            Phase::Copying                            => "practice.finish",
+            Phase::Result => {
+                lines.extend(self.result.iter().cloned());
+                "practice.line_scored"
+            }
+        };
+        View {
+            header: format!(" {}  |  {}  |  {}", self.i18n.t("app.title"),
+                            self.i18n.f("copy.header", &[&kind_name(self.kind)]), self.speed),
+            title:  format!(" {} ", self.i18n.f("copy.title", &[&kind_name(self.kind).to_uppercase()])),
+            lines,
+            input:  if self.phase == Phase::Result { String::new() } else { self.copy.text.clone() },
+            status: self.i18n.t(status).into(),
+            ..Default::default()
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+        if self.rounds.is_empty() {
+            let _ = writeln!(out, "{}", self.i18n.f("copy.none", &[&kind_name(self.kind)]));
+            return out;
+        }
+        let t = &self.tally;
+        let _ = writeln!(out, "{}", self.i18n.f("copy.summary", &[&kind_name(self.kind), &self.rounds.len(),
+                                                 &self.speed, &format!("{:.0}", t.accuracy() * 100.0)]));
+        let _ = writeln!(out, "  {}", self.i18n.f("copy.counts", &[&t.correct, &t.substituted, &t.missed, &t.inserted]));
+        let worst: Vec<String> = self.chars_seen.worst(8).iter()
+            .map(|(c, miss, sent)| format!("{c} {miss}/{sent}")).collect();
+        if !worst.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("practice.most_missed", &[&worst.join("  ")]));
+        }
+        out
+    }
+
+    fn record(&self) -> Option<DrillRecord> {
+        (!self.rounds.is_empty()).then(|| DrillRecord {
+            kind:     kind_name(self.kind).to_string(),
+            rounds:   self.rounds.len() as u32,
+            accuracy: Some(self.tally.accuracy()),
+            ..DrillRecord::new(PracticeMode::Copy, self.wpm, &self.chars_seen)
+        })
+    }
+}
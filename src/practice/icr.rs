@@ -0,0 +1,354 @@
+// src/practice/icr.rs  —  Instant character recognition drill
+//
+// One character (or one short word with --icr-kind words) at a time, sent
+// at full --sim-wpm with a long pause after it: the Farnsworth word gap at
+// --farnsworth, or at 5 WPM when none is set.  The user types it as fast as
+// they can — a character is answered by its first key, a word by Enter or
+// Space — and the time from the end of the sound to the first key is the
+// reaction time.  Nothing typed within three seconds counts as missed.
+//
+// Every item has a weight in the queue the next one is drawn from, starting
+// from the review schedule (schedule.rs).  A miss adds 2, a slow answer
+// adds 1 and a quick right one takes a quarter off, so the characters the
+// user hesitates on come back until they stop being slow.
+// "Slow" is half again the user's median this run (at least 300 ms), or a
+// second until five answers are in.
+use anyhow::Result;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+use crate::config::{AppConfig, IcrKind, PracticeMode};
+use crate::history::DrillRecord;
+use crate::i18n::I18n;
+use crate::morse::Timing;
+use crate::report;
+use crate::schedule;
+use super::{CharStats, Drill, Input, Line, Mark, Player, View};
+
+/// Short words and abbreviations that should be read as a whole
+const WORDS: &[&str] = &[
+    "CQ", "DE", "K", "KN", "TU", "73", "88", "ES", "UR", "FB", "OM", "YL", "HR", "OP",
+    "RST", "5NN", "599", "QTH", "QSL", "QRZ", "QSB", "QRM", "NAME", "TNX", "FER", "PSE",
+    "AGN", "HW", "BK", "WX", "RIG", "ANT", "PWR", "GM", "GA", "GE", "GUD", "DX", "SRI",
+    "CUL", "BEST", "THE", "AND", "CALL", "TEST", "NR",
+];
+
+/// Spacing speed when no Farnsworth speed is set
+const GAP_WPM: u8 = 5;
+/// Nothing typed this long after the sound counts as missed
+const TIMEOUT: Duration = Duration::from_secs(3);
+/// "Slow" until there are enough answers for a median
+const SLOW_START_MS: u32 = 1000;
+const SLOW_FLOOR_MS: u32 = 300;
+const MEDIAN_AFTER:  usize = 5;
+const MAX_WEIGHT: f64 = 8.0;
+/// Answers shown on screen
+const SHOWN: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Pause before the next item
+    Wait(Instant),
+    /// Sending; keys typed now count as instant
+    Playing,
+    /// The sound has ended at this moment; waiting for the answer
+    Answer(Instant),
+}
+
+/// One character or word in the queue
+struct Item {
+    text:   String,
+    weight: f64,
+    /// Reaction times of right answers, ms
+    times:  Vec<u32>,
+}
+
+/// One answer of the run
+struct Answer {
+    item:  String,
+    given: String,
+    /// Reaction time; None when nothing was typed in time
+    ms:    Option<u32>,
+    right: bool,
+    slow:  bool,
+}
+
+pub struct Icr {
+    kind:       IcrKind,
+    wpm:        u8,
+    timing:     Timing,
+    /// Pause after each answer
+    gap:        Duration,
+    speed:      String,
+    rng:        SmallRng,
+    phase:      Phase,
+    items:      Vec<Item>,
+    /// Index of the item being sent
+    current:    usize,
+    typed:      String,
+    /// When the first key of this answer came
+    first_key:  Option<Instant>,
+    answers:    Vec<Answer>,
+    chars_seen: CharStats,
+    i18n:       I18n,
+}
+
+impl Icr {
+    pub fn new(cfg: &AppConfig) -> Self {
+        let texts: Vec<String> = match cfg.icr_kind {
+            IcrKind::Chars => super::koch::ORDER.iter().map(char::to_string).collect(),
+            IcrKind::Words => WORDS.iter().map(|w| w.to_string()).collect(),
+        };
+        let spacing = if cfg.farnsworth_wpm > 0 && cfg.farnsworth_wpm < cfg.sim_wpm { cfg.farnsworth_wpm }
+                      else { GAP_WPM.min(cfg.sim_wpm) };
+        Self {
+            kind:       cfg.icr_kind,
+            wpm:        cfg.sim_wpm,
+            timing:     super::timing(cfg),
+            gap:        Timing::farnsworth(cfg.sim_wpm, spacing).word_gap,
+            speed:      super::speed_label(cfg),
+            rng:        SmallRng::seed_from_u64(cfg.seed),
+            phase:      Phase::Wait(Instant::now() + Duration::from_secs(1)),
+            items:      texts.into_iter()
+                            .map(|text| Item { weight: schedule::weight(&text), text, times: vec![] })
+                            .collect(),
+            current:    0,
+            typed:      String::new(),
+            first_key:  None,
+            answers:    vec![],
+            chars_seen: CharStats::default(),
+            i18n:       I18n::new(&cfg.language),
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self.kind { IcrKind::Chars => "chars", IcrKind::Words => "words" }
+    }
+
+    /// Draw the next item by weight, never the same one twice running
+    fn next(&mut self) -> usize {
+        let last = (!self.answers.is_empty()).then_some(self.current);
+        let pool: Vec<usize> = (0..self.items.len()).filter(|&i| Some(i) != last).collect();
+        pool.choose_weighted(&mut self.rng, |&i| self.items[i].weight).copied().unwrap_or(0)
+    }
+
+    /// Reaction times of every right answer this run, ms
+    fn times(&self) -> Vec<u32> {
+        self.answers.iter().filter(|a| a.right).filter_map(|a| a.ms).collect()
+    }
+
+    /// Slower than this is slow
+    fn slow_ms(&self) -> u32 {
+        let times = self.times();
+        if times.len() < MEDIAN_AFTER { return SLOW_START_MS; }
+        (median(&times).unwrap_or(SLOW_START_MS) * 3 / 2).max(SLOW_FLOOR_MS)
+    }
+
+    fn answer(&mut self, heard: Instant) {
+        let ms = self.first_key.map(|k| k.saturating_duration_since(heard).as_millis() as u32);
+        let given = std::mem::take(&mut self.typed);
+        let slow_ms = self.slow_ms();
+        let item = &mut self.items[self.current];
+        let right = ms.is_some() && given == item.text;
+        let slow = right && ms.is_some_and(|t| t > slow_ms);
+
+        let ops = super::align(&item.text, &given);
+        self.chars_seen.add(&ops);
+        if self.kind == IcrKind::Words { self.chars_seen.add_words(&ops); }
+        if right { item.times.extend(ms); }
+        item.weight = if !right { (item.weight + 2.0).min(MAX_WEIGHT) }
+                      else if slow { (item.weight + 1.0).min(MAX_WEIGHT) }
+                      else { (item.weight * 0.75).max(1.0) };
+
+        self.answers.push(Answer { item: item.text.clone(), given, ms, right, slow });
+        self.first_key = None;
+        self.phase = Phase::Wait(Instant::now() + self.gap);
+    }
+
+    /// Keys typed while playing or answering; true once the answer is complete
+    fn take(&mut self, input: &[Input]) -> bool {
+        for &i in input {
+            match (i, self.kind) {
+                (Input::Char(c), IcrKind::Chars) => {
+                    self.first_key.get_or_insert_with(Instant::now);
+                    self.typed.push(c);
+                    return true;
+                }
+                (Input::Char(c), IcrKind::Words) => {
+                    self.first_key.get_or_insert_with(Instant::now);
+                    self.typed.push(c);
+                }
+                (Input::Backspace, _) => { self.typed.pop(); }
+                (Input::Enter | Input::Space, IcrKind::Words) if !self.typed.is_empty() => return true,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Items that come up more often than the rest, heaviest first
+    fn weak(&self, n: usize) -> Vec<&Item> {
+        let mut v: Vec<&Item> = self.items.iter().filter(|it| it.weight > 1.0).collect();
+        v.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        v.truncate(n);
+        v
+    }
+
+    /// Items with the slowest mean reaction, slowest first: (text, ms)
+    fn slowest(&self, n: usize) -> Vec<(&str, u32)> {
+        let mut v: Vec<(&str, u32)> = self.items.iter()
+            .filter_map(|it| mean_ms(&it.times).map(|m| (it.text.as_str(), m)))
+            .collect();
+        v.sort_by_key(|&(_, m)| std::cmp::Reverse(m));
+        v.truncate(n);
+        v
+    }
+}
+
+/// Mean of reaction times, ms
+fn mean_ms(v: &[u32]) -> Option<u32> {
+    report::mean(v.iter().map(|&t| f64::from(t))).map(|m| m.round() as u32)
+}
+
+fn median(v: &[u32]) -> Option<u32> {
+    let mut v = v.to_vec();
+    v.sort_unstable();
+    v.get(v.len() / 2).copied()
+}
+
+impl Drill for Icr {
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()> {
+        match self.phase {
+            Phase::Wait(until) => if Instant::now() >= until {
+                self.current = self.next();
+                self.typed.clear();
+                player.play(&self.items[self.current].text, &self.timing);
+                self.phase = Phase::Playing;
+            },
+            Phase::Playing => {
+                let done = self.take(input);
+                if !player.busy() || done {
+                    // Keys that beat the end of the sound count as 0 ms
+                    let heard = Instant::now();
+                    if done { self.answer(heard) } else { self.phase = Phase::Answer(heard) }
+                }
+            }
+            Phase::Answer(heard) => {
+                if self.take(input) || (self.first_key.is_none() && heard.elapsed() >= TIMEOUT) {
+                    self.answer(heard);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn view(&self) -> View {
+        let mut lines: Vec<Line> = vec![];
+        if !self.answers.is_empty() {
+            let right = self.answers.iter().filter(|a| a.right).count();
+            let mut l: Line = vec![
+                (format!("{} ", self.i18n.t("icr.answers")), Mark::Dim),
+                (format!("{}  ", self.answers.len()), Mark::Plain),
+                (format!("{} ", self.i18n.t("icr.right")), Mark::Dim),
+                (format!("{:.0}%  ", 100.0 * right as f64 / self.answers.len() as f64), Mark::Info),
+            ];
+            let times = self.times();
+            if let (Some(m), Some(md)) = (mean_ms(&times), median(&times)) {
+                l.push((format!("{} ", self.i18n.t("icr.reaction")), Mark::Dim));
+                l.push((format!("{}  ", self.i18n.f("icr.times", &[&m, &md])), Mark::Plain));
+            }
+            l.push((format!("{} ", self.i18n.t("icr.slow_over")), Mark::Dim));
+            l.push((format!("{} ms", self.slow_ms()), Mark::Plain));
+            lines.push(l);
+        }
+        let weak = self.weak(10);
+        if !weak.is_empty() {
+            let mut l: Line = vec![(format!("{} ", self.i18n.t("icr.coming_back")), Mark::Dim)];
+            for it in weak {
+                l.push((format!("{} ", it.text), Mark::Missed));
+                l.push((format!("×{:.1}  ", it.weight), Mark::Dim));
+            }
+            lines.push(l);
+        }
+        if !lines.is_empty() { lines.push(vec![]); }
+
+        let from = self.answers.len().saturating_sub(SHOWN);
+        for (i, a) in self.answers.iter().enumerate().skip(from) {
+            let time = a.ms.map_or("—".to_string(), |t| format!("{t} ms"));
+            let mark = if !a.right { Mark::Bad } else if a.slow { Mark::Missed } else { Mark::Good };
+            let mut l: Line = vec![
+                (format!("{:>4}  ", i + 1), Mark::Dim),
+                (format!("{:<6}", a.item), Mark::Plain),
+                (format!("{time:>8}  "), mark),
+                (if a.right { "✓" } else { "✗" }.to_string(), mark),
+            ];
+            if !a.right {
+                let given = if a.given.is_empty() { "—" } else { &a.given };
+                l.push((format!("   {}", self.i18n.f("practice.you", &[&given])), Mark::Bad));
+            } else if a.slow {
+                l.push((format!("   {}", self.i18n.t("icr.slow")), Mark::Missed));
+            }
+            lines.push(l);
+        }
+
+        let status = match self.phase {
+            Phase::Wait(_)   => "icr.next",
+            Phase::Playing   => "practice.listen",
+            Phase::Answer(_) => match self.kind {
+                IcrKind::Chars => "icr.now",
+                IcrKind::Words => "icr.enter",
+            },
+        };
+        View {
+            header: format!(" {}  |  {}  |  {}", self.i18n.t("app.title"),
+                            self.i18n.f("icr.header", &[&self.kind_name()]), self.speed),
+            title:  format!(" {} ", self.i18n.f("icr.title", &[&self.kind_name().to_uppercase()])),
+            lines,
+            input:  self.typed.clone(),
+            status: self.i18n.t(status).into(),
+            ..Default::default()
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+        if self.answers.is_empty() {
+            let _ = writeln!(out, "{}", self.i18n.f("icr.none", &[&self.kind_name()]));
+            return out;
+        }
+        let right = self.answers.iter().filter(|a| a.right).count();
+        let missed = self.answers.iter().filter(|a| a.ms.is_none()).count();
+        let pct = format!("{:.0}", 100.0 * right as f64 / self.answers.len() as f64);
+        let _ = writeln!(out, "{}", self.i18n.f("icr.summary", &[&self.kind_name(), &self.answers.len(),
+                                                 &self.speed, &pct, &missed]));
+        let times = self.times();
+        if let (Some(m), Some(md)) = (mean_ms(&times), median(&times)) {
+            let _ = writeln!(out, "  {}", self.i18n.f("icr.reaction_time", &[&m, &md]));
+        }
+        let slowest: Vec<String> = self.slowest(8).iter().map(|(t, m)| format!("{t} {m} ms")).collect();
+        if !slowest.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("icr.slowest", &[&slowest.join("  ")]));
+        }
+        let worst: Vec<String> = self.chars_seen.worst(8).iter()
+            .map(|(c, miss, sent)| format!("{c} {miss}/{sent}")).collect();
+        if !worst.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("practice.most_missed", &[&worst.join("  ")]));
+        }
+        out
+    }
+
+    fn record(&self) -> Option<DrillRecord> {
+        (!self.answers.is_empty()).then(|| {
+            let right = self.answers.iter().filter(|a| a.right).count();
+            DrillRecord {
+                kind:        self.kind_name().to_string(),
+                rounds:      self.answers.len() as u32,
+                accuracy:    Some(right as f64 / self.answers.len() as f64),
+                reaction_ms: mean_ms(&self.times()),
+                ..DrillRecord::new(PracticeMode::Icr, self.wpm, &self.chars_seen)
+            }
+        })
+    }
+}
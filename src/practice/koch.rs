@@ -0,0 +1,267 @@
+// src/practice/koch.rs  —  Koch method trainer
+//
+// Lesson 1 is K and M at full character speed; every lesson adds one
+// character, in the order LCWO and most Koch courses use.  A round is ten
+// five-character groups with Farnsworth spacing (--sim-wpm / --farnsworth);
+// the user copies them on the keyboard or the paddle, and 90 % or better
+// unlocks the next lesson.  The lesson is kept in <config dir>/koch.toml.
+use anyhow::{Context, Result};
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use crate::config::{AppConfig, PracticeMode};
+use crate::history::DrillRecord;
+use crate::i18n::I18n;
+use crate::morse::Timing;
+use crate::morse::encoder::char_to_morse;
+use crate::schedule;
+use super::{CharStats, Drill, Input, Line, Mark, Player, Tally, UserCopy, View};
+
+/// Koch order: lesson n teaches the first n + 1 characters
+pub const ORDER: &[char] = &[
+    'K', 'M', 'U', 'R', 'E', 'S', 'N', 'A', 'P', 'T', 'L', 'W', 'I', '.', 'J', 'Z',
+    '=', 'F', 'O', 'Y', ',', 'V', 'G', '5', '/', 'Q', '9', '2', 'H', '3', '8', 'B',
+    '?', '4', '7', 'C', '1', 'D', '6', '0', 'X',
+];
+pub const LAST_LESSON: usize = ORDER.len() - 1;
+
+const GROUPS:    usize = 10;
+const GROUP_LEN: usize = 5;
+/// Accuracy that unlocks the next lesson
+const PASS: f64 = 0.90;
+/// The newest character turns up this often, so it gets learnt
+const NEW_SHARE: f64 = 0.3;
+
+/// What is saved between runs
+#[derive(Debug, Serialize, Deserialize)]
+struct Progress {
+    lesson: usize,
+}
+
+impl Progress {
+    fn path() -> PathBuf {
+        crate::config::config_dir().join("koch.toml")
+    }
+
+    fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() { return Ok(Self { lesson: 1 }); }
+        let raw = std::fs::read_to_string(&path)
+            .with_context(|| format!("Reading {:?}", path))?;
+        toml::from_str(&raw).with_context(|| format!("Parsing {:?}", path))
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Creating {:?}", dir))?;
+        }
+        std::fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Writing {:?}", path))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Pause, then introduce the new character or send a round
+    Wait { until: Instant, intro: bool },
+    Intro,
+    /// Round sending; the user copies
+    Copying,
+    Result,
+}
+
+pub struct Koch {
+    lesson:      usize,
+    first:       usize,
+    wpm:         u8,
+    timing:      Timing,
+    speed:       String,
+    rng:         SmallRng,
+    phase:       Phase,
+    sent:        String,
+    copy:        UserCopy,
+    /// Lesson and accuracy of every round this run
+    rounds:      Vec<(usize, f64)>,
+    tally:       Tally,
+    chars_seen:  CharStats,
+    /// Result screen: the copy against what was sent, and the verdict
+    result:      Vec<Line>,
+    i18n:        I18n,
+}
+
+impl Koch {
+    pub fn new(cfg: &AppConfig) -> Result<Self> {
+        let mut progress = Progress::load()?;
+        if let Some(n) = cfg.koch_lesson {
+            progress.lesson = n;
+            progress.save()?;
+        }
+        let lesson = progress.lesson.clamp(1, LAST_LESSON);
+        Ok(Self {
+            lesson,
+            first:      lesson,
+            wpm:        cfg.sim_wpm,
+            timing:     super::timing(cfg),
+            speed:      super::speed_label(cfg),
+            rng:        SmallRng::seed_from_u64(cfg.seed),
+            phase:      Phase::Wait { until: Instant::now() + Duration::from_secs(1), intro: true },
+            sent:       String::new(),
+            copy:       UserCopy::default(),
+            rounds:     vec![],
+            tally:      Tally::default(),
+            chars_seen: CharStats::default(),
+            result:     vec![],
+            i18n:       I18n::new(&cfg.language),
+        })
+    }
+
+    fn chars(&self) -> &'static [char] { &ORDER[..=self.lesson] }
+
+    /// Characters this lesson introduces: K and M in lesson 1, then one
+    fn new_chars(&self) -> &'static [char] {
+        if self.lesson == 1 { &ORDER[..2] } else { &ORDER[self.lesson..=self.lesson] }
+    }
+
+    fn groups(&mut self) -> String {
+        let chars = self.chars();
+        let newest = chars[chars.len() - 1];
+        let mut groups = Vec::with_capacity(GROUPS);
+        for _ in 0..GROUPS {
+            let g: String = (0..GROUP_LEN).map(|_| {
+                if self.lesson > 1 && self.rng.gen_bool(NEW_SHARE) { newest }
+                else { schedule::choose(&mut self.rng, chars, char::to_string).copied().unwrap_or(newest) }
+            }).collect();
+            groups.push(g);
+        }
+        groups.join(" ")
+    }
+
+    fn score(&mut self) -> Result<()> {
+        let ops = super::align(&self.sent, &self.copy.text);
+        let tally = Tally::of(&ops);
+        let acc = tally.accuracy();
+        self.tally.add(tally);
+        self.chars_seen.add(&ops);
+        self.rounds.push((self.lesson, acc));
+
+        let pct = format!("{:.0}%", acc * 100.0);
+        let verdict = if acc >= PASS && self.lesson < LAST_LESSON {
+            self.lesson += 1;
+            Progress { lesson: self.lesson }.save()?;
+            (self.i18n.f("koch.unlocked", &[&pct, &self.lesson, &ORDER[self.lesson]]), Mark::Good)
+        } else if acc >= PASS {
+            (self.i18n.f("koch.all_learnt", &[&pct, &ORDER.len()]), Mark::Good)
+        } else {
+            (self.i18n.f("koch.again", &[&pct, &format!("{:.0}", PASS * 100.0)]), Mark::Bad)
+        };
+        self.result = super::diff_lines(&ops, 60, &self.i18n);
+        self.result.extend([
+            vec![],
+            vec![verdict],
+            vec![(self.i18n.t("koch.next").into(), Mark::Dim)],
+        ]);
+        self.phase = Phase::Result;
+        Ok(())
+    }
+}
+
+impl Drill for Koch {
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()> {
+        match self.phase {
+            Phase::Wait { until, intro } => {
+                if Instant::now() < until { return Ok(()); }
+                if intro {
+                    // Each new character three times
+                    let text: Vec<String> = self.new_chars().iter()
+                        .map(|c| format!("{c} {c} {c}")).collect();
+                    player.play(&text.join("  "), &self.timing);
+                    self.phase = Phase::Intro;
+                } else {
+                    self.sent = self.groups();
+                    self.copy = UserCopy::default();
+                    player.play(&self.sent, &self.timing);
+                    self.phase = Phase::Copying;
+                }
+            }
+            Phase::Intro => if !player.busy() {
+                self.phase = Phase::Wait { until: Instant::now() + Duration::from_secs(2), intro: false };
+            },
+            Phase::Copying => if self.copy.step(player, input) {
+                self.score()?;
+            },
+            Phase::Result => if input.contains(&Input::Enter) {
+                let unlocked = self.rounds.last().is_some_and(|&(l, _)| l != self.lesson);
+                self.phase = Phase::Wait { until: Instant::now(), intro: unlocked };
+            },
+        }
+        Ok(())
+    }
+
+    fn view(&self) -> View {
+        let chars: Vec<String> = self.chars().iter().map(char::to_string).collect();
+        let mut lines: Vec<Line> = vec![
+            vec![(format!("{}  ", self.i18n.t("koch.characters")), Mark::Dim), (chars.join(" "), Mark::Info)],
+            vec![],
+        ];
+        let status = match self.phase {
+            Phase::Wait { .. } | Phase::Intro => {
+                for &c in self.new_chars() {
+                    lines.push(vec![
+                        (format!("{}  ", self.i18n.t("koch.new_char")), Mark::Dim),
+                        (format!("{c}   {}", char_to_morse(c).unwrap_or("")), Mark::Good),
+                    ]);
+                }
+                "koch.listen"
+            }
+            Phase::Copying if !self.copy.heard() => "koch.copy",
+            Phase::Copying => "practice.finish",
+            Phase::Result  => {
+                lines.extend(self.result.iter().cloned());
+                "koch.scored"
+            }
+        };
+        View {
+            header: format!(" {}  |  {}  |  {}", self.i18n.t("app.title"),
+                            self.i18n.f("koch.header", &[&self.lesson, &LAST_LESSON]), self.speed),
+            title:  format!(" {} ", self.i18n.f("koch.title", &[&self.lesson])),
+            lines,
+            input:  if self.phase == Phase::Result { String::new() } else { self.copy.text.clone() },
+            status: self.i18n.t(status).into(),
+            ..Default::default()
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+        let chars: String = self.chars().iter().collect();
+        if self.rounds.is_empty() {
+            let _ = writeln!(out, "{}", self.i18n.f("koch.none", &[&self.lesson, &chars]));
+            return out;
+        }
+        let scores: Vec<String> = self.rounds.iter().map(|(_, a)| format!("{:.0}%", a * 100.0)).collect();
+        let _ = writeln!(out, "{}", self.i18n.f("koch.summary", &[&self.rounds.len(), &scores.join(" ")]));
+        if self.lesson > self.first {
+            let _ = writeln!(out, "  {}", self.i18n.f("koch.lesson", &[&self.first, &self.lesson]));
+        }
+        let worst: Vec<String> = self.chars_seen.worst(6).iter()
+            .map(|(c, miss, sent)| format!("{c} {miss}/{sent}")).collect();
+        if !worst.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("practice.most_missed", &[&worst.join("  ")]));
+        }
+        let _ = writeln!(out, "  {}", self.i18n.f("koch.next_time", &[&self.lesson, &chars]));
+        out
+    }
+
+    fn record(&self) -> Option<DrillRecord> {
+        (!self.rounds.is_empty()).then(|| DrillRecord {
+            rounds:   self.rounds.len() as u32,
+            level:    Some(self.lesson as u32),
+            accuracy: Some(self.tally.accuracy()),
+            ..DrillRecord::new(PracticeMode::Koch, self.wpm, &self.chars_seen)
+        })
+    }
+}
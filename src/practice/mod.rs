@@ -0,0 +1,467 @@
+// src/practice/mod.rs  —  Practice drills: shared runner, input, audio and screen
+//
+// A drill runs instead of the QSO engine.  The runner owns the keyer,
+// sidetone, decoder and TUI the same way the QSO loop in main.rs does, and
+// hands the drill one stream of input every tick — typed keys in keyboard
+// mode, decoded characters from a paddle.  The drill plays its Morse
+// through `Player` and describes its screen as a `View`.
+pub mod copy;
+pub mod icr;
+pub mod koch;
+pub mod rufz;
+pub mod send;
+
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::audio::{self, AudioOutput};
+use crate::config::{AppConfig, PracticeMode};
+use crate::history::{DrillRecord, History};
+use crate::i18n::I18n;
+use crate::keyer;
+use crate::morse::{self, Decoder, Timing};
+
+/// One piece of user input: typed, or decoded from the paddle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Input {
+    Char(char),
+    /// Space bar, or a word gap from the paddle
+    Space,
+    Enter,
+    Backspace,
+}
+
+/// How a piece of drill text is coloured
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mark {
+    Plain,
+    Dim,
+    Info,
+    Good,
+    Bad,
+    /// Sent but not copied
+    Missed,
+    /// Copied but never sent
+    Extra,
+    /// Keyed right but spaced wrong — two characters run together, or one split
+    Spacing,
+}
+
+pub type Line = Vec<(String, Mark)>;
+
+/// What a drill wants on screen
+#[derive(Debug, Clone, Default)]
+pub struct View {
+    /// Header bar
+    pub header: String,
+    /// Title and content of the drill panel
+    pub title:  String,
+    pub lines:  Vec<Line>,
+    /// The user's copy so far
+    pub input:  String,
+    /// Paddle elements of the character being keyed (filled in by the runner)
+    pub code:   String,
+    pub status: String,
+    /// The user keys a shown text rather than copying one
+    pub sending: bool,
+}
+
+pub trait Drill {
+    /// Advance one tick with the input that arrived since the last one
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()>;
+    fn view(&self) -> View;
+    /// Printed after the TUI has closed
+    fn summary(&self) -> String;
+    /// What goes into the practice history; None when nothing was practised
+    fn record(&self) -> Option<DrillRecord>;
+    /// Called after `step` with the runner's decoder, for drills that grade
+    /// the keying itself
+    fn keying(&mut self, _decoder: &mut Decoder) {}
+}
+
+// ── Audio ────────────────────────────────────────────────────────────────────
+
+/// Plays drill text in the background so the screen and input stay live
+pub struct Player {
+    audio: Arc<Mutex<Box<dyn AudioOutput>>>,
+    busy:  Arc<AtomicBool>,
+}
+
+impl Player {
+    fn new(audio: Arc<Mutex<Box<dyn AudioOutput>>>) -> Self {
+        Self { audio, busy: Arc::new(AtomicBool::new(false)) }
+    }
+
+    /// Start sending `text`; returns at once
+    pub fn play(&self, text: &str, timing: &Timing) {
+        let seq = morse::encode(text, timing);
+        self.busy.store(true, Ordering::Relaxed);
+        let (audio, busy) = (Arc::clone(&self.audio), Arc::clone(&self.busy));
+        thread::spawn(move || {
+            let _ = audio.lock().unwrap().play_sequence(&seq);
+            busy.store(false, Ordering::Relaxed);
+        });
+    }
+
+    pub fn busy(&self) -> bool { self.busy.load(Ordering::Relaxed) }
+}
+
+/// Drill sending speed: characters at `sim_wpm`, gaps stretched to the
+/// Farnsworth speed when one is set
+pub fn timing(cfg: &AppConfig) -> Timing {
+    if cfg.farnsworth_wpm > 0 && cfg.farnsworth_wpm < cfg.sim_wpm {
+        Timing::farnsworth(cfg.sim_wpm, cfg.farnsworth_wpm)
+    } else {
+        Timing::from_wpm(cfg.sim_wpm)
+    }
+}
+
+/// "20 WPM" or "20/10 WPM" with Farnsworth spacing
+pub fn speed_label(cfg: &AppConfig) -> String {
+    if cfg.farnsworth_wpm > 0 && cfg.farnsworth_wpm < cfg.sim_wpm {
+        format!("{}/{} WPM", cfg.sim_wpm, cfg.farnsworth_wpm)
+    } else {
+        format!("{} WPM", cfg.sim_wpm)
+    }
+}
+
+// ── The user's copy ──────────────────────────────────────────────────────────
+
+/// Paddle copy counts as finished after this much silence (keyboard users press Enter)
+const IDLE: Duration = Duration::from_secs(6);
+
+/// What the user copies of one sent text, typed or keyed while it plays and after
+pub struct UserCopy {
+    pub text:   String,
+    last_input: Instant,
+    heard:      bool,
+}
+
+impl Default for UserCopy {
+    fn default() -> Self {
+        Self { text: String::new(), last_input: Instant::now(), heard: false }
+    }
+}
+
+impl UserCopy {
+    /// True once the whole text has played
+    pub fn heard(&self) -> bool { self.heard }
+
+    /// Feed one tick of input; true when the copy is finished — Enter after
+    /// the text has played, or a paddle gone quiet
+    pub fn step(&mut self, player: &Player, input: &[Input]) -> bool {
+        let mut done = false;
+        for &i in input {
+            match i {
+                Input::Char(c)   => self.text.push(c),
+                Input::Space     => if !self.text.is_empty() && !self.text.ends_with(' ') { self.text.push(' ') },
+                Input::Backspace => { self.text.pop(); }
+                Input::Enter     => done = self.heard,
+            }
+            self.last_input = Instant::now();
+        }
+        if !self.heard && !player.busy() {
+            self.heard = true;
+            self.last_input = Instant::now();
+        }
+        done || (self.heard && !self.text.trim().is_empty() && self.last_input.elapsed() >= IDLE)
+    }
+}
+
+// ── Alignment and scoring ────────────────────────────────────────────────────
+
+/// One column of an alignment between the sent text and the user's copy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Match(char),
+    Sub { sent: char, copy: char },
+    /// Sent, not copied
+    Miss(char),
+    /// Copied, not sent
+    Extra(char),
+}
+
+/// Align copy against sent with the fewest edits (Levenshtein); runs of
+/// spaces count as one
+pub fn align(sent: &str, copy: &str) -> Vec<Op> {
+    let norm = |s: &str| -> Vec<char> { s.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect() };
+    let (a, b) = (norm(sent), norm(copy));
+    let (n, m) = (a.len(), b.len());
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() { row[0] = i; }
+    for (j, v) in d[0].iter_mut().enumerate() { *v = j; }
+    for i in 1..=n {
+        for j in 1..=m {
+            d[i][j] = (d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]))
+                .min(d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1);
+        }
+    }
+    // Walk back from the end, preferring matches and substitutions
+    let (mut i, mut j, mut ops) = (n, m, vec![]);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]) {
+            ops.push(if a[i - 1] == b[j - 1] { Op::Match(a[i - 1]) }
+                     else { Op::Sub { sent: a[i - 1], copy: b[j - 1] } });
+            i -= 1; j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            ops.push(Op::Miss(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(Op::Extra(b[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    ops
+}
+
+/// Character counts of an alignment (spaces are not counted)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tally {
+    pub correct:     u32,
+    pub missed:      u32,
+    pub inserted:    u32,
+    pub substituted: u32,
+}
+
+impl Tally {
+    pub fn of(ops: &[Op]) -> Self {
+        let mut t = Self::default();
+        for op in ops {
+            match *op {
+                Op::Match(c) if c != ' ' => t.correct += 1,
+                Op::Sub { .. }           => t.substituted += 1,
+                Op::Miss(c)  if c != ' ' => t.missed += 1,
+                Op::Extra(c) if c != ' ' => t.inserted += 1,
+                _ => {}
+            }
+        }
+        t
+    }
+
+    pub fn add(&mut self, o: Tally) {
+        self.correct += o.correct;
+        self.missed += o.missed;
+        self.inserted += o.inserted;
+        self.substituted += o.substituted;
+    }
+
+    /// 1 − errors ÷ characters sent, never below 0
+    pub fn accuracy(&self) -> f64 {
+        let sent = self.correct + self.missed + self.substituted;
+        if sent == 0 { return 0.0; }
+        let errors = self.missed + self.inserted + self.substituted;
+        (1.0 - f64::from(errors) / f64::from(sent)).max(0.0)
+    }
+}
+
+/// The alignment as two rows — sent over copied, one column per character —
+/// broken every `width` columns
+pub fn diff_lines(ops: &[Op], width: usize, i18n: &I18n) -> Vec<Line> {
+    diff_lines_as(ops, width, i18n.t("practice.sent"), i18n.t("practice.copy"), &[])
+}
+
+/// `diff_lines` with its own labels for the two rows, and the columns in
+/// `spacing` marked as spacing errors
+pub fn diff_lines_as(ops: &[Op], width: usize, upper: &str, lower: &str, spacing: &[usize]) -> Vec<Line> {
+    let mut out = vec![];
+    let width = width.max(1);
+    // Both labels padded to one width so the columns line up
+    let pad = upper.chars().count().max(lower.chars().count()) + 2;
+    for (n, chunk) in ops.chunks(width).enumerate() {
+        let mut sent: Line = vec![(format!("{upper:<pad$}"), Mark::Dim)];
+        let mut copy: Line = vec![(format!("{lower:<pad$}"), Mark::Dim)];
+        for (i, op) in chunk.iter().enumerate() {
+            let (s, c) = match *op {
+                Op::Match(ch)          => ((ch, Mark::Good),   (ch, Mark::Good)),
+                Op::Sub { sent, copy } => ((sent, Mark::Bad),  (copy, Mark::Bad)),
+                Op::Miss(ch)           => ((ch, Mark::Missed), ('_', Mark::Missed)),
+                Op::Extra(ch)          => (('·', Mark::Dim),   (ch, Mark::Extra)),
+            };
+            let (s, c) = if spacing.contains(&(n * width + i)) {
+                ((s.0, Mark::Spacing), (c.0, Mark::Spacing))
+            } else { (s, c) };
+            sent.push((s.0.to_string(), s.1));
+            copy.push((c.0.to_string(), c.1));
+        }
+        out.push(sent);
+        out.push(copy);
+    }
+    out
+}
+
+/// How often each character was sent and how often it was missed or copied
+/// wrong; words too, for drills that send real words
+#[derive(Debug, Default)]
+pub struct CharStats {
+    chars: BTreeMap<char, (u32, u32)>,
+    words: BTreeMap<String, (u32, u32)>,
+}
+
+impl CharStats {
+    pub fn add(&mut self, ops: &[Op]) {
+        for op in ops {
+            let (c, wrong) = match *op {
+                Op::Match(c)        => (c, false),
+                Op::Sub { sent, .. } => (sent, true),
+                Op::Miss(c)         => (c, true),
+                Op::Extra(_)        => continue,
+            };
+            if c == ' ' { continue; }
+            let e = self.chars.entry(c).or_insert((0, 0));
+            e.0 += 1;
+            if wrong { e.1 += 1; }
+        }
+    }
+
+    /// Count the sent words of an alignment, punctuation trimmed; a word is
+    /// wrong when anything in it, or the space after it, was.  Words without
+    /// a letter are skipped.
+    pub fn add_words(&mut self, ops: &[Op]) {
+        let (mut word, mut wrong) = (String::new(), false);
+        // A space closes the alignment, as if one followed the last word
+        for op in ops.iter().copied().map(Some).chain([None]) {
+            let (sent, bad) = match op {
+                Some(Op::Match(c))         => (Some(c), false),
+                Some(Op::Sub { sent, .. }) => (Some(sent), true),
+                Some(Op::Miss(c))          => (Some(c), true),
+                Some(Op::Extra(_))         => (None, true),
+                None                       => (Some(' '), false),
+            };
+            wrong |= bad;
+            match sent {
+                Some(' ') => {
+                    let w = crate::schedule::word(&word);
+                    if w.chars().count() > 1 && w.chars().any(|c| c.is_ascii_alphabetic()) {
+                        let e = self.words.entry(w.to_string()).or_insert((0, 0));
+                        e.0 += 1;
+                        if wrong { e.1 += 1; }
+                    }
+                    word.clear();
+                    wrong = false;
+                }
+                Some(c) => word.push(c),
+                None    => {}
+            }
+        }
+    }
+
+    /// Most-missed characters first: (char, missed, sent)
+    pub fn worst(&self, n: usize) -> Vec<(char, u32, u32)> {
+        let mut v: Vec<(char, u32, u32)> = self.chars.iter()
+            .filter(|(_, &(_, miss))| miss > 0)
+            .map(|(&c, &(sent, miss))| (c, miss, sent))
+            .collect();
+        v.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+        v.truncate(n);
+        v
+    }
+
+    /// Character → times missed, for the practice history
+    pub fn errors(&self) -> BTreeMap<String, u32> {
+        self.chars.iter().filter(|(_, &(_, miss))| miss > 0).map(|(c, &(_, miss))| (c.to_string(), miss)).collect()
+    }
+
+    /// Word → times missed, for the practice history
+    pub fn word_errors(&self) -> BTreeMap<String, u32> {
+        self.words.iter().filter(|(_, &(_, miss))| miss > 0).map(|(w, &(_, miss))| (w.clone(), miss)).collect()
+    }
+
+    /// Characters and words → times sent, for the review schedule
+    pub fn seen(&self) -> BTreeMap<String, u32> {
+        self.chars.iter().map(|(c, &(sent, _))| (c.to_string(), sent))
+            .chain(self.words.iter().map(|(w, &(sent, _))| (w.clone(), sent)))
+            .collect()
+    }
+}
+
+// ── Runner ───────────────────────────────────────────────────────────────────
+
+/// Run a practice drill until the user quits
+pub fn run(cfg: &AppConfig, mode: PracticeMode) -> Result<()> {
+    let mut drill: Box<dyn Drill> = match mode {
+        PracticeMode::Koch => Box::new(koch::Koch::new(cfg)?),
+        PracticeMode::Copy => Box::new(copy::CopyDrill::new(cfg)),
+        PracticeMode::Rufz => Box::new(rufz::Rufz::new(cfg)),
+        PracticeMode::Send => Box::new(send::SendDrill::new(cfg)?),
+        PracticeMode::Icr  => Box::new(icr::Icr::new(cfg)),
+    };
+
+    let audio = Arc::new(Mutex::new(audio::create_audio(cfg.tone_hz as f32, cfg.volume)));
+    let player = Player::new(Arc::clone(&audio));
+
+    // ── Keyer + sidetone ──────────────────────────────────────────────────────
+    let user_timing = Timing::from_wpm(cfg.user_wpm);
+    let keyer_port = if !cfg.midi_port.is_empty() { &cfg.midi_port } else { &cfg.port };
+    let (keyer, is_keyboard, _) =
+        keyer::create_keyer(cfg.adapter, keyer_port, cfg.paddle_mode, user_timing.dot, cfg.switch_paddle)?;
+    if is_keyboard && mode == PracticeMode::Send {
+        bail!("Send practice grades your keying and needs a paddle or key — choose one with --adapter");
+    }
+    let keying = keyer::Keying::start(keyer, user_timing.dot, &audio, cfg.sidetone);
+    let mut decoder = Decoder::new(user_timing);
+
+    #[cfg(feature = "tui")]
+    let mut tui = crate::tui::Tui::new(&cfg.language)?;
+
+    let tick = Duration::from_millis(10);
+    'main: loop {
+        let mut input = vec![];
+
+        #[cfg(feature = "tui")]
+        {
+            use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+            while event::poll(Duration::from_millis(0))? {
+                let Event::Key(k) = event::read()? else { continue };
+                if k.kind == KeyEventKind::Release { continue; }
+                if k.code == KeyCode::Esc
+                    || (k.code == KeyCode::Char('c') && k.modifiers.contains(KeyModifiers::CONTROL))
+                {
+                    break 'main;
+                }
+                match k.code {
+                    KeyCode::Enter                     => input.push(Input::Enter),
+                    KeyCode::Backspace if is_keyboard  => input.push(Input::Backspace),
+                    KeyCode::Char(' ') if is_keyboard  => input.push(Input::Space),
+                    KeyCode::Char(c)   if is_keyboard  => input.push(Input::Char(c.to_ascii_uppercase())),
+                    KeyCode::Char('q' | 'Q')           => break 'main,
+                    _ => {}
+                }
+            }
+        }
+
+        // Paddle → sidetone + decoder
+        keying.drain(&mut decoder);
+        if let Some(chars) = decoder.tick() {
+            input.extend(chars.chars().map(|c| if c == ' ' { Input::Space } else { Input::Char(c) }));
+        }
+
+        drill.step(&player, &input)?;
+        drill.keying(&mut decoder);
+
+        #[cfg(feature = "tui")]
+        {
+            let mut view = drill.view();
+            if !is_keyboard { view.code = decoder.current_code().to_string(); }
+            tui.draw_practice(&view, is_keyboard)?;
+        }
+
+        thread::sleep(tick);
+    }
+
+    #[cfg(feature = "tui")]
+    tui.cleanup();
+
+    println!("\n{}", drill.summary());
+    if let Some(rec) = drill.record() {
+        if let Err(e) = History::append_drill(rec) {
+            log::warn!("Could not update the practice history: {e:#}");
+        }
+    }
+    println!("\n73 de cw-qso-sim! Good luck with the pile-ups.\n");
+    Ok(())
+}
@@ -0,0 +1,216 @@
+// src/practice/rufz.rs  —  Callsign speed trainer in the style of RufzXP
+//
+// A run is 50 callsigns, one at a time, from the call pool or the
+// generator.  The user copies each one; a right call raises the speed by
+// 5 %, a wrong one lowers it by 10 %, so the run settles at the speed the
+// user can just manage.  A call scores  CPM × characters ÷ 5  (CPM = 5 × WPM),
+// less a share for every character wrong — nothing once half of it is
+// wrong.  The final score goes to the practice history.
+use anyhow::Result;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+use crate::config::{AppConfig, PracticeMode};
+use crate::history::{DrillRecord, History};
+use crate::i18n::I18n;
+use crate::morse::Timing;
+use crate::qso::callsigns;
+use super::{CharStats, Drill, Input, Line, Mark, Player, UserCopy, View};
+
+const CALLS: usize = 50;
+const FASTER: f64 = 1.05;
+const SLOWER: f64 = 0.90;
+const MIN_WPM: f64 = 5.0;
+const MAX_WPM: f64 = 99.0;
+/// Results shown on screen
+const SHOWN: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Pause before the next call
+    Wait(Instant),
+    Copying,
+    Done,
+}
+
+/// One call of the run
+struct Answer {
+    call:   String,
+    copy:   String,
+    wpm:    f64,
+    points: u32,
+}
+
+pub struct Rufz {
+    wpm:        f64,
+    start_wpm:  f64,
+    rng:        SmallRng,
+    phase:      Phase,
+    call:       String,
+    copy:       UserCopy,
+    answers:    Vec<Answer>,
+    chars_seen: CharStats,
+    /// Best score in the history before this run
+    best:       Option<u32>,
+    i18n:       I18n,
+}
+
+impl Rufz {
+    pub fn new(cfg: &AppConfig) -> Self {
+        let best = History::load().ok()
+            .and_then(|h| h.drill.iter().filter(|d| d.mode == PracticeMode::Rufz).filter_map(|d| d.score).max());
+        Self {
+            wpm:        f64::from(cfg.sim_wpm),
+            start_wpm:  f64::from(cfg.sim_wpm),
+            rng:        SmallRng::seed_from_u64(cfg.seed),
+            phase:      Phase::Wait(Instant::now() + Duration::from_secs(1)),
+            call:       String::new(),
+            copy:       UserCopy::default(),
+            answers:    vec![],
+            chars_seen: CharStats::default(),
+            best,
+            i18n:       I18n::new(&cfg.language),
+        }
+    }
+
+    fn score(&self) -> u32 { self.answers.iter().map(|a| a.points).sum() }
+
+    fn peak(&self) -> f64 { self.answers.iter().map(|a| a.wpm).fold(self.start_wpm, f64::max) }
+
+    fn answer(&mut self) {
+        let ops = super::align(&self.call, &self.copy.text);
+        self.chars_seen.add(&ops);
+        let errors = ops.iter().filter(|op| !matches!(op, super::Op::Match(_))).count();
+        let len = self.call.chars().count();
+        let points = points(self.wpm, len, errors);
+        self.answers.push(Answer {
+            call: std::mem::take(&mut self.call),
+            copy: self.copy.text.trim().to_string(),
+            wpm: self.wpm,
+            points,
+        });
+        self.wpm = (self.wpm * if errors == 0 { FASTER } else { SLOWER }).clamp(MIN_WPM, MAX_WPM);
+        self.phase = if self.answers.len() >= CALLS { Phase::Done }
+                     else { Phase::Wait(Instant::now() + Duration::from_millis(700)) };
+    }
+}
+
+/// CPM × characters ÷ 5 for a clean call, less a share per error;
+/// half the call wrong scores nothing
+fn points(wpm: f64, len: usize, errors: usize) -> u32 {
+    if len == 0 || 2 * errors >= len { return 0; }
+    let full = 5.0 * wpm * len as f64 / 5.0;
+    (full * (1.0 - errors as f64 / len as f64)).round() as u32
+}
+
+impl Drill for Rufz {
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()> {
+        match self.phase {
+            Phase::Wait(until) => if Instant::now() >= until {
+                self.call = callsigns::random_station(&mut self.rng).call;
+                self.copy = UserCopy::default();
+                player.play(&self.call, &Timing::from_wpm(self.wpm.round() as u8));
+                self.phase = Phase::Copying;
+            },
+            Phase::Copying => {
+                // A call is one word: a paddle word gap ends it like Enter
+                let input: Vec<Input> = input.iter()
+                    .map(|&i| if i == Input::Space && !self.copy.text.is_empty() { Input::Enter } else { i })
+                    .collect();
+                if self.copy.step(player, &input) { self.answer(); }
+            }
+            Phase::Done => {}
+        }
+        Ok(())
+    }
+
+    fn view(&self) -> View {
+        let mut lines: Vec<Line> = vec![vec![
+            (format!("{} ", self.i18n.t("rufz.call")), Mark::Dim),
+            (format!("{}/{CALLS}  ", (self.answers.len() + 1).min(CALLS)), Mark::Plain),
+            (format!("{} ", self.i18n.t("rufz.score")), Mark::Dim),
+            (format!("{}  ", self.score()), Mark::Info),
+            (format!("{} ", self.i18n.t("rufz.speed")), Mark::Dim),
+            (format!("{:.0} WPM ({:.0} CPM)", self.wpm, self.wpm * 5.0), Mark::Plain),
+        ]];
+        if let Some(b) = self.best {
+            lines.push(vec![(format!("{} ", self.i18n.t("rufz.best_label")), Mark::Dim), (b.to_string(), Mark::Plain)]);
+        }
+        lines.push(vec![]);
+        let from = self.answers.len().saturating_sub(SHOWN);
+        for (i, a) in self.answers.iter().enumerate().skip(from) {
+            let right = a.copy == a.call;
+            let mut l: Line = vec![
+                (format!("{:>3}  ", i + 1), Mark::Dim),
+                (format!("{:<12}", a.call), Mark::Plain),
+                (format!("{:>3.0} WPM  ", a.wpm), Mark::Dim),
+                (format!("{:>5}", format!("+{}", a.points)), if right { Mark::Good } else { Mark::Bad }),
+            ];
+            if !right {
+                let copy = if a.copy.is_empty() { "—" } else { &a.copy };
+                l.push((format!("   {}", self.i18n.f("practice.you", &[&copy])), Mark::Bad));
+            }
+            lines.push(l);
+        }
+        let status = match self.phase {
+            Phase::Wait(_)                       => "rufz.next",
+            Phase::Copying if !self.copy.heard() => "practice.listen",
+            Phase::Copying                       => "rufz.type",
+            Phase::Done => {
+                lines.push(vec![]);
+                let peak = format!("{:.0}", self.peak());
+                lines.push(vec![(self.i18n.f("rufz.final", &[&self.score(), &peak]), Mark::Info)]);
+                "rufz.complete"
+            }
+        };
+        let wpm = format!("{:.0}", self.wpm);
+        View {
+            header: format!(" {}  |  {}", self.i18n.t("app.title"), self.i18n.f("rufz.header", &[&wpm, &self.score()])),
+            title:  format!(" {} ", self.i18n.t("rufz.title")),
+            lines,
+            input:  self.copy.text.clone(),
+            status: self.i18n.t(status).into(),
+            ..Default::default()
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+        if self.answers.is_empty() {
+            let _ = writeln!(out, "{}", self.i18n.t("rufz.none"));
+            return out;
+        }
+        let right = self.answers.iter().filter(|a| a.copy == a.call).count();
+        let done = if self.answers.len() >= CALLS { String::new() } else { format!("  {}", self.i18n.t("rufz.unfinished")) };
+        let _ = writeln!(out, "{}{done}", self.i18n.f("rufz.summary", &[&self.score()]));
+        let wpm = |w: f64| format!("{w:.0}");
+        let _ = writeln!(out, "  {}", self.i18n.f("rufz.right", &[&right, &self.answers.len(),
+                                                 &wpm(self.start_wpm), &wpm(self.wpm), &wpm(self.peak())]));
+        match self.best {
+            Some(b) if self.answers.len() >= CALLS && self.score() > b
+                => { let _ = writeln!(out, "  {}", self.i18n.f("rufz.new_best", &[&b])); }
+            Some(b) => { let _ = writeln!(out, "  {}", self.i18n.f("rufz.best", &[&b])); }
+            None => {}
+        }
+        let worst: Vec<String> = self.chars_seen.worst(8).iter()
+            .map(|(c, miss, sent)| format!("{c} {miss}/{sent}")).collect();
+        if !worst.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("practice.most_missed", &[&worst.join("  ")]));
+        }
+        out
+    }
+
+    /// Only full runs are scored — a partial run's score means nothing
+    fn record(&self) -> Option<DrillRecord> {
+        (self.answers.len() >= CALLS).then(|| {
+            let right = self.answers.iter().filter(|a| a.copy == a.call).count();
+            DrillRecord {
+                rounds:   self.answers.len() as u32,
+                accuracy: Some(right as f64 / self.answers.len() as f64),
+                score:    Some(self.score()),
+                ..DrillRecord::new(PracticeMode::Rufz, self.peak().round() as u8, &self.chars_seen)
+            }
+        })
+    }
+}
@@ -0,0 +1,318 @@
+// src/practice/send.rs  —  Send practice: key a shown text, see what was decoded
+//
+// The screen shows a line — callsigns, groups, numbers, QSO phrases, a
+// sentence, or the next line of --send-file — and the user keys it on the
+// paddle.  What the decoder made of it is aligned with the line the same way
+// copy practice aligns a copy.  Mistakes that are really spacing are picked
+// out: two characters keyed without a character gap decode as one ("AN" →
+// "P"), a gap inside a character splits it ("P" → "AN"), and a word gap can
+// go missing or turn up inside a word.  Every line ends with the accuracy and
+// the effective speed from the keying analysis.
+use anyhow::{bail, Context, Result};
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+use crate::config::{AppConfig, CopyKind, PracticeMode};
+use crate::history::DrillRecord;
+use crate::i18n::I18n;
+use crate::morse::Decoder;
+use crate::morse::analysis::KeyingSummary;
+use crate::morse::encoder::char_to_morse;
+use crate::report;
+use super::{CharStats, Drill, Input, Line, Mark, Op, Player, Tally, UserCopy, View};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Pause before the next line
+    Wait(Instant),
+    Keying,
+    Result,
+}
+
+/// What the runner's decoder should do after this tick
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Measure {
+    Nothing,
+    /// A line starts: drop what was keyed in between
+    Reset,
+    /// A line was scored: take its keying
+    Take,
+}
+
+pub struct SendDrill {
+    kind:       CopyKind,
+    /// Lines of --send-file, sent in order; empty = generate
+    file:       Vec<String>,
+    next:       usize,
+    user_wpm:   u8,
+    rng:        SmallRng,
+    phase:      Phase,
+    target:     String,
+    keyed:      UserCopy,
+    measure:    Measure,
+    /// Alignment of the line just scored, until its keying is in
+    ops:        Vec<Op>,
+    /// Accuracy of every line this run
+    rounds:     Vec<f64>,
+    /// Effective speed of every line it could be measured for
+    wpm:        Vec<f64>,
+    tally:      Tally,
+    spacing:    u32,
+    chars_seen: CharStats,
+    result:     Vec<Line>,
+    i18n:       I18n,
+}
+
+impl SendDrill {
+    pub fn new(cfg: &AppConfig) -> Result<Self> {
+        let file = match &cfg.send_file {
+            Some(path) => {
+                let raw = std::fs::read_to_string(path)
+                    .with_context(|| format!("Reading {:?}", path))?;
+                let lines: Vec<String> = raw.lines().map(sendable).filter(|l| !l.is_empty()).collect();
+                if lines.is_empty() { bail!("{:?} has no text that can be sent in Morse", path); }
+                lines
+            }
+            None => vec![],
+        };
+        Ok(Self {
+            kind:       cfg.send_kind,
+            file,
+            next:       0,
+            user_wpm:   cfg.user_wpm,
+            rng:        SmallRng::seed_from_u64(cfg.seed),
+            phase:      Phase::Wait(Instant::now() + Duration::from_secs(1)),
+            target:     String::new(),
+            keyed:      UserCopy::default(),
+            measure:    Measure::Nothing,
+            ops:        vec![],
+            rounds:     vec![],
+            wpm:        vec![],
+            tally:      Tally::default(),
+            spacing:    0,
+            chars_seen: CharStats::default(),
+            result:     vec![],
+            i18n:       I18n::new(&cfg.language),
+        })
+    }
+
+    fn kind_name(&self) -> &'static str {
+        if self.file.is_empty() { super::copy::kind_name(self.kind) } else { "file" }
+    }
+
+    fn line(&mut self) -> String {
+        if self.file.is_empty() { return super::copy::line(self.kind, &mut self.rng); }
+        let l = self.file[self.next % self.file.len()].clone();
+        self.next += 1;
+        l
+    }
+
+    fn score(&mut self) {
+        self.ops = super::align(&self.target, &self.keyed.text);
+        let t = Tally::of(&self.ops);
+        self.tally.add(t);
+        self.chars_seen.add(&self.ops);
+        if !self.file.is_empty() || matches!(self.kind, CopyKind::Phrases | CopyKind::Text) {
+            self.chars_seen.add_words(&self.ops);
+        }
+        self.rounds.push(t.accuracy());
+        self.measure = Measure::Take;
+        self.phase = Phase::Result;
+    }
+
+    /// The result screen, once the line's keying has been measured
+    fn result(&mut self, k: &KeyingSummary) {
+        let spans = spacing(&self.ops);
+        self.spacing += spans.len() as u32;
+        let cols: Vec<usize> = spans.iter().flat_map(|&(i, n)| i..i + n).collect();
+        let t = Tally::of(&self.ops);
+
+        self.result = super::diff_lines_as(&self.ops, 60, self.i18n.t("practice.sent"), self.i18n.t("send.keyed"), &cols);
+        self.result.extend([
+            vec![],
+            vec![
+                (format!("{}  ", self.i18n.f("practice.correct", &[&t.correct])), Mark::Good),
+                (format!("{}  ", self.i18n.f("practice.wrong", &[&t.substituted])), Mark::Bad),
+                (format!("{}  ", self.i18n.f("practice.missed", &[&t.missed])), Mark::Missed),
+                (format!("{}  ", self.i18n.f("practice.extra", &[&t.inserted])), Mark::Extra),
+                (format!("{}  ", self.i18n.f("send.spacing", &[&spans.len()])), Mark::Spacing),
+                (format!("— {:.0}%", t.accuracy() * 100.0), Mark::Info),
+            ],
+        ]);
+        let mut keying: Line = vec![];
+        if let Some(w) = k.wpm {
+            self.wpm.push(w);
+            keying.push((format!("{} ", self.i18n.t("send.eff_speed")), Mark::Dim));
+            keying.push((format!("{w:.1} WPM   "), Mark::Plain));
+        }
+        if let Some(r) = k.ratio {
+            keying.push((format!("{} ", self.i18n.t("send.ratio")), Mark::Dim));
+            keying.push((format!("{r:.1}   "), Mark::Plain));
+        }
+        if let Some((gap, _)) = k.char_gap {
+            keying.push((format!("{} ", self.i18n.t("send.char_gap")), Mark::Dim));
+            keying.push((self.i18n.f("send.units", &[&format!("{gap:.1}")]), Mark::Plain));
+        }
+        if !keying.is_empty() { self.result.push(keying); }
+        if !spans.is_empty() {
+            self.result.push(vec![(self.i18n.t("send.blue").into(), Mark::Spacing)]);
+        }
+        self.result.push(vec![(self.i18n.t("practice.next_line").into(), Mark::Dim)]);
+    }
+}
+
+/// A line of a text file as it can be keyed: upper case, only characters
+/// Morse has, single spaces
+fn sendable(line: &str) -> String {
+    line.to_uppercase()
+        .split_whitespace()
+        .map(|w| w.chars().filter(|&c| char_to_morse(c).is_some()).collect::<String>())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Stretches of the alignment that are spacing mistakes, as (first column,
+/// columns): two sent characters decoded as one, one decoded as two, a word
+/// gap left out or a word gap inside a word
+fn spacing(ops: &[Op]) -> Vec<(usize, usize)> {
+    let code = |c: char| char_to_morse(c).unwrap_or("");
+    // `whole` keyed as `a` then `b` — the same elements either way
+    let same = |a: char, b: char, whole: char| {
+        let (a, b, whole) = (code(a), code(b), code(whole));
+        !a.is_empty() && !b.is_empty() && whole.len() == a.len() + b.len() && whole.starts_with(a) && whole.ends_with(b)
+    };
+    let mut out = vec![];
+    let mut i = 0;
+    while i < ops.len() {
+        if matches!(ops[i], Op::Miss(' ') | Op::Extra(' ')) {
+            out.push((i, 1));
+            i += 1;
+            continue;
+        }
+        let hit = ops.get(i + 1).is_some_and(|&next| match (ops[i], next) {
+            // Run together
+            (Op::Sub { sent: a, copy: whole }, Op::Miss(b))
+            | (Op::Miss(a), Op::Sub { sent: b, copy: whole }) => same(a, b, whole),
+            // Split
+            (Op::Sub { sent: whole, copy: a }, Op::Extra(b))
+            | (Op::Extra(a), Op::Sub { sent: whole, copy: b }) => same(a, b, whole),
+            _ => false,
+        });
+        if hit {
+            out.push((i, 2));
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+impl Drill for SendDrill {
+    fn step(&mut self, player: &Player, input: &[Input]) -> Result<()> {
+        match self.phase {
+            Phase::Wait(until) => if Instant::now() >= until {
+                self.target = self.line();
+                self.keyed = UserCopy::default();
+                self.measure = Measure::Reset;
+                self.phase = Phase::Keying;
+            },
+            Phase::Keying => {
+                let done = self.keyed.step(player, input);
+                // A line keyed exactly needs no Enter
+                if done || self.keyed.text.trim_end() == self.target { self.score(); }
+            }
+            Phase::Result => if input.contains(&Input::Enter) {
+                self.phase = Phase::Wait(Instant::now() + Duration::from_millis(500));
+            },
+        }
+        Ok(())
+    }
+
+    fn keying(&mut self, decoder: &mut Decoder) {
+        match std::mem::replace(&mut self.measure, Measure::Nothing) {
+            Measure::Nothing => {}
+            Measure::Reset   => { decoder.take_keying(); }
+            Measure::Take    => {
+                let k = decoder.take_keying().summary();
+                self.result(&k);
+            }
+        }
+    }
+
+    fn view(&self) -> View {
+        let mut lines: Vec<Line> = vec![];
+        if !self.rounds.is_empty() {
+            let mut l: Line = vec![
+                (format!("{} ", self.i18n.t("practice.lines")), Mark::Dim),
+                (format!("{}  ", self.rounds.len()), Mark::Plain),
+                (format!("{} ", self.i18n.t("practice.accuracy")), Mark::Dim),
+                (format!("{:.0}%  ", self.tally.accuracy() * 100.0), Mark::Info),
+                (format!("{} ", self.i18n.t("send.mistakes")), Mark::Dim),
+                (format!("{}", self.spacing), Mark::Plain),
+            ];
+            if let Some(w) = report::mean(self.wpm.iter().copied()) {
+                l.push((format!("  {} ", self.i18n.t("send.speed")), Mark::Dim));
+                l.push((format!("{w:.1} WPM"), Mark::Plain));
+            }
+            lines.push(l);
+            lines.push(vec![]);
+        }
+        let status = match self.phase {
+            Phase::Wait(_) => "practice.get_ready",
+            Phase::Keying  => {
+                lines.push(vec![(self.i18n.t("send.key_this").into(), Mark::Dim)]);
+                lines.push(vec![]);
+                lines.push(vec![(self.target.clone(), Mark::Info)]);
+                if self.keyed.text.is_empty() { "send.start" } else { "send.enter" }
+            }
+            Phase::Result => {
+                lines.extend(self.result.iter().cloned());
+                "practice.line_scored"
+            }
+        };
+        View {
+            header:  format!(" {}  |  {}", self.i18n.t("app.title"),
+                             self.i18n.f("send.header", &[&self.kind_name(), &self.user_wpm])),
+            title:   format!(" {} ", self.i18n.f("send.title", &[&self.kind_name().to_uppercase()])),
+            lines,
+            input:   if self.phase == Phase::Result { String::new() } else { self.keyed.text.clone() },
+            status:  self.i18n.t(status).into(),
+            sending: true,
+            ..Default::default()
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut out = String::new();
+        if self.rounds.is_empty() {
+            let _ = writeln!(out, "{}", self.i18n.f("send.none", &[&self.kind_name()]));
+            return out;
+        }
+        let t = &self.tally;
+        let speed = report::mean(self.wpm.iter().copied())
+            .map(|w| self.i18n.f("send.summary_speed", &[&format!("{w:.1}")])).unwrap_or_default();
+        let _ = writeln!(out, "{}{speed}", self.i18n.f("send.summary", &[&self.kind_name(), &self.rounds.len(),
+                                                        &format!("{:.0}", t.accuracy() * 100.0)]));
+        let _ = writeln!(out, "  {}", self.i18n.f("send.counts", &[&t.correct, &t.substituted, &t.missed,
+                                                                  &t.inserted, &self.spacing]));
+        let worst: Vec<String> = self.chars_seen.worst(8).iter()
+            .map(|(c, miss, sent)| format!("{c} {miss}/{sent}")).collect();
+        if !worst.is_empty() {
+            let _ = writeln!(out, "  {}", self.i18n.f("send.most_wrong", &[&worst.join("  ")]));
+        }
+        out
+    }
+
+    fn record(&self) -> Option<DrillRecord> {
+        let wpm = report::mean(self.wpm.iter().copied()).map_or(self.user_wpm, |w| w.round() as u8);
+        (!self.rounds.is_empty()).then(|| DrillRecord {
+            kind:     self.kind_name().to_string(),
+            rounds:   self.rounds.len() as u32,
+            accuracy: Some(self.tally.accuracy()),
+            ..DrillRecord::new(PracticeMode::Send, wpm, &self.chars_seen)
+        })
+    }
+}
@@ -0,0 +1,110 @@
+// src/qso/band.rs  —  Band conventions: who is on, how fast, and where in the band
+//
+// The low bands are the locals' bands after dark — short skip, slower
+// rag-chews, nets.  20 m in daylight is the DX band; 15 and 10 m close at
+// night and leave only the odd local.  Contests stay off the WARC bands
+// (60, 30, 17, 12 m) and crowd the bottom of the CW segment; QRP and club
+// sprinters gather round their calling frequencies.
+use rand::Rng;
+use crate::config::{Band, QsoStyle};
+
+/// "20m" — or "2m" for the VHF contest, which is not on an HF band
+pub fn label(band: Band, style: QsoStyle) -> &'static str {
+    if style == QsoStyle::VhfContest { return "2m"; }
+    match band {
+        Band::B160 => "160m", Band::B80 => "80m", Band::B60 => "60m", Band::B40 => "40m",
+        Band::B30  => "30m",  Band::B20 => "20m", Band::B17 => "17m", Band::B15 => "15m",
+        Band::B12  => "12m",  Band::B10 => "10m",
+    }
+}
+
+/// 60, 30, 17 and 12 m: no contests by IARU band plan
+pub fn is_warc(band: Band) -> bool {
+    matches!(band, Band::B60 | Band::B30 | Band::B17 | Band::B12)
+}
+
+/// Styles that are contests in the band-plan sense (not POTA-style activations)
+pub fn is_contest(style: QsoStyle) -> bool {
+    matches!(style,
+        QsoStyle::Contest | QsoStyle::DxPileup | QsoStyle::DarcCwContest | QsoStyle::MwcContest
+        | QsoStyle::CwtContest | QsoStyle::WwaContest | QsoStyle::WpxContest
+        | QsoStyle::CqWwContest | QsoStyle::SstContest | QsoStyle::SkccSprint
+        | QsoStyle::NaqccSprint | QsoStyle::FistsSprint)
+}
+
+/// Share of callers that are DX rather than locals, by day (`daylight` 1)
+/// and by night (0)
+pub fn dx_share(band: Band, daylight: f64) -> f64 {
+    let (day, night) = match band {
+        Band::B160 => (0.05, 0.35),
+        Band::B80  => (0.10, 0.30),
+        Band::B60  => (0.10, 0.25),
+        Band::B40  => (0.30, 0.60),
+        Band::B30  => (0.50, 0.60),
+        Band::B20  => (0.80, 0.50),
+        Band::B17  => (0.85, 0.40),
+        Band::B15  => (0.85, 0.20),
+        Band::B12  => (0.85, 0.15),
+        Band::B10  => (0.80, 0.10),
+    };
+    daylight * day + (1.0 - daylight) * night
+}
+
+/// How far a "local" is on this band, in km
+pub fn local_km(band: Band) -> f64 {
+    match band {
+        Band::B160 => 800.0,
+        Band::B80  => 1000.0,
+        Band::B60  => 1200.0,
+        Band::B40  => 1500.0,
+        _          => 2500.0,
+    }
+}
+
+/// Typical SIM speed for a configured `wpm`: slower rag-chews on the low
+/// bands, a little quicker on 30 and 20 m where the DX chasers are
+pub fn wpm(band: Band, wpm: u8) -> u8 {
+    let factor = match band {
+        Band::B160 | Band::B80 => 0.9,
+        Band::B60              => 0.85,
+        Band::B30 | Band::B20  => 1.05,
+        Band::B10              => 0.95,
+        _                      => 1.0,
+    };
+    (f32::from(wpm) * factor).round().clamp(5.0, 50.0) as u8
+}
+
+/// Bottom of the CW segment and the QRP calling frequency, in kHz
+fn segment(band: Band) -> (f64, f64) {
+    match band {
+        Band::B160 => (1810.0,   1836.0),
+        Band::B80  => (3500.0,   3560.0),
+        Band::B60  => (5351.5,   5354.0),
+        Band::B40  => (7000.0,   7030.0),
+        Band::B30  => (10100.0, 10116.0),
+        Band::B20  => (14000.0, 14060.0),
+        Band::B17  => (18068.0, 18086.0),
+        Band::B15  => (21000.0, 21060.0),
+        Band::B12  => (24890.0, 24906.0),
+        Band::B10  => (28000.0, 28060.0),
+    }
+}
+
+/// Where the SIM is heard, in kHz: contests in the bottom 30 kHz, sprinters
+/// round the QRP frequency, activators and rag-chewers above the contest
+/// stretch.  The VHF contest is on 2 m.
+pub fn qrg_khz<R: Rng>(rng: &mut R, band: Band, style: QsoStyle) -> f64 {
+    let (edge, qrp) = segment(band);
+    let khz = match style {
+        QsoStyle::VhfContest => 144_000.0 + rng.gen_range(20.0..120.0),
+        _ if band == Band::B60 => rng.gen_range(edge..qrp),
+        QsoStyle::NaqccSprint | QsoStyle::FistsSprint | QsoStyle::SkccSprint
+            => qrp + rng.gen_range(-6.0..6.0),
+        s if is_contest(s) => edge + rng.gen_range(1.0..30.0),
+        QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+            => edge + rng.gen_range(25.0_f64..65.0).min(qrp - edge + 8.0),
+        _ => edge + rng.gen_range(15.0..(qrp - edge).max(16.0)),
+    };
+    // Stations sit on round-ish frequencies: 100 Hz steps
+    (khz * 10.0).round() / 10.0
+}
@@ -0,0 +1,783 @@
+// src/qso/callgen.rs  —  Synthetic callsigns in each country's real format
+//
+// A call is built as prefix + call-area digit + suffix, following the shapes
+// each licensing authority actually issues (DL1ABC, DO7XY, M0ABC, 2E0ABC,
+// K1AB, KB1ABC, AA1A, HB9ABC, …).  The QTH is picked first so the digit
+// matches the region where that matters (W6 in California, VE3 in Ontario,
+// JA8 on Hokkaido).  Every call is checked against the cty table so it
+// resolves to the intended DXCC entity.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use super::callsigns::Station;
+use super::{cty, locator};
+
+/// Where a generated station lives
+struct Qth {
+    city:    &'static str,
+    locator: &'static str,
+    /// SST SPC: state / province for US, VE, VK; DXCC prefix elsewhere
+    spc:     &'static str,
+    /// Call-area digit of the region, "" if the country has no call areas
+    area:    &'static str,
+}
+
+/// One callsign shape a country issues
+struct Shape {
+    prefixes: &'static [&'static str],
+    /// Call-area digits; "" when the digit is part of the prefix (HB9, M0, 2E0)
+    digits:   &'static str,
+    /// Possible suffix lengths
+    suffix:   &'static [usize],
+    weight:   u32,
+}
+
+struct Format {
+    /// DXCC primary prefix as returned by `cty::dxcc_prefix`
+    dxcc:   &'static str,
+    /// Relative on-air activity — how often the country shows up
+    weight: u32,
+    shapes: &'static [Shape],
+    names:  &'static [&'static str],
+    qths:   &'static [Qth],
+}
+
+const fn shape(prefixes: &'static [&'static str], digits: &'static str,
+               suffix: &'static [usize], weight: u32) -> Shape {
+    Shape { prefixes, digits, suffix, weight }
+}
+
+const ALL: &str = "0123456789";
+
+// ── Generator ─────────────────────────────────────────────────────────────────
+
+/// A random station from a random country, weighted by on-air activity.
+pub fn generate<R: Rng>(rng: &mut R) -> Station {
+    let fmt = FORMATS.choose_weighted(rng, |f| f.weight).unwrap();
+    let qth = fmt.qths.choose(rng).unwrap();
+    build(rng, fmt, qth)
+}
+
+/// A random station from one DXCC entity (primary prefix, e.g. "DL"),
+/// or None if there is no format for it.
+pub fn generate_in<R: Rng>(rng: &mut R, dxcc: &str) -> Option<Station> {
+    let fmt = FORMATS.iter().find(|f| f.dxcc == dxcc)?;
+    let qth = fmt.qths.choose(rng)?;
+    Some(build(rng, fmt, qth))
+}
+
+/// A station within `max_km` of `my_locator` — for the VHF/UHF contest.
+/// If fewer than three QTHs are in range the nearest three are used; without
+/// a usable locator only European QTHs (JO/JN/IO/KO/KP fields) are drawn.
+pub fn generate_near<R: Rng>(rng: &mut R, my_locator: &str, max_km: f64) -> Station {
+    let mut near: Vec<(&Format, &Qth, f64)> = FORMATS.iter()
+        .flat_map(|f| f.qths.iter().map(move |q| (f, q)))
+        .filter_map(|(f, q)| match locator::distance_km(my_locator, q.locator) {
+            Some(km) => Some((f, q, km)),
+            None     => matches!(&q.locator[..2], "JO" | "JN" | "IO" | "KO" | "KP")
+                            .then_some((f, q, 0.0)),
+        })
+        .collect();
+    near.sort_by(|a, b| a.2.total_cmp(&b.2));
+    let n = near.iter().take_while(|c| c.2 <= max_km).count().max(3);
+    match near[..n.min(near.len())].choose(rng) {
+        Some(&(fmt, qth, _)) => build(rng, fmt, qth),
+        None                 => generate(rng),
+    }
+}
+
+/// Name, QTH, locator and SPC for a known callsign, e.g. one drawn from a
+/// call-history file.  `state` (or else the call-area digit) narrows the QTH
+/// where the country has regions.  Countries without a format get an English
+/// name and the centre of their DXCC entity.
+pub fn station_for<R: Rng>(rng: &mut R, call: &str, state: Option<&str>) -> Station {
+    let info = cty::lookup(call);
+    let dxcc = info.as_ref().map_or("", |i| i.entity.prefix.as_str());
+    if let Some(fmt) = FORMATS.iter().find(|f| f.dxcc == dxcc) {
+        let wpx  = cty::wpx_prefix(call);
+        let area = &wpx[wpx.len().saturating_sub(1)..];
+        let by_state: Vec<&Qth> = fmt.qths.iter().filter(|q| Some(q.spc) == state).collect();
+        let by_area:  Vec<&Qth> = fmt.qths.iter().filter(|q| q.area == area).collect();
+        let qth = by_state.choose(rng).copied()
+            .or_else(|| by_area.choose(rng).copied())
+            .or_else(|| fmt.qths.choose(rng))
+            .unwrap();
+        return station(rng, call.to_string(), fmt.names, qth);
+    }
+    let (qth, loc) = match &info {
+        Some(i) => (i.entity.name.to_uppercase(), locator::from_latlon(i.entity.lat, i.entity.lon)),
+        None    => (String::new(), String::new()),
+    };
+    let spc = state.unwrap_or(dxcc).to_string();
+    Station {
+        call:    call.to_string(),
+        name:    NAMES_EN.choose(rng).unwrap().to_string(),
+        qth,
+        locator: loc,
+        cwt_ex:  spc.clone(),
+        spc,
+        skcc:    None,
+        naqcc:   None,
+        fists:   None,
+    }
+}
+
+/// Draw a call in `fmt` until it resolves to the intended entity
+/// (IW0U… is Sardinia, not Italy; R2… is Kaliningrad).
+fn build<R: Rng>(rng: &mut R, fmt: &Format, qth: &Qth) -> Station {
+    let mut call = make_call(rng, fmt, qth);
+    for _ in 0..20 {
+        if cty::dxcc_prefix(&call) == fmt.dxcc { break; }
+        call = make_call(rng, fmt, qth);
+    }
+    station(rng, call, fmt.names, qth)
+}
+
+fn station<R: Rng>(rng: &mut R, call: String, names: &[&str], qth: &Qth) -> Station {
+    Station {
+        call,
+        name:    names.choose(rng).unwrap().to_string(),
+        qth:     qth.city.to_string(),
+        locator: qth.locator.to_string(),
+        spc:     qth.spc.to_string(),
+        // About a third of the regulars are CWops members and send their number
+        cwt_ex:  if rng.gen_bool(0.35) {
+                     rng.gen_range(1000u32..=9999).to_string()
+                 } else {
+                     qth.spc.to_string()
+                 },
+        skcc:    None,
+        naqcc:   None,
+        fists:   None,
+    }
+}
+
+/// prefix + call-area digit + suffix.  Shapes whose digits do not include the
+/// QTH's call area are skipped, so a Californian never gets a W1 call.
+fn make_call<R: Rng>(rng: &mut R, fmt: &Format, qth: &Qth) -> String {
+    let shapes: Vec<&Shape> = fmt.shapes.iter()
+        .filter(|s| s.digits.is_empty() || qth.area.is_empty() || s.digits.contains(qth.area))
+        .collect();
+    let shape = shapes.choose_weighted(rng, |s| s.weight).map_or(&fmt.shapes[0], |s| *s);
+
+    let mut call = shape.prefixes.choose(rng).unwrap().to_string();
+    if !shape.digits.is_empty() {
+        if !qth.area.is_empty() && shape.digits.contains(qth.area) {
+            call.push_str(qth.area);
+        } else {
+            let d = shape.digits.as_bytes();
+            call.push(d[rng.gen_range(0..d.len())] as char);
+        }
+    }
+    let len = *shape.suffix.choose(rng).unwrap();
+    for i in 0..len {
+        // Suffixes never start with Q (Q-codes): draw from 25 letters, skip over Q
+        let c = if i == 0 {
+            let c = rng.gen_range(b'A'..=b'Y');
+            if c >= b'Q' { c + 1 } else { c }
+        } else {
+            rng.gen_range(b'A'..=b'Z')
+        };
+        call.push(c as char);
+    }
+    call
+}
+
+// ── Callsign formats ──────────────────────────────────────────────────────────
+/// US two-letter K/N/W prefixes — KH, KL and KP belong to Hawaii, Alaska and the Caribbean
+static US_2X: &[&str] = &[
+    "KA", "KB", "KC", "KD", "KE", "KF", "KG", "KI", "KJ", "KK", "KM", "KN", "KO", "KQ",
+    "KR", "KS", "KT", "KU", "KV", "KW", "KX", "KY", "KZ", "NA", "NB", "NC", "ND", "NE",
+    "NF", "NG", "NI", "NJ", "NK", "NM", "NN", "NO", "NQ", "NR", "NS", "NT", "NU", "NV",
+    "NW", "NX", "NY", "NZ", "WA", "WB", "WC", "WD", "WE", "WF", "WG", "WI", "WJ", "WK",
+    "WM", "WN", "WO", "WQ", "WR", "WS", "WT", "WU", "WV", "WW", "WX", "WY", "WZ",
+];
+/// US Amateur Extra A-block (AL is Alaska, AH the Pacific)
+static US_AX: &[&str] = &["AA", "AB", "AC", "AD", "AE", "AF", "AG", "AI", "AJ", "AK"];
+static US_1X: &[&str] = &["K", "N", "W"];
+
+static FORMATS: &[Format] = &[
+    Format { dxcc: "K", weight: 30, names: NAMES_EN, qths: QTH_K, shapes: &[
+        shape(US_1X, ALL, &[3], 30),   // K1ABC   General
+        shape(US_2X, ALL, &[3], 35),   // KB1ABC  Technician / General
+        shape(US_AX, ALL, &[2], 12),   // AA1AB   Extra
+        shape(US_2X, ALL, &[2],  8),   // KA1AB   Advanced / Extra
+        shape(US_1X, ALL, &[2], 10),   // K1AB    Extra 1x2
+        shape(US_AX, ALL, &[1],  3),   // AA1A    Extra 2x1
+    ]},
+    Format { dxcc: "VE", weight: 5, names: NAMES_EN, qths: QTH_VE, shapes: &[
+        shape(&["VE", "VA"], ALL, &[2, 3, 3], 1),
+    ]},
+    Format { dxcc: "DL", weight: 20, names: NAMES_DE, qths: QTH_DL, shapes: &[
+        shape(&["DL", "DK", "DJ", "DF", "DG", "DH", "DB", "DC", "DD", "DM"], ALL, &[2, 3, 3], 85),
+        shape(&["DO"], ALL, &[3], 15),  // class E
+    ]},
+    Format { dxcc: "G", weight: 8, names: NAMES_EN, qths: QTH_G, shapes: &[
+        shape(&["G"],  "01234678", &[3], 40),
+        shape(&["M"],  "0",        &[3], 30),
+        shape(&["M"],  "367",      &[3], 15),   // foundation
+        shape(&["2E"], "01",       &[3], 15),   // intermediate
+    ]},
+    Format { dxcc: "GM", weight: 2, names: NAMES_EN, qths: QTH_GM, shapes: &[
+        shape(&["GM"], "03468", &[3], 60),
+        shape(&["MM"], "0367",  &[3], 30),
+        shape(&["2M"], "0",     &[3], 10),
+    ]},
+    Format { dxcc: "GW", weight: 1, names: NAMES_EN, qths: QTH_GW, shapes: &[
+        shape(&["GW"], "03468", &[3], 60),
+        shape(&["MW"], "0367",  &[3], 30),
+        shape(&["2W"], "0",     &[3], 10),
+    ]},
+    Format { dxcc: "EI", weight: 2, names: NAMES_EN, qths: QTH_EI, shapes: &[
+        shape(&["EI"], "2345679", &[2, 3], 1),
+    ]},
+    Format { dxcc: "F", weight: 5, names: NAMES_FR, qths: QTH_F, shapes: &[
+        shape(&["F"], "14568", &[3], 70),
+        shape(&["F"], "56",    &[2], 30),
+    ]},
+    Format { dxcc: "I", weight: 8, names: NAMES_IT, qths: QTH_I, shapes: &[
+        shape(&["I"], ALL, &[2, 3], 30),
+        shape(&["IK", "IZ", "IW"], ALL, &[3], 70),
+    ]},
+    Format { dxcc: "EA", weight: 6, names: NAMES_ES, qths: QTH_EA, shapes: &[
+        shape(&["EA", "EB", "EC", "ED"], "123457", &[2, 3], 1),
+    ]},
+    Format { dxcc: "CT", weight: 2, names: NAMES_PT, qths: QTH_CT, shapes: &[
+        shape(&["CT"], "127", &[3], 80),
+        shape(&["CS"], "7",   &[3], 20),
+    ]},
+    Format { dxcc: "PA", weight: 4, names: NAMES_NL, qths: QTH_PA, shapes: &[
+        shape(&["PA", "PD", "PE", "PH", "PB"], ALL, &[2, 3], 1),
+    ]},
+    Format { dxcc: "ON", weight: 3, names: NAMES_NL, qths: QTH_ON, shapes: &[
+        shape(&["ON"], "2345678", &[2, 3], 90),
+        shape(&["OO", "OT"], "4579", &[2, 3], 10),
+    ]},
+    Format { dxcc: "OE", weight: 3, names: NAMES_DE, qths: QTH_OE, shapes: &[
+        shape(&["OE"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "HB", weight: 2, names: NAMES_DE, qths: QTH_HB, shapes: &[
+        shape(&["HB9"], "", &[2, 3], 85),
+        shape(&["HB3"], "", &[3], 15),
+    ]},
+    Format { dxcc: "SP", weight: 7, names: NAMES_SLAVIC, qths: QTH_SP, shapes: &[
+        shape(&["SP", "SQ", "SO"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "OK", weight: 5, names: NAMES_SLAVIC, qths: QTH_OK, shapes: &[
+        shape(&["OK"], "12", &[2, 3], 1),
+    ]},
+    Format { dxcc: "OM", weight: 2, names: NAMES_SLAVIC, qths: QTH_OM, shapes: &[
+        shape(&["OM"], "12345678", &[2, 3], 1),
+    ]},
+    Format { dxcc: "HA", weight: 3, names: NAMES_HA, qths: QTH_HA, shapes: &[
+        shape(&["HA", "HG"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "S5", weight: 2, names: NAMES_SLAVIC, qths: QTH_S5, shapes: &[
+        shape(&["S5"], ALL, &[2, 3], 1),
+    ]},
+    Format { dxcc: "9A", weight: 2, names: NAMES_SLAVIC, qths: QTH_9A, shapes: &[
+        shape(&["9A"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "YU", weight: 2, names: NAMES_SLAVIC, qths: QTH_YU, shapes: &[
+        shape(&["YU", "YT"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "LZ", weight: 2, names: NAMES_BALKAN, qths: QTH_LZ, shapes: &[
+        shape(&["LZ"], "12345", &[2, 3], 1),
+    ]},
+    Format { dxcc: "YO", weight: 2, names: NAMES_BALKAN, qths: QTH_YO, shapes: &[
+        shape(&["YO"], "23456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "SV", weight: 2, names: NAMES_BALKAN, qths: QTH_SV, shapes: &[
+        shape(&["SV"], "12378", &[2, 3], 1),
+    ]},
+    Format { dxcc: "SM", weight: 4, names: NAMES_NORDIC, qths: QTH_SM, shapes: &[
+        shape(&["SM", "SA", "SK"], "01234567", &[2, 3], 1),
+    ]},
+    Format { dxcc: "LA", weight: 2, names: NAMES_NORDIC, qths: QTH_LA, shapes: &[
+        shape(&["LA", "LB"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "OZ", weight: 2, names: NAMES_NORDIC, qths: QTH_OZ, shapes: &[
+        shape(&["OZ", "OU"], "123456789", &[1, 2, 3], 1),
+    ]},
+    Format { dxcc: "OH", weight: 3, names: NAMES_FI, qths: QTH_OH, shapes: &[
+        shape(&["OH"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "TF", weight: 1, names: NAMES_IS, qths: QTH_TF, shapes: &[
+        shape(&["TF"], "12345678", &[2, 3], 1),
+    ]},
+    Format { dxcc: "ES", weight: 1, names: NAMES_BALTIC, qths: QTH_ES, shapes: &[
+        shape(&["ES"], "12345678", &[2, 3], 1),
+    ]},
+    Format { dxcc: "YL", weight: 1, names: NAMES_BALTIC, qths: QTH_YL, shapes: &[
+        shape(&["YL"], "23", &[2, 3], 1),
+    ]},
+    Format { dxcc: "LY", weight: 2, names: NAMES_BALTIC, qths: QTH_LY, shapes: &[
+        shape(&["LY"], "12345", &[1, 2, 3], 1),
+    ]},
+    Format { dxcc: "UA", weight: 10, names: NAMES_RU, qths: QTH_UA, shapes: &[
+        shape(&["UA", "RA", "RW", "RX", "RZ", "RV", "RU", "RN", "RK", "R"], "1346", &[2, 3], 1),
+    ]},
+    Format { dxcc: "UA9", weight: 3, names: NAMES_RU, qths: QTH_UA9, shapes: &[
+        shape(&["UA", "RA", "RW", "RX", "RZ", "RV", "RU", "RK", "R"], "890", &[2, 3], 1),
+    ]},
+    Format { dxcc: "UR", weight: 5, names: NAMES_RU, qths: QTH_UR, shapes: &[
+        shape(&["UR", "UT", "US", "UX", "UY", "UZ", "UW", "UV"], "012345678", &[2, 3], 1),
+    ]},
+    Format { dxcc: "EU", weight: 2, names: NAMES_RU, qths: QTH_EU, shapes: &[
+        shape(&["EW", "EU"], "12345678", &[2, 3], 1),
+    ]},
+    Format { dxcc: "JA", weight: 12, names: NAMES_JA, qths: QTH_JA, shapes: &[
+        shape(&["JA", "JE", "JF", "JG", "JH", "JI", "JJ", "JK", "JL", "JM", "JN", "JO",
+                "JP", "JQ", "JR", "JS"], ALL, &[3], 90),
+        shape(&["7K", "7L", "7M", "7N"], "1", &[3], 10),
+    ]},
+    Format { dxcc: "BY", weight: 2, names: NAMES_ZH, qths: QTH_BY, shapes: &[
+        shape(&["BA", "BD", "BG", "BH", "BI"], ALL, &[2, 3], 1),
+    ]},
+    Format { dxcc: "HL", weight: 1, names: NAMES_KO, qths: QTH_HL, shapes: &[
+        shape(&["HL", "DS"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "VK", weight: 3, names: NAMES_EN, qths: QTH_VK, shapes: &[
+        shape(&["VK"], ALL, &[2, 3, 3], 1),
+    ]},
+    Format { dxcc: "ZL", weight: 1, names: NAMES_EN, qths: QTH_ZL, shapes: &[
+        shape(&["ZL"], "1234", &[2, 3], 1),
+    ]},
+    Format { dxcc: "LU", weight: 2, names: NAMES_ES, qths: QTH_LU, shapes: &[
+        shape(&["LU"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "PY", weight: 3, names: NAMES_PT, qths: QTH_PY, shapes: &[
+        shape(&["PY", "PU"], "123456789", &[2, 3], 1),
+    ]},
+    Format { dxcc: "XE", weight: 1, names: NAMES_ES, qths: QTH_XE, shapes: &[
+        shape(&["XE"], "123", &[2, 3], 1),
+    ]},
+    Format { dxcc: "ZS", weight: 1, names: NAMES_EN, qths: QTH_ZS, shapes: &[
+        shape(&["ZS"], "123456", &[2, 3], 1),
+    ]},
+];
+
+// ── Operator names ────────────────────────────────────────────────────────────
+static NAMES_DE: &[&str] = &[
+    "HANS", "PETER", "KLAUS", "JUERGEN", "WOLFGANG", "MICHAEL", "THOMAS", "ANDREAS",
+    "STEFAN", "FRANK", "UWE", "BERND", "DIETER", "JOERG", "MARTIN", "KARL", "HORST",
+    "RALF", "MARKUS", "SABINE", "PETRA", "ULI", "GERD", "DIRK",
+];
+static NAMES_EN: &[&str] = &[
+    "JOHN", "MIKE", "BOB", "DAVE", "JIM", "TOM", "BILL", "STEVE", "PAUL", "MARK", "DAN",
+    "RICH", "CHRIS", "JEFF", "GARY", "TIM", "KEN", "RON", "JOE", "ED", "AL", "DON",
+    "SUE", "MARY", "RANDY", "HANK", "BRUCE", "NEIL",
+];
+static NAMES_FR: &[&str] = &[
+    "PIERRE", "JEAN", "MICHEL", "ALAIN", "PHILIPPE", "BERNARD", "PATRICK", "DIDIER",
+    "ERIC", "LUC", "FRANCK", "GILLES", "SERGE", "CLAUDE",
+];
+static NAMES_IT: &[&str] = &[
+    "MARCO", "GIUSEPPE", "FRANCO", "PAOLO", "LUIGI", "ANDREA", "MARIO", "ROBERTO",
+    "STEFANO", "GIANNI", "SERGIO", "FABIO", "MASSIMO", "CARLO",
+];
+static NAMES_ES: &[&str] = &[
+    "JOSE", "ANTONIO", "MANUEL", "PACO", "JUAN", "CARLOS", "LUIS", "JAVIER", "MIGUEL",
+    "PEDRO", "JORGE", "RAFA", "DIEGO",
+];
+static NAMES_PT: &[&str] = &[
+    "JOAO", "JOSE", "ANTONIO", "PAULO", "CARLOS", "RUI", "LUIS", "MARCOS", "FERNANDO",
+    "RICARDO", "SERGIO", "EDU",
+];
+static NAMES_NL: &[&str] = &[
+    "JAN", "PIET", "KEES", "HENK", "WIM", "JOOP", "GERRIT", "BART", "DIRK", "JOS",
+    "RUUD", "LUC", "MARC",
+];
+static NAMES_NORDIC: &[&str] = &[
+    "LARS", "BJORN", "OLE", "ERIK", "NILS", "SVEN", "JAN", "PER", "ANDERS", "MAGNUS",
+    "JENS", "SOREN", "HENRIK", "KNUT", "GUNNAR",
+];
+static NAMES_FI: &[&str] = &[
+    "JUHA", "MATTI", "PEKKA", "KARI", "TIMO", "JARI", "MARKKU", "ARI", "MIKKO", "HANNU",
+];
+static NAMES_SLAVIC: &[&str] = &[
+    "TOMASZ", "PIOTR", "JAN", "JIRI", "PAVEL", "PETR", "MAREK", "ADAM", "ZDENEK",
+    "MILAN", "IVAN", "JOZEF", "BRANKO", "DRAGAN", "ZORAN", "ANDRZEJ",
+];
+static NAMES_HA: &[&str] = &[
+    "LASZLO", "ISTVAN", "ZOLTAN", "GABOR", "ATTILA", "JANOS", "TIBOR", "SANDOR",
+];
+static NAMES_BALKAN: &[&str] = &[
+    "ION", "MIHAI", "DAN", "ADRIAN", "NICU", "CRISTI", "GEORGE", "NIKOS", "KOSTAS",
+    "DIMITRIS", "PANOS", "IVO", "STOYAN", "PLAMEN",
+];
+static NAMES_RU: &[&str] = &[
+    "IVAN", "SERGEY", "ALEX", "VLAD", "YURI", "IGOR", "OLEG", "DMITRY", "ANDREY",
+    "NIKOLAI", "VICTOR", "PAVEL", "SASHA", "MISHA", "VOVA", "TARAS", "BOGDAN",
+];
+static NAMES_BALTIC: &[&str] = &[
+    "TOMAS", "JANIS", "ANDRIS", "TONNO", "MART", "JONAS", "GINTAS", "AIVARS", "ALGIS",
+    "TOOMAS", "PEETER",
+];
+static NAMES_IS: &[&str] = &["SIGGI", "JON", "GUNNAR", "OLAFUR", "EINAR", "KRISTJAN"];
+static NAMES_JA: &[&str] = &[
+    "KENJI", "TAKA", "HIRO", "YOSHI", "AKI", "SHIN", "KAZU", "MASA", "TOSHI", "NORI",
+    "KEN", "YUKI",
+];
+static NAMES_ZH: &[&str] = &["LI", "WANG", "ZHANG", "CHEN", "JACK", "TONY", "DAVID", "LEO"];
+static NAMES_KO: &[&str] = &["KIM", "LEE", "PARK", "CHOI", "JUNG", "KANG", "YOON", "HAN"];
+
+// ── QTHs (locators are the city centres) ──────────────────────────────────────
+static QTH_K: &[Qth] = &[
+    Qth { city: "BOSTON",        locator: "FN42LI", spc: "MA",  area: "1" },
+    Qth { city: "HARTFORD",      locator: "FN31PS", spc: "CT",  area: "1" },
+    Qth { city: "PORTLAND",      locator: "FN43UP", spc: "ME",  area: "1" },
+    Qth { city: "BURLINGTON",    locator: "FN34JL", spc: "VT",  area: "1" },
+    Qth { city: "CONCORD",       locator: "FN43FF", spc: "NH",  area: "1" },
+    Qth { city: "PROVIDENCE",    locator: "FN41HT", spc: "RI",  area: "1" },
+    Qth { city: "NEW YORK",      locator: "FN20XR", spc: "NY",  area: "2" },
+    Qth { city: "ALBANY",        locator: "FN32DP", spc: "NY",  area: "2" },
+    Qth { city: "TRENTON",       locator: "FN20OF", spc: "NJ",  area: "2" },
+    Qth { city: "BUFFALO",       locator: "FN02NV", spc: "NY",  area: "2" },
+    Qth { city: "PHILADELPHIA",  locator: "FM29JW", spc: "PA",  area: "3" },
+    Qth { city: "PITTSBURGH",    locator: "FN00AK", spc: "PA",  area: "3" },
+    Qth { city: "BALTIMORE",     locator: "FM19QG", spc: "MD",  area: "3" },
+    Qth { city: "DOVER",         locator: "FM29FD", spc: "DE",  area: "3" },
+    Qth { city: "RALEIGH",       locator: "FM05QS", spc: "NC",  area: "4" },
+    Qth { city: "ATLANTA",       locator: "EM73TS", spc: "GA",  area: "4" },
+    Qth { city: "ORLANDO",       locator: "EL98HM", spc: "FL",  area: "4" },
+    Qth { city: "NASHVILLE",     locator: "EM66OD", spc: "TN",  area: "4" },
+    Qth { city: "RICHMOND",      locator: "FM17GM", spc: "VA",  area: "4" },
+    Qth { city: "LOUISVILLE",    locator: "EM78CG", spc: "KY",  area: "4" },
+    Qth { city: "CHARLESTON",    locator: "FM02AS", spc: "SC",  area: "4" },
+    Qth { city: "BIRMINGHAM",    locator: "EM63OM", spc: "AL",  area: "4" },
+    Qth { city: "DALLAS",        locator: "EM12OS", spc: "TX",  area: "5" },
+    Qth { city: "AUSTIN",        locator: "EM10DG", spc: "TX",  area: "5" },
+    Qth { city: "TULSA",         locator: "EM26AD", spc: "OK",  area: "5" },
+    Qth { city: "LITTLE ROCK",   locator: "EM34US", spc: "AR",  area: "5" },
+    Qth { city: "NEW ORLEANS",   locator: "EL49XW", spc: "LA",  area: "5" },
+    Qth { city: "JACKSON",       locator: "EM42VH", spc: "MS",  area: "5" },
+    Qth { city: "ALBUQUERQUE",   locator: "DM65QB", spc: "NM",  area: "5" },
+    Qth { city: "LOS ANGELES",   locator: "DM04VB", spc: "CA",  area: "6" },
+    Qth { city: "SAN DIEGO",     locator: "DM12KR", spc: "CA",  area: "6" },
+    Qth { city: "SACRAMENTO",    locator: "CM98GN", spc: "CA",  area: "6" },
+    Qth { city: "SAN JOSE",      locator: "CM97BI", spc: "CA",  area: "6" },
+    Qth { city: "SEATTLE",       locator: "CN87UO", spc: "WA",  area: "7" },
+    Qth { city: "PORTLAND",      locator: "CN85PM", spc: "OR",  area: "7" },
+    Qth { city: "PHOENIX",       locator: "DM33XK", spc: "AZ",  area: "7" },
+    Qth { city: "OGDEN",         locator: "DN41AF", spc: "UT",  area: "7" },
+    Qth { city: "BOISE",         locator: "DN13VO", spc: "ID",  area: "7" },
+    Qth { city: "RENO",          locator: "DM09CM", spc: "NV",  area: "7" },
+    Qth { city: "BILLINGS",      locator: "DN55SS", spc: "MT",  area: "7" },
+    Qth { city: "CASPER",        locator: "DN62UU", spc: "WY",  area: "7" },
+    Qth { city: "DETROIT",       locator: "EN82LH", spc: "MI",  area: "8" },
+    Qth { city: "COLUMBUS",      locator: "EM89MX", spc: "OH",  area: "8" },
+    Qth { city: "CLEVELAND",     locator: "EN91DM", spc: "OH",  area: "8" },
+    Qth { city: "CHARLESTON",    locator: "EM98EI", spc: "WV",  area: "8" },
+    Qth { city: "CHICAGO",       locator: "EN61EV", spc: "IL",  area: "9" },
+    Qth { city: "INDIANAPOLIS",  locator: "EM69WS", spc: "IN",  area: "9" },
+    Qth { city: "MILWAUKEE",     locator: "EN63BA", spc: "WI",  area: "9" },
+    Qth { city: "DENVER",        locator: "DM79MR", spc: "CO",  area: "0" },
+    Qth { city: "MINNEAPOLIS",   locator: "EN34IX", spc: "MN",  area: "0" },
+    Qth { city: "ST LOUIS",      locator: "EM48VP", spc: "MO",  area: "0" },
+    Qth { city: "DES MOINES",    locator: "EN31EO", spc: "IA",  area: "0" },
+    Qth { city: "WICHITA",       locator: "EM17HQ", spc: "KS",  area: "0" },
+    Qth { city: "OMAHA",         locator: "EN21AG", spc: "NE",  area: "0" },
+    Qth { city: "FARGO",         locator: "EN16OV", spc: "ND",  area: "0" },
+    Qth { city: "SIOUX FALLS",   locator: "EN13PN", spc: "SD",  area: "0" },
+];
+static QTH_VE: &[Qth] = &[
+    Qth { city: "HALIFAX",       locator: "FN84FP", spc: "NS",  area: "1" },
+    Qth { city: "MONCTON",       locator: "FN76OC", spc: "NB",  area: "9" },
+    Qth { city: "MONTREAL",      locator: "FN35FM", spc: "QC",  area: "2" },
+    Qth { city: "QUEBEC",        locator: "FN46JT", spc: "QC",  area: "2" },
+    Qth { city: "TORONTO",       locator: "FN03HP", spc: "ON",  area: "3" },
+    Qth { city: "OTTAWA",        locator: "FN25DK", spc: "ON",  area: "3" },
+    Qth { city: "WINNIPEG",      locator: "EN19KV", spc: "MB",  area: "4" },
+    Qth { city: "REGINA",        locator: "DO70QK", spc: "SK",  area: "5" },
+    Qth { city: "CALGARY",       locator: "DO21XB", spc: "AB",  area: "6" },
+    Qth { city: "EDMONTON",      locator: "DO33GN", spc: "AB",  area: "6" },
+    Qth { city: "VANCOUVER",     locator: "CN89KG", spc: "BC",  area: "7" },
+    Qth { city: "VICTORIA",      locator: "CN88HK", spc: "BC",  area: "7" },
+];
+static QTH_VK: &[Qth] = &[
+    Qth { city: "CANBERRA",      locator: "QF44NR", spc: "ACT", area: "1" },
+    Qth { city: "SYDNEY",        locator: "QF56OD", spc: "NSW", area: "2" },
+    Qth { city: "NEWCASTLE",     locator: "QF57VB", spc: "NSW", area: "2" },
+    Qth { city: "MELBOURNE",     locator: "QF22LE", spc: "VIC", area: "3" },
+    Qth { city: "BRISBANE",      locator: "QG62MM", spc: "QLD", area: "4" },
+    Qth { city: "ADELAIDE",      locator: "PF95HB", spc: "SA",  area: "5" },
+    Qth { city: "PERTH",         locator: "OF78WB", spc: "WA",  area: "6" },
+    Qth { city: "HOBART",        locator: "QE37PC", spc: "TAS", area: "7" },
+    Qth { city: "DARWIN",        locator: "PH57KM", spc: "NT",  area: "8" },
+];
+static QTH_JA: &[Qth] = &[
+    Qth { city: "TOKYO",         locator: "PM95UQ", spc: "JA",  area: "1" },
+    Qth { city: "YOKOHAMA",      locator: "PM95TK", spc: "JA",  area: "1" },
+    Qth { city: "NAGOYA",        locator: "PM85KE", spc: "JA",  area: "2" },
+    Qth { city: "OSAKA",         locator: "PM74SQ", spc: "JA",  area: "3" },
+    Qth { city: "KYOTO",         locator: "PM75VA", spc: "JA",  area: "3" },
+    Qth { city: "HIROSHIMA",     locator: "PM64FJ", spc: "JA",  area: "4" },
+    Qth { city: "MATSUYAMA",     locator: "PM63JU", spc: "JA",  area: "5" },
+    Qth { city: "FUKUOKA",       locator: "PM53EO", spc: "JA",  area: "6" },
+    Qth { city: "SENDAI",        locator: "QM08KG", spc: "JA",  area: "7" },
+    Qth { city: "SAPPORO",       locator: "QN03QB", spc: "JA",  area: "8" },
+    Qth { city: "KANAZAWA",      locator: "PM86HN", spc: "JA",  area: "9" },
+    Qth { city: "NIIGATA",       locator: "PM97MW", spc: "JA",  area: "0" },
+];
+static QTH_ZL: &[Qth] = &[
+    Qth { city: "AUCKLAND",      locator: "RF73JD", spc: "ZL",  area: "1" },
+    Qth { city: "WELLINGTON",    locator: "RE78JR", spc: "ZL",  area: "2" },
+    Qth { city: "CHRISTCHURCH",  locator: "RE66HL", spc: "ZL",  area: "3" },
+    Qth { city: "DUNEDIN",       locator: "RE54GD", spc: "ZL",  area: "4" },
+];
+static QTH_DL: &[Qth] = &[
+    Qth { city: "BERLIN",        locator: "JO62QM", spc: "DL",  area: "" },
+    Qth { city: "HAMBURG",       locator: "JO43XN", spc: "DL",  area: "" },
+    Qth { city: "MUNICH",        locator: "JN58SD", spc: "DL",  area: "" },
+    Qth { city: "COLOGNE",       locator: "JO30LW", spc: "DL",  area: "" },
+    Qth { city: "FRANKFURT",     locator: "JO40IC", spc: "DL",  area: "" },
+    Qth { city: "STUTTGART",     locator: "JN48OS", spc: "DL",  area: "" },
+    Qth { city: "DRESDEN",       locator: "JO61UB", spc: "DL",  area: "" },
+    Qth { city: "LEIPZIG",       locator: "JO61EI", spc: "DL",  area: "" },
+    Qth { city: "HANNOVER",      locator: "JO42UI", spc: "DL",  area: "" },
+    Qth { city: "BREMEN",        locator: "JO43JB", spc: "DL",  area: "" },
+    Qth { city: "NUREMBERG",     locator: "JN59MK", spc: "DL",  area: "" },
+    Qth { city: "DORTMUND",      locator: "JO31RM", spc: "DL",  area: "" },
+    Qth { city: "KIEL",          locator: "JO54BH", spc: "DL",  area: "" },
+    Qth { city: "ROSTOCK",       locator: "JO64BC", spc: "DL",  area: "" },
+    Qth { city: "FREIBURG",      locator: "JN37WX", spc: "DL",  area: "" },
+];
+static QTH_G: &[Qth] = &[
+    Qth { city: "LONDON",        locator: "IO91WM", spc: "G",   area: "" },
+    Qth { city: "BIRMINGHAM",    locator: "IO92BL", spc: "G",   area: "" },
+    Qth { city: "MANCHESTER",    locator: "IO83VL", spc: "G",   area: "" },
+    Qth { city: "LEEDS",         locator: "IO93FT", spc: "G",   area: "" },
+    Qth { city: "BRISTOL",       locator: "IO81QK", spc: "G",   area: "" },
+    Qth { city: "NORWICH",       locator: "JO02PP", spc: "G",   area: "" },
+    Qth { city: "YORK",          locator: "IO93LX", spc: "G",   area: "" },
+];
+static QTH_GM: &[Qth] = &[
+    Qth { city: "EDINBURGH",     locator: "IO85JW", spc: "GM",  area: "" },
+    Qth { city: "GLASGOW",       locator: "IO75VU", spc: "GM",  area: "" },
+    Qth { city: "ABERDEEN",      locator: "IO87WD", spc: "GM",  area: "" },
+    Qth { city: "INVERNESS",     locator: "IO77VL", spc: "GM",  area: "" },
+];
+static QTH_GW: &[Qth] = &[
+    Qth { city: "CARDIFF",       locator: "IO81JL", spc: "GW",  area: "" },
+    Qth { city: "SWANSEA",       locator: "IO81AO", spc: "GW",  area: "" },
+];
+static QTH_EI: &[Qth] = &[
+    Qth { city: "DUBLIN",        locator: "IO63UI", spc: "EI",  area: "" },
+    Qth { city: "CORK",          locator: "IO51SV", spc: "EI",  area: "" },
+    Qth { city: "GALWAY",        locator: "IO53LG", spc: "EI",  area: "" },
+];
+static QTH_F: &[Qth] = &[
+    Qth { city: "PARIS",         locator: "JN18EU", spc: "F",   area: "" },
+    Qth { city: "LYON",          locator: "JN25KS", spc: "F",   area: "" },
+    Qth { city: "MARSEILLE",     locator: "JN23QH", spc: "F",   area: "" },
+    Qth { city: "TOULOUSE",      locator: "JN03RO", spc: "F",   area: "" },
+    Qth { city: "BORDEAUX",      locator: "IN94RU", spc: "F",   area: "" },
+    Qth { city: "LILLE",         locator: "JO10MP", spc: "F",   area: "" },
+    Qth { city: "NANTES",        locator: "IN97FF", spc: "F",   area: "" },
+    Qth { city: "STRASBOURG",    locator: "JN38VN", spc: "F",   area: "" },
+];
+static QTH_I: &[Qth] = &[
+    Qth { city: "ROME",          locator: "JN61GV", spc: "I",   area: "0" },
+    Qth { city: "MILAN",         locator: "JN45OL", spc: "I",   area: "2" },
+    Qth { city: "NAPLES",        locator: "JN70DU", spc: "I",   area: "8" },
+    Qth { city: "TURIN",         locator: "JN35UB", spc: "I",   area: "1" },
+    Qth { city: "BOLOGNA",       locator: "JN54QL", spc: "I",   area: "4" },
+    Qth { city: "FLORENCE",      locator: "JN53PS", spc: "I",   area: "5" },
+    Qth { city: "VENICE",        locator: "JN65DK", spc: "I",   area: "3" },
+    Qth { city: "PALERMO",       locator: "JM68QC", spc: "I",   area: "9" },
+];
+static QTH_EA: &[Qth] = &[
+    Qth { city: "MADRID",        locator: "IN80DK", spc: "EA",  area: "4" },
+    Qth { city: "BARCELONA",     locator: "JN11CJ", spc: "EA",  area: "3" },
+    Qth { city: "VALENCIA",      locator: "IM99TL", spc: "EA",  area: "5" },
+    Qth { city: "SEVILLE",       locator: "IM77AJ", spc: "EA",  area: "7" },
+    Qth { city: "BILBAO",        locator: "IN83MG", spc: "EA",  area: "2" },
+    Qth { city: "ZARAGOZA",      locator: "IN91NP", spc: "EA",  area: "2" },
+    Qth { city: "LA CORUNA",     locator: "IN53TI", spc: "EA",  area: "1" },
+];
+static QTH_CT: &[Qth] = &[
+    Qth { city: "LISBON",        locator: "IM58KR", spc: "CT",  area: "" },
+    Qth { city: "PORTO",         locator: "IN51QD", spc: "CT",  area: "" },
+];
+static QTH_PA: &[Qth] = &[
+    Qth { city: "AMSTERDAM",     locator: "JO22KI", spc: "PA",  area: "" },
+    Qth { city: "ROTTERDAM",     locator: "JO21FW", spc: "PA",  area: "" },
+    Qth { city: "UTRECHT",       locator: "JO22NC", spc: "PA",  area: "" },
+    Qth { city: "EINDHOVEN",     locator: "JO21RK", spc: "PA",  area: "" },
+    Qth { city: "GRONINGEN",     locator: "JO33GF", spc: "PA",  area: "" },
+];
+static QTH_ON: &[Qth] = &[
+    Qth { city: "BRUSSELS",      locator: "JO20EU", spc: "ON",  area: "" },
+    Qth { city: "ANTWERP",       locator: "JO21EF", spc: "ON",  area: "" },
+    Qth { city: "GHENT",         locator: "JO11UB", spc: "ON",  area: "" },
+    Qth { city: "LIEGE",         locator: "JO20SP", spc: "ON",  area: "" },
+];
+static QTH_OE: &[Qth] = &[
+    Qth { city: "VIENNA",        locator: "JN88EF", spc: "OE",  area: "1" },
+    Qth { city: "SALZBURG",      locator: "JN67MT", spc: "OE",  area: "2" },
+    Qth { city: "ST POELTEN",    locator: "JN78TE", spc: "OE",  area: "3" },
+    Qth { city: "LINZ",          locator: "JN78DH", spc: "OE",  area: "5" },
+    Qth { city: "GRAZ",          locator: "JN77RB", spc: "OE",  area: "6" },
+    Qth { city: "INNSBRUCK",     locator: "JN57QG", spc: "OE",  area: "7" },
+    Qth { city: "KLAGENFURT",    locator: "JN76DO", spc: "OE",  area: "8" },
+];
+static QTH_HB: &[Qth] = &[
+    Qth { city: "ZURICH",        locator: "JN47GI", spc: "HB",  area: "" },
+    Qth { city: "BERN",          locator: "JN36RW", spc: "HB",  area: "" },
+    Qth { city: "GENEVA",        locator: "JN36BE", spc: "HB",  area: "" },
+    Qth { city: "BASEL",         locator: "JN37TN", spc: "HB",  area: "" },
+    Qth { city: "LUGANO",        locator: "JN46LA", spc: "HB",  area: "" },
+];
+static QTH_SP: &[Qth] = &[
+    Qth { city: "WARSAW",        locator: "KO02MF", spc: "SP",  area: "5" },
+    Qth { city: "KRAKOW",        locator: "JO90XB", spc: "SP",  area: "9" },
+    Qth { city: "GDANSK",        locator: "JO94HI", spc: "SP",  area: "2" },
+    Qth { city: "WROCLAW",       locator: "JO81MC", spc: "SP",  area: "6" },
+    Qth { city: "POZNAN",        locator: "JO82LJ", spc: "SP",  area: "3" },
+    Qth { city: "LODZ",          locator: "JO91RS", spc: "SP",  area: "7" },
+];
+static QTH_OK: &[Qth] = &[
+    Qth { city: "PRAGUE",        locator: "JO70FB", spc: "OK",  area: "" },
+    Qth { city: "BRNO",          locator: "JN89HE", spc: "OK",  area: "" },
+    Qth { city: "OSTRAVA",       locator: "JN99DT", spc: "OK",  area: "" },
+];
+static QTH_OM: &[Qth] = &[
+    Qth { city: "BRATISLAVA",    locator: "JN88ND", spc: "OM",  area: "" },
+    Qth { city: "KOSICE",        locator: "KN08PR", spc: "OM",  area: "" },
+];
+static QTH_HA: &[Qth] = &[
+    Qth { city: "BUDAPEST",      locator: "JN97MM", spc: "HA",  area: "" },
+    Qth { city: "DEBRECEN",      locator: "KN07TM", spc: "HA",  area: "" },
+    Qth { city: "SZEGED",        locator: "KN06BG", spc: "HA",  area: "" },
+];
+static QTH_S5: &[Qth] = &[
+    Qth { city: "LJUBLJANA",     locator: "JN76GB", spc: "S5",  area: "" },
+    Qth { city: "MARIBOR",       locator: "JN76TN", spc: "S5",  area: "" },
+];
+static QTH_9A: &[Qth] = &[
+    Qth { city: "ZAGREB",        locator: "JN75XT", spc: "9A",  area: "" },
+    Qth { city: "SPLIT",         locator: "JN83FM", spc: "9A",  area: "" },
+    Qth { city: "RIJEKA",        locator: "JN75FH", spc: "9A",  area: "" },
+];
+static QTH_YU: &[Qth] = &[
+    Qth { city: "BELGRADE",      locator: "KN04FS", spc: "YU",  area: "" },
+    Qth { city: "NOVI SAD",      locator: "JN95VG", spc: "YU",  area: "" },
+];
+static QTH_LZ: &[Qth] = &[
+    Qth { city: "SOFIA",         locator: "KN12PQ", spc: "LZ",  area: "" },
+    Qth { city: "PLOVDIV",       locator: "KN22JD", spc: "LZ",  area: "" },
+    Qth { city: "VARNA",         locator: "KN33WF", spc: "LZ",  area: "" },
+];
+static QTH_YO: &[Qth] = &[
+    Qth { city: "BUCHAREST",     locator: "KN34BK", spc: "YO",  area: "" },
+    Qth { city: "CLUJ",          locator: "KN16TS", spc: "YO",  area: "" },
+    Qth { city: "IASI",          locator: "KN37TD", spc: "YO",  area: "" },
+    Qth { city: "TIMISOARA",     locator: "KN05OS", spc: "YO",  area: "" },
+];
+static QTH_SV: &[Qth] = &[
+    Qth { city: "ATHENS",        locator: "KM17UX", spc: "SV",  area: "" },
+    Qth { city: "THESSALONIKI",  locator: "KN10LP", spc: "SV",  area: "" },
+];
+static QTH_SM: &[Qth] = &[
+    Qth { city: "STOCKHOLM",     locator: "JO99AH", spc: "SM",  area: "0" },
+    Qth { city: "GOTHENBURG",    locator: "JO57XR", spc: "SM",  area: "6" },
+    Qth { city: "MALMO",         locator: "JO65MO", spc: "SM",  area: "7" },
+    Qth { city: "UPPSALA",       locator: "JO89TU", spc: "SM",  area: "5" },
+    Qth { city: "UMEA",          locator: "KP03DT", spc: "SM",  area: "2" },
+];
+static QTH_LA: &[Qth] = &[
+    Qth { city: "OSLO",          locator: "JO59JV", spc: "LA",  area: "" },
+    Qth { city: "BERGEN",        locator: "JP20PJ", spc: "LA",  area: "" },
+    Qth { city: "TRONDHEIM",     locator: "JP53EK", spc: "LA",  area: "" },
+    Qth { city: "TROMSO",        locator: "JP99LP", spc: "LA",  area: "" },
+];
+static QTH_OZ: &[Qth] = &[
+    Qth { city: "COPENHAGEN",    locator: "JO65GQ", spc: "OZ",  area: "" },
+    Qth { city: "AARHUS",        locator: "JO56CD", spc: "OZ",  area: "" },
+    Qth { city: "ODENSE",        locator: "JO55EJ", spc: "OZ",  area: "" },
+    Qth { city: "AALBORG",       locator: "JO47XB", spc: "OZ",  area: "" },
+];
+static QTH_OH: &[Qth] = &[
+    Qth { city: "TURKU",         locator: "KP10DK", spc: "OH",  area: "1" },
+    Qth { city: "HELSINKI",      locator: "KP20LE", spc: "OH",  area: "2" },
+    Qth { city: "TAMPERE",       locator: "KP11VM", spc: "OH",  area: "3" },
+    Qth { city: "KUOPIO",        locator: "KP32UV", spc: "OH",  area: "7" },
+    Qth { city: "OULU",          locator: "KP25RA", spc: "OH",  area: "8" },
+];
+static QTH_TF: &[Qth] = &[
+    Qth { city: "REYKJAVIK",     locator: "HP94AD", spc: "TF",  area: "" },
+    Qth { city: "AKUREYRI",      locator: "IP05WQ", spc: "TF",  area: "" },
+];
+static QTH_ES: &[Qth] = &[
+    Qth { city: "TALLINN",       locator: "KO29JK", spc: "ES",  area: "" },
+    Qth { city: "TARTU",         locator: "KO38IJ", spc: "ES",  area: "" },
+];
+static QTH_YL: &[Qth] = &[
+    Qth { city: "RIGA",          locator: "KO26BW", spc: "YL",  area: "" },
+    Qth { city: "LIEPAJA",       locator: "KO06MM", spc: "YL",  area: "" },
+];
+static QTH_LY: &[Qth] = &[
+    Qth { city: "VILNIUS",       locator: "KO24PQ", spc: "LY",  area: "" },
+    Qth { city: "KAUNAS",        locator: "KO14WV", spc: "LY",  area: "" },
+];
+static QTH_UA: &[Qth] = &[
+    Qth { city: "MOSCOW",        locator: "KO85TS", spc: "UA",  area: "3" },
+    Qth { city: "ST PETERSBURG", locator: "KO59EW", spc: "UA",  area: "1" },
+    Qth { city: "KAZAN",         locator: "LO45NT", spc: "UA",  area: "4" },
+    Qth { city: "VORONEZH",      locator: "KO91OQ", spc: "UA",  area: "3" },
+    Qth { city: "ROSTOV",        locator: "KN97UF", spc: "UA",  area: "6" },
+    Qth { city: "SAMARA",        locator: "LO53BE", spc: "UA",  area: "4" },
+    Qth { city: "KRASNODAR",     locator: "KN95LA", spc: "UA",  area: "6" },
+];
+static QTH_UA9: &[Qth] = &[
+    Qth { city: "NOVOSIBIRSK",   locator: "NO15LA", spc: "UA9", area: "9" },
+    Qth { city: "YEKATERINBURG", locator: "MO06HU", spc: "UA9", area: "9" },
+    Qth { city: "OMSK",          locator: "MO64QX", spc: "UA9", area: "9" },
+    Qth { city: "VLADIVOSTOK",   locator: "PN53WC", spc: "UA9", area: "0" },
+    Qth { city: "IRKUTSK",       locator: "OO22DG", spc: "UA9", area: "0" },
+];
+static QTH_UR: &[Qth] = &[
+    Qth { city: "KYIV",          locator: "KO50GK", spc: "UR",  area: "" },
+    Qth { city: "LVIV",          locator: "KN29AU", spc: "UR",  area: "" },
+    Qth { city: "KHARKIV",       locator: "KN89CX", spc: "UR",  area: "" },
+    Qth { city: "ODESA",         locator: "KN56IL", spc: "UR",  area: "" },
+    Qth { city: "DNIPRO",        locator: "KN78ML", spc: "UR",  area: "" },
+];
+static QTH_EU: &[Qth] = &[
+    Qth { city: "MINSK",         locator: "KO33SV", spc: "EU",  area: "" },
+    Qth { city: "GOMEL",         locator: "KO52LK", spc: "EU",  area: "" },
+];
+static QTH_LU: &[Qth] = &[
+    Qth { city: "BUENOS AIRES",  locator: "GF05TJ", spc: "LU",  area: "" },
+    Qth { city: "CORDOBA",       locator: "FF78VN", spc: "LU",  area: "" },
+    Qth { city: "ROSARIO",       locator: "FF97QB", spc: "LU",  area: "" },
+    Qth { city: "MENDOZA",       locator: "FF57OC", spc: "LU",  area: "" },
+];
+static QTH_PY: &[Qth] = &[
+    Qth { city: "RIO",           locator: "GG87JC", spc: "PY",  area: "1" },
+    Qth { city: "SAO PAULO",     locator: "GG66QK", spc: "PY",  area: "2" },
+    Qth { city: "PORTO ALEGRE",  locator: "GF49JX", spc: "PY",  area: "3" },
+    Qth { city: "BELO HORIZONTE",locator: "GH80AB", spc: "PY",  area: "4" },
+    Qth { city: "CURITIBA",      locator: "GG54IN", spc: "PY",  area: "5" },
+    Qth { city: "RECIFE",        locator: "HI21NW", spc: "PY",  area: "7" },
+];
+static QTH_ZS: &[Qth] = &[
+    Qth { city: "CAPE TOWN",     locator: "JF96FB", spc: "ZS",  area: "1" },
+    Qth { city: "DURBAN",        locator: "KG50MD", spc: "ZS",  area: "5" },
+    Qth { city: "JOHANNESBURG",  locator: "KG43AT", spc: "ZS",  area: "6" },
+    Qth { city: "PRETORIA",      locator: "KG44CG", spc: "ZS",  area: "6" },
+];
+static QTH_XE: &[Qth] = &[
+    Qth { city: "MEXICO CITY",   locator: "EK09KK", spc: "XE",  area: "1" },
+    Qth { city: "GUADALAJARA",   locator: "DL80HQ", spc: "XE",  area: "1" },
+    Qth { city: "MONTERREY",     locator: "DL95UQ", spc: "XE",  area: "2" },
+];
+static QTH_BY: &[Qth] = &[
+    Qth { city: "BEIJING",       locator: "OM89EV", spc: "BY",  area: "1" },
+    Qth { city: "SHANGHAI",      locator: "PM01RF", spc: "BY",  area: "4" },
+    Qth { city: "GUANGZHOU",     locator: "OL63PD", spc: "BY",  area: "7" },
+];
+static QTH_HL: &[Qth] = &[
+    Qth { city: "SEOUL",         locator: "PM37LN", spc: "HL",  area: "1" },
+    Qth { city: "BUSAN",         locator: "PM45ME", spc: "HL",  area: "5" },
+];
@@ -0,0 +1,142 @@
+// src/qso/callpool.rs  —  External callsign pools: MASTER.SCP and N1MM call history
+//
+// MASTER.SCP (super check partial): one callsign per line, `#` comments.
+// N1MM call history: CSV whose `!!Order!!,Call,Name,…` line names the columns;
+// `#` lines are comments.  Recognised columns (any case): Call, Name, State,
+// Loc1, Exch1, CWOps, SKCC, NAQCC, FISTS, Grid / Locator — others are ignored.
+// Whatever a file does not provide (name, QTH, locator) is filled in by the
+// generator for the call's country.
+use std::path::Path;
+use std::sync::OnceLock;
+use anyhow::{bail, Context, Result};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use super::callsigns::Station;
+use super::{callgen, cty, locator};
+
+/// Random draws tried before a filtered pick gives up
+const MAX_DRAWS: usize = 64;
+
+/// One callsign from the pool with whatever the file knows about it
+#[derive(Debug, Clone, Default)]
+pub struct PoolEntry {
+    pub call:    String,
+    pub name:    Option<String>,
+    /// State / province / SPC
+    pub state:   Option<String>,
+    /// CWT exchange: CWops member number
+    pub exch:    Option<String>,
+    pub skcc:    Option<String>,
+    pub naqcc:   Option<String>,
+    pub fists:   Option<String>,
+    pub locator: Option<String>,
+}
+
+pub struct CallPool {
+    entries: Vec<PoolEntry>,
+}
+
+impl CallPool {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Reading call pool {:?}", path))?;
+        let pool = Self::parse(&text);
+        if pool.entries.is_empty() {
+            bail!("No usable callsigns in call pool {:?}", path);
+        }
+        Ok(pool)
+    }
+
+    /// Parse MASTER.SCP or N1MM call history; the `!!Order!!` line switches
+    /// to CSV.  Lines whose callsign does not resolve to a DXCC entity are skipped.
+    pub fn parse(text: &str) -> Self {
+        let mut columns: Option<Vec<String>> = None;
+        let mut entries = vec![];
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') { continue; }
+            if let Some(rest) = line.strip_prefix("!!Order!!") {
+                columns = Some(rest.trim_start_matches(',').split(',')
+                    .map(|c| c.trim().to_ascii_lowercase())
+                    .collect());
+                continue;
+            }
+            let entry = match &columns {
+                Some(cols) => from_csv(cols, line),
+                None => PoolEntry {
+                    call: line.split_whitespace().next().unwrap_or("").to_uppercase(),
+                    ..Default::default()
+                },
+            };
+            if valid_call(&entry.call) {
+                entries.push(entry);
+            }
+        }
+        Self { entries }
+    }
+
+    /// A random station from the pool.
+    pub fn draw<R: Rng>(&self, rng: &mut R) -> Station {
+        let e = self.entries.choose(rng).unwrap();
+        let mut st = callgen::station_for(rng, &e.call, e.state.as_deref());
+        if let Some(v) = &e.name    { st.name    = v.clone(); }
+        if let Some(v) = &e.state   { st.spc     = v.clone(); }
+        if let Some(v) = &e.locator { st.locator = v.clone(); }
+        if let Some(v) = e.exch.as_ref().or(e.state.as_ref()) { st.cwt_ex = v.clone(); }
+        st.skcc  = e.skcc.clone();
+        st.naqcc = e.naqcc.clone();
+        st.fists = e.fists.clone();
+        st
+    }
+
+    /// A random station for which `keep` holds, or None if a bounded number
+    /// of draws finds none (e.g. no German calls in a W-only SCP file).
+    pub fn draw_where<R: Rng>(&self, rng: &mut R, keep: impl Fn(&Station) -> bool)
+        -> Option<Station>
+    {
+        (0..MAX_DRAWS).map(|_| self.draw(rng)).find(|s| keep(s))
+    }
+}
+
+fn from_csv(cols: &[String], line: &str) -> PoolEntry {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let get = |name: &str| cols.iter().position(|c| c == name)
+        .and_then(|i| fields.get(i))
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_uppercase());
+    PoolEntry {
+        call:    get("call").unwrap_or_default(),
+        name:    get("name"),
+        state:   get("state").or_else(|| get("loc1")),
+        exch:    get("cwops").or_else(|| get("exch1")),
+        skcc:    get("skcc"),
+        naqcc:   get("naqcc"),
+        fists:   get("fists"),
+        locator: get("grid").or_else(|| get("locator"))
+                     .and_then(|l| locator::normalize(&l)),
+    }
+}
+
+/// Letters, digits and '/', at least one of each kind, and a known prefix.
+fn valid_call(call: &str) -> bool {
+    (3..=12).contains(&call.len())
+        && call.chars().all(|c| c.is_ascii_alphanumeric() || c == '/')
+        && call.chars().any(|c| c.is_ascii_digit())
+        && call.chars().any(|c| c.is_ascii_alphabetic())
+        && cty::lookup(call).is_some()
+}
+
+static POOL: OnceLock<CallPool> = OnceLock::new();
+
+/// Load the pool given with `--call-pool`; returns the number of calls.
+/// Call once at startup, before the first QSO.
+pub fn init(path: &Path) -> Result<usize> {
+    let pool = CallPool::load(path)?;
+    let n = pool.entries.len();
+    let _ = POOL.set(pool);
+    Ok(n)
+}
+
+/// The loaded pool, or None when stations are generated.
+pub fn get() -> Option<&'static CallPool> {
+    POOL.get()
+}
@@ -1,55 +1,27 @@
-// src/qso/callsigns.rs  —  Large embedded callsign + name/QTH pool
+// src/qso/callsigns.rs  —  Sim stations, name/QTH pools and exchange values
 use rand::seq::SliceRandom;
+use crate::config::AppConfig;
+use super::{band, callgen, callpool, locator, propagation};
 
-pub struct SimStation {
-    pub call:    &'static str,
-    pub name:    &'static str,
-    pub qth:     &'static str,
-    pub country: &'static str,
-    pub dok:     &'static str,   // DARC DOK, or "NM" for non-members
-    pub cwt_ex:  &'static str,   // CWT exchange: 4-digit member nr OR state/country for non-members
-    pub spc:     &'static str,   // SST SPC: US/VE/VK state or province; DXCC prefix for others
+/// A station the simulator can play — generated for its country's callsign
+/// format, or drawn from a call-history pool (`--call-pool`).
+#[derive(Debug, Clone)]
+pub struct Station {
+    pub call:    String,
+    pub name:    String,
+    pub qth:     String,
+    /// CWT exchange: 4-digit member nr OR state/country for non-members
+    pub cwt_ex:  String,
+    /// SST SPC: US/VE/VK state or province; DXCC prefix for others
+    pub spc:     String,
+    /// 6-char Maidenhead locator of the QTH
+    pub locator: String,
+    /// Club member numbers known from a call-history file
+    pub skcc:    Option<String>,
+    pub naqcc:   Option<String>,
+    pub fists:   Option<String>,
 }
 
-pub static STATIONS: &[SimStation] = &[
-    SimStation { call:"DL1ABC", name:"HANS",    qth:"BERLIN",    country:"DL",  dok:"D01", cwt_ex:"1812", spc:"DL"  },
-    SimStation { call:"DL2XYZ", name:"PETER",   qth:"HAMBURG",   country:"DL",  dok:"H09", cwt_ex:"DL",   spc:"DL"  },
-    SimStation { call:"DL5QRS", name:"FRITZ",   qth:"MUNICH",    country:"DL",  dok:"M02", cwt_ex:"3047", spc:"DL"  },
-    SimStation { call:"OE3KAB", name:"WALTER",  qth:"VIENNA",    country:"OE",  dok:"NM",  cwt_ex:"OE",   spc:"OE"  },
-    SimStation { call:"PA3ABC", name:"JAN",     qth:"AMSTERDAM", country:"PA",  dok:"NM",  cwt_ex:"1563", spc:"PA"  },
-    SimStation { call:"G4XYZ",  name:"JOHN",    qth:"LONDON",    country:"G",   dok:"NM",  cwt_ex:"G",    spc:"G"   },
-    SimStation { call:"ON4ABC", name:"LUC",     qth:"BRUSSELS",  country:"ON",  dok:"NM",  cwt_ex:"ON",   spc:"ON"  },
-    SimStation { call:"F5NTX",  name:"PIERRE",  qth:"PARIS",     country:"F",   dok:"NM",  cwt_ex:"2291", spc:"F"   },
-    SimStation { call:"I2ABC",  name:"MARCO",   qth:"MILAN",     country:"I",   dok:"NM",  cwt_ex:"I",    spc:"I"   },
-    SimStation { call:"SM5XY",  name:"LARS",    qth:"STOCKHOLM", country:"SM",  dok:"NM",  cwt_ex:"SM",   spc:"SM"  },
-    SimStation { call:"SP5ZAP", name:"TOMASZ",  qth:"WARSAW",    country:"SP",  dok:"NM",  cwt_ex:"SP",   spc:"SP"  },
-    SimStation { call:"UT5UDX", name:"SERGIY",  qth:"KYIV",      country:"UT",  dok:"NM",  cwt_ex:"UT",   spc:"UT"  },
-    SimStation { call:"UA9XYZ", name:"IVAN",    qth:"MOSCOW",    country:"UA",  dok:"NM",  cwt_ex:"UA",   spc:"UA"  },
-    SimStation { call:"W1AW",   name:"HIRAM",   qth:"NEWINGTON", country:"W",   dok:"NM",  cwt_ex:"CT",   spc:"CT"  },
-    SimStation { call:"K5ZD",   name:"RANDY",   qth:"HARVARD",   country:"W",   dok:"NM",  cwt_ex:"MA",   spc:"MA"  },
-    SimStation { call:"VE3XYZ", name:"MIKE",    qth:"TORONTO",   country:"VE",  dok:"NM",  cwt_ex:"ON",   spc:"ON"  },
-    SimStation { call:"JA1ABC", name:"KENJI",   qth:"TOKYO",     country:"JA",  dok:"NM",  cwt_ex:"JA",   spc:"JA"  },
-    SimStation { call:"VK2XYZ", name:"BRUCE",   qth:"SYDNEY",    country:"VK",  dok:"NM",  cwt_ex:"VK",   spc:"VK"  },
-    SimStation { call:"ZL2ABC", name:"NEIL",    qth:"AUCKLAND",  country:"ZL",  dok:"NM",  cwt_ex:"ZL",   spc:"ZL"  },
-    SimStation { call:"HB9ABC", name:"BEAT",    qth:"ZURICH",    country:"HB9", dok:"NM",  cwt_ex:"HB",   spc:"HB"  },
-    SimStation { call:"OK2XYZ", name:"JIRI",    qth:"BRNO",      country:"OK",  dok:"NM",  cwt_ex:"OK",   spc:"OK"  },
-    SimStation { call:"YL3ABC", name:"JANIS",   qth:"RIGA",      country:"YL",  dok:"NM",  cwt_ex:"YL",   spc:"YL"  },
-    SimStation { call:"LY5T",   name:"TOMAS",   qth:"VILNIUS",   country:"LY",  dok:"NM",  cwt_ex:"LY",   spc:"LY"  },
-    SimStation { call:"ES5TV",  name:"TONNO",   qth:"TALLINN",   country:"ES",  dok:"NM",  cwt_ex:"ES",   spc:"ES"  },
-    SimStation { call:"OH2BH",  name:"MARTTI",  qth:"HELSINKI",  country:"OH",  dok:"NM",  cwt_ex:"OH",   spc:"OH"  },
-    SimStation { call:"LA5YJ",  name:"BJORN",   qth:"OSLO",      country:"LA",  dok:"NM",  cwt_ex:"LA",   spc:"LA"  },
-    SimStation { call:"OZ5E",   name:"FLEMMING",qth:"COPENHAGEN",country:"OZ",  dok:"NM",  cwt_ex:"OZ",   spc:"OZ"  },
-    SimStation { call:"EI5DI",  name:"SEAN",    qth:"DUBLIN",    country:"EI",  dok:"NM",  cwt_ex:"EI",   spc:"EI"  },
-    SimStation { call:"GM4ZUK", name:"ANGUS",   qth:"EDINBURGH", country:"GM",  dok:"NM",  cwt_ex:"GM",   spc:"GM"  },
-    SimStation { call:"TF3CW",  name:"SIGGI",   qth:"REYKJAVIK", country:"TF",  dok:"NM",  cwt_ex:"TF",   spc:"TF"  },
-];
-
-/// RST values realistic for CW
-pub static RST_VALUES: &[&str] = &[
-    "559", "569", "579", "589", "599",
-    "459", "469", "479", "489",
-];
-
 /// Rig descriptions
 pub static RIGS: &[&str] = &[
     "IC 7300", "IC 7610", "FT 991A", "FT 857", "TS 590", "TS 890",
@@ -66,21 +38,52 @@ pub static POWER: &[&str] = &[
     "5W", "10W", "50W", "100W", "200W", "400W",
 ];
 
-pub fn random_station<R: rand::Rng>(rng: &mut R) -> &'static SimStation {
-    STATIONS.choose(rng).unwrap()
+pub fn random_station<R: rand::Rng>(rng: &mut R) -> Station {
+    match callpool::get() {
+        Some(pool) => pool.draw(rng),
+        None       => callgen::generate(rng),
+    }
 }
 
-/// Pick only from German (DL) stations — used for DARC CW contest so the
+/// Pick only German (DL) stations — used for DARC CW contest so the
 /// SIM always has a valid DOK instead of "NM".
-pub fn random_dl_station<R: rand::Rng>(rng: &mut R) -> &'static SimStation {
-    let dl: Vec<&'static SimStation> = STATIONS.iter()
-        .filter(|s| s.country == "DL")
-        .collect();
-    dl.choose(rng).copied().unwrap_or_else(|| STATIONS.choose(rng).unwrap())
+pub fn random_dl_station<R: rand::Rng>(rng: &mut R) -> Station {
+    callpool::get()
+        .and_then(|pool| pool.draw_where(rng, |s| super::cty::dxcc_prefix(&s.call) == "DL"))
+        .unwrap_or_else(|| callgen::generate_in(rng, "DL").expect("DL has a callsign format"))
 }
 
-pub fn random_rst<R: rand::Rng>(rng: &mut R) -> &'static str {
-    RST_VALUES.choose(rng).unwrap()
+/// Pick a station within `max_km` of `my_locator` — used for the VHF/UHF
+/// contest, where a Tokyo station answering a Berlin CQ on 2 m would be absurd.
+pub fn random_station_near<R: rand::Rng>(rng: &mut R, my_locator: &str, max_km: f64)
+    -> Station
+{
+    callpool::get()
+        .and_then(|pool| pool.draw_where(rng, |s| {
+            locator::distance_km(my_locator, &s.locator).is_some_and(|km| km <= max_km)
+        }))
+        .unwrap_or_else(|| callgen::generate_near(rng, my_locator, max_km))
+}
+
+/// Pick a station for `cfg.band` at the session's time of day: a local
+/// within the band's short-skip range or DX, in the band's day / night
+/// proportion (locals on 80 m at night, DX on 20 m by day).
+pub fn random_station_on<R: rand::Rng>(rng: &mut R, cfg: &AppConfig) -> Station {
+    let Some(home) = propagation::home(cfg) else { return random_station(rng) };
+    let local_km = band::local_km(cfg.band);
+    if !rng.gen_bool(band::dx_share(cfg.band, propagation::home_daylight(cfg))) {
+        let my_locator = locator::from_latlon(home.0, home.1);
+        return random_station_near(rng, &my_locator, local_km);
+    }
+    // DX: a few draws to get one from outside the local range
+    let mut st = random_station(rng);
+    for _ in 0..8 {
+        let far = locator::to_latlon(&st.locator)
+            .is_none_or(|pos| locator::great_circle_km(home, pos) > local_km);
+        if far { break; }
+        st = random_station(rng);
+    }
+    st
 }
 
 pub fn random_rig<R: rand::Rng>(rng: &mut R) -> &'static str {
@@ -204,6 +207,41 @@ pub fn random_dok<R: rand::Rng>(rng: &mut R) -> &'static str {
     DOK_CODES.choose(rng).unwrap()
 }
 
+/// Generate an SKCC member number with its award suffix.
+/// Most members carry a plain number; Centurions get "C", Tribunes "T" and
+/// Senators "S" — e.g. 4711, 12345C, 8420T, 1977S.
+pub fn random_skcc_nr<R: rand::Rng>(rng: &mut R) -> String {
+    let nr = rng.gen_range(1u32..=32000);
+    let suffix = match rng.gen_range(0u32..100) {
+        0..=59  => "",
+        60..=79 => "C",
+        80..=91 => "T",
+        _       => "S",
+    };
+    format!("{nr}{suffix}")
+}
+
+/// Generate an NAQCC sprint exchange number.
+/// Members send their member number; non-members (about 1 in 6) send their
+/// QRP power instead, as the sprint rules require.
+pub fn random_naqcc_nr<R: rand::Rng>(rng: &mut R) -> String {
+    if rng.gen_bool(0.15) {
+        ["1W", "2W", "3W", "5W"].choose(rng).unwrap().to_string()
+    } else {
+        rng.gen_range(1u32..=11500).to_string()
+    }
+}
+
+/// Generate a FISTS sprint exchange number.
+/// Members send their FISTS number; non-members send their power.
+pub fn random_fists_nr<R: rand::Rng>(rng: &mut R) -> String {
+    if rng.gen_bool(0.2) {
+        random_pwr(rng).to_string()
+    } else {
+        rng.gen_range(1u32..=20500).to_string()
+    }
+}
+
 /// Official WWA (World Wide Award) special station callsigns — sourced from
 /// https://hamaward.cloud/wwa/teams  (2026 edition, 118 entries)
 pub static WWA_CALLSIGNS: &[&str] = &[
@@ -232,48 +270,25 @@ pub fn random_wwa_callsign<R: rand::Rng>(rng: &mut R) -> &'static str {
     WWA_CALLSIGNS.choose(rng).unwrap()
 }
 
-/// Generate a POTA (Parks on the Air) park reference based on the station's country.
+/// Generate a POTA (Parks on the Air) park reference for a DXCC entity.
+/// `country` is the entity's primary prefix from `cty::dxcc_prefix`; POTA uses
+/// it as the program prefix for most entities.
 /// Format: {prefix}-{NNNN}  e.g. K-1234, DL-0042, VE-0567
 pub fn random_pota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
     let prefix = match country {
-        "W"   => "K",
-        "VE"  => "VE",
-        "DL"  => "DL",
-        "G"   => "G",
-        "GM"  => "GM",
-        "EI"  => "EI",
-        "F"   => "F",
-        "I"   => "I",
-        "SM"  => "SM",
-        "OH"  => "OH",
-        "OE"  => "OE",
-        "PA"  => "PA",
-        "ON"  => "ON",
-        "SP"  => "SP",
-        "OK"  => "OK",
-        "HB9" => "HB",
-        "JA"  => "JA",
-        "VK"  => "VK",
-        "ZL"  => "ZL",
-        "LA"  => "LA",
-        "OZ"  => "OZ",
-        "LY"  => "LY",
-        "YL"  => "YL",
-        "ES"  => "ES",
-        "TF"  => "TF",
-        "UT"  => "UT",
-        "UA"  => "RA",
-        _     => "K",
+        "UA" | "UA2" | "UA9" => "RA",
+        ""                   => "K",
+        other                => other,
     };
     let nr = rng.gen_range(1u32..=9999);
     format!("{prefix}-{nr:04}")
 }
 
-/// Generate a SOTA (Summits on the Air) summit reference based on the station's country.
+/// Generate a SOTA (Summits on the Air) summit reference for a DXCC primary prefix.
 /// Format: {association}/{region}-{NNN}  e.g. DL/AL-042, W6/NC-001
 pub fn random_sota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
     let (assoc, region) = match country {
-        "W"   => ("W1",  "WR"),
+        "K"   => ("W1",  "WR"),
         "VE"  => ("VE3", "ON"),
         "DL"  => ("DL",  "AL"),
         "G"   => ("G",   "NW"),
@@ -286,7 +301,7 @@ pub fn random_sota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "OE"  => ("OE",  "ST"),
         "PA"  => ("PA",  "PA"),
         "ON"  => ("ON",  "ON"),
-        "HB9" => ("HB",  "AG"),
+        "HB"  => ("HB",  "AG"),
         "JA"  => ("JA",  "KG"),
         "VK"  => ("VK3", "VC"),
         "ZL"  => ("ZL3", "CB"),
@@ -298,18 +313,18 @@ pub fn random_sota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "TF"  => ("TF",  "SW"),
         "SP"  => ("SP",  "BZ"),
         "OK"  => ("OK",  "JM"),
-        "UT"  => ("UT",  "CR"),
+        "UR"  => ("UT",  "CR"),
         _     => ("W1",  "WR"),
     };
     let nr = rng.gen_range(1u32..=999);
     format!("{assoc}/{region}-{nr:03}")
 }
 
-/// Generate a COTA (Castles on the Air) castle reference based on the station's country.
+/// Generate a COTA (Castles on the Air) castle reference for a DXCC primary prefix.
 /// Format: {country_code}/CA-{NNN}  e.g. GB/CA-042, DL/CA-007
 pub fn random_cota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
     let code = match country {
-        "W"   => "US",
+        "K"   => "US",
         "VE"  => "CA",
         "DL"  => "DL",
         "G"   | "GM" | "EI" => "GB",
@@ -322,7 +337,7 @@ pub fn random_cota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "ON"  => "BE",
         "SP"  => "PL",
         "OK"  => "CZ",
-        "HB9" => "CH",
+        "HB"  => "CH",
         "JA"  => "JP",
         "VK"  => "AU",
         "ZL"  => "NZ",
@@ -332,89 +347,18 @@ pub fn random_cota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "YL"  => "LV",
         "ES"  => "EE",
         "TF"  => "IS",
-        "UT"  => "UA",
+        "UR"  => "UA",
         _     => "GB",
     };
     let nr = rng.gen_range(1u32..=999);
     format!("{code}/CA-{nr:03}")
 }
 
-/// Derive a country code from a callsign for use with the random_*_ref generators.
-/// Handles the most common amateur radio callsign prefixes worldwide.
-pub fn country_from_callsign(call: &str) -> &'static str {
-    let c = call.to_uppercase();
-    // German prefixes: DA..DP series (all 2-letter prefixes starting with D)
-    if c.starts_with("DA") || c.starts_with("DB") || c.starts_with("DC")
-        || c.starts_with("DD") || c.starts_with("DE") || c.starts_with("DF")
-        || c.starts_with("DG") || c.starts_with("DH") || c.starts_with("DJ")
-        || c.starts_with("DK") || c.starts_with("DL") || c.starts_with("DM")
-        || c.starts_with("DO") || c.starts_with("DP") { "DL" }
-    // Canada (VE/VA/VO/VY — must come before VK)
-    else if c.starts_with("VE") || c.starts_with("VA")
-         || c.starts_with("VO") || c.starts_with("VY") { "VE" }
-    // Australia
-    else if c.starts_with("VK") { "VK" }
-    // Poland
-    else if c.starts_with("SP") { "SP" }
-    // Sweden
-    else if c.starts_with("SM") || c.starts_with("SA")
-         || c.starts_with("SE") || c.starts_with("SK") { "SM" }
-    // Finland
-    else if c.starts_with("OH") { "OH" }
-    // Austria
-    else if c.starts_with("OE") { "OE" }
-    // Belgium
-    else if c.starts_with("ON") { "ON" }
-    // Denmark
-    else if c.starts_with("OZ") { "OZ" }
-    // Czech Republic
-    else if c.starts_with("OK") { "OK" }
-    // Netherlands
-    else if c.starts_with("PA") || c.starts_with("PD")
-         || c.starts_with("PE") || c.starts_with("PH") { "PA" }
-    // Spain
-    else if c.starts_with("EA") { "EA" }
-    // Switzerland
-    else if c.starts_with("HB") { "HB9" }
-    // Norway
-    else if c.starts_with("LA") || c.starts_with("LB") { "LA" }
-    // Lithuania
-    else if c.starts_with("LY") { "LY" }
-    // Latvia
-    else if c.starts_with("YL") { "YL" }
-    // Estonia
-    else if c.starts_with("ES") { "ES" }
-    // Iceland
-    else if c.starts_with("TF") { "TF" }
-    // Ukraine
-    else if c.starts_with("UT") || c.starts_with("UR") { "UT" }
-    // New Zealand
-    else if c.starts_with("ZL") { "ZL" }
-    // Japan
-    else if c.starts_with("JA") || c.starts_with("JH")
-         || c.starts_with("JR") || c.starts_with("JO") { "JA" }
-    // Ireland
-    else if c.starts_with("EI") { "EI" }
-    // Scotland (GM before single G)
-    else if c.starts_with("GM") { "GM" }
-    // United Kingdom
-    else if c.starts_with('G') || c.starts_with('M') { "G" }
-    // France
-    else if c.starts_with('F') { "F" }
-    // Italy
-    else if c.starts_with('I') { "I" }
-    // USA: A*, K*, N*, W* (must come after two-letter prefixes handled above)
-    else if c.starts_with('A') || c.starts_with('K')
-         || c.starts_with('N') || c.starts_with('W') { "W" }
-    // Fallback — use DL so refs are always well-formed
-    else { "DL" }
-}
-
-/// Generate a TOTA (Towers on the Air) tower reference based on the station's country.
+/// Generate a TOTA (Towers on the Air) tower reference for a DXCC primary prefix.
 /// Format: {country_code}-{NNNN}  e.g. US-0042, DL-0123  (wwtota.com style)
 pub fn random_tota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
     let code = match country {
-        "W"   => "US",
+        "K"   => "US",
         "VE"  => "CA",
         "DL"  => "DL",
         "G"   | "GM" | "EI" => "GB",
@@ -427,7 +371,7 @@ pub fn random_tota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "ON"  => "BE",
         "SP"  => "PL",
         "OK"  => "CZ",
-        "HB9" => "CH",
+        "HB"  => "CH",
         "JA"  => "JP",
         "VK"  => "AU",
         "ZL"  => "NZ",
@@ -437,7 +381,7 @@ pub fn random_tota_ref<R: rand::Rng>(rng: &mut R, country: &str) -> String {
         "YL"  => "LV",
         "ES"  => "EE",
         "TF"  => "IS",
-        "UT"  => "UA",
+        "UR"  => "UA",
         _     => "US",
     };
     let nr = rng.gen_range(1u32..=9999);
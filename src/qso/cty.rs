@@ -0,0 +1,237 @@
+// src/qso/cty.rs  —  DXCC prefix resolver built from a cty.dat-style table
+//
+// The table ships embedded (src/qso/cty.dat).  A `cty.dat` in the config
+// directory — e.g. the full, current AD1C file from www.country-files.com —
+// replaces it at runtime, so new prefixes need no rebuild.
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use anyhow::{bail, Context, Result};
+
+static EMBEDDED: &str = include_str!("cty.dat");
+
+/// Portable suffixes that say nothing about the station's location
+static SUFFIXES: &[&str] = &["P", "M", "A", "B", "QRP", "QRPP", "LH"];
+
+/// One DXCC entity as listed in the table
+#[derive(Debug, Clone)]
+pub struct Entity {
+    pub name:      String,
+    /// Primary prefix ("DL", "K", "UA9", …) — also the CQ WW country multiplier
+    pub prefix:    String,
+    pub continent: String,
+    pub cq_zone:   u8,
+    pub itu_zone:  u8,
+    /// Centre of the entity in degrees, north and east positive
+    pub lat:       f64,
+    pub lon:       f64,
+}
+
+/// A resolved callsign.  Zones and continent already include any per-prefix
+/// override, e.g. W6ABC → CQ zone 3 although the USA default is 5.
+#[derive(Debug, Clone)]
+pub struct CallInfo<'a> {
+    pub entity:    &'a Entity,
+    pub continent: &'a str,
+    pub cq_zone:   u8,
+    pub itu_zone:  u8,
+    pub wpx:       String,
+}
+
+/// A prefix or exact callsign with its optional overrides
+#[derive(Debug, Clone)]
+struct Alias {
+    entity:    usize,
+    cq_zone:   Option<u8>,
+    itu_zone:  Option<u8>,
+    continent: Option<String>,
+}
+
+pub struct CtyDb {
+    entities: Vec<Entity>,
+    prefixes: HashMap<String, Alias>,
+    exact:    HashMap<String, Alias>,
+}
+
+impl CtyDb {
+    /// Parse a cty.dat file:
+    ///
+    ///   Name: CQ: ITU: Cont: Lat: Lon (west +): UTC: Prefix:
+    ///       PFX,PFX(cq)[itu]{cont},=FULLCALL,...;
+    ///
+    /// The UTC offset is not used; `<lat/lon>` and `~utc~` overrides are
+    /// accepted and ignored.  Entities
+    /// whose primary prefix starts with `*` (WAE-only) are skipped.
+    pub fn parse(text: &str) -> Result<Self> {
+        let body: String = text.lines()
+            .filter(|l| !l.trim_start().starts_with('#'))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut db = Self { entities: vec![], prefixes: HashMap::new(), exact: HashMap::new() };
+        for rec in body.split(';').map(str::trim).filter(|r| !r.is_empty()) {
+            let f: Vec<&str> = rec.splitn(9, ':').map(str::trim).collect();
+            if f.len() < 9 {
+                bail!("malformed record near {:?}", rec.lines().next().unwrap_or(""));
+            }
+            if f[7].starts_with('*') { continue; }
+            let zone = |s: &str| s.parse::<u8>()
+                .with_context(|| format!("{}: bad zone {s:?}", f[0]));
+            let deg = |s: &str| s.parse::<f64>()
+                .with_context(|| format!("{}: bad coordinate {s:?}", f[0]));
+
+            let idx = db.entities.len();
+            db.entities.push(Entity {
+                name:      f[0].to_string(),
+                prefix:    f[7].to_string(),
+                continent: f[3].to_string(),
+                cq_zone:   zone(f[1])?,
+                itu_zone:  zone(f[2])?,
+                lat:       deg(f[4])?,
+                // cty.dat counts longitude west-positive
+                lon:       -deg(f[5])?,
+            });
+            for tok in f[8].split(',') {
+                let tok: String = tok.split_whitespace().collect();
+                if tok.is_empty() { continue; }
+                let (exact, key, alias) = parse_alias(&tok, idx)
+                    .with_context(|| format!("{}: bad prefix {tok:?}", f[0]))?;
+                if exact { db.exact.insert(key, alias); } else { db.prefixes.insert(key, alias); }
+            }
+        }
+        if db.entities.is_empty() { bail!("no entities found"); }
+        Ok(db)
+    }
+
+    /// Resolve a callsign, or `None` for maritime/aeronautical mobile and
+    /// calls no prefix matches.  The longest matching prefix wins; exact
+    /// entries (`=CALL`) take precedence over any prefix.
+    pub fn lookup(&self, call: &str) -> Option<CallInfo<'_>> {
+        let up = call.trim().to_uppercase();
+        let alias = match self.exact.get(&up) {
+            Some(a) => a,
+            None => {
+                let loc = location_part(&up)?;
+                self.exact.get(&loc).or_else(|| {
+                    (1..=loc.len()).rev()
+                        .filter(|&n| loc.is_char_boundary(n))
+                        .find_map(|n| self.prefixes.get(&loc[..n]))
+                })?
+            }
+        };
+        let entity = &self.entities[alias.entity];
+        Some(CallInfo {
+            entity,
+            continent: alias.continent.as_deref().unwrap_or(&entity.continent),
+            cq_zone:   alias.cq_zone.unwrap_or(entity.cq_zone),
+            itu_zone:  alias.itu_zone.unwrap_or(entity.itu_zone),
+            wpx:       wpx_prefix(&up),
+        })
+    }
+}
+
+/// "W6(3)[6]" → (false, "W6", overrides); "=KC4AAA" → (true, "KC4AAA", …)
+fn parse_alias(tok: &str, entity: usize) -> Result<(bool, String, Alias)> {
+    let exact = tok.starts_with('=');
+    let tok   = tok.trim_start_matches('=');
+    let end   = tok.find(|c| "([<{~".contains(c)).unwrap_or(tok.len());
+    let mods  = &tok[end..];
+    let between = |open: char, close: char| -> Option<&str> {
+        let s = mods.find(open)? + 1;
+        let e = mods[s..].find(close)? + s;
+        Some(&mods[s..e])
+    };
+    Ok((exact, tok[..end].to_string(), Alias {
+        entity,
+        cq_zone:   between('(', ')').map(str::parse).transpose()?,
+        itu_zone:  between('[', ']').map(str::parse).transpose()?,
+        continent: between('{', '}').map(str::to_string),
+    }))
+}
+
+/// Split a compound call into (home call, designator):
+///   DL1ABC/P → (DL1ABC, None), DL/G4XYZ → (G4XYZ, Some(DL)),
+///   VP2E/W1ABC → (W1ABC, Some(VP2E)), W1AW/6 → (W1AW, Some(6)).
+/// The shorter of two parts is the designator (the first one on a tie).
+/// `None` for /MM and /AM, which count for no entity.
+fn split_call(up: &str) -> Option<(&str, Option<&str>)> {
+    let parts: Vec<&str> = up.split('/').filter(|p| !p.is_empty()).collect();
+    if parts.iter().any(|p| matches!(*p, "MM" | "AM")) { return None; }
+    let parts: Vec<&str> = parts.into_iter().filter(|p| !SUFFIXES.contains(p)).collect();
+    match parts.as_slice() {
+        [base]                      => Some((*base, None)),
+        [a, b] if b.len() < a.len() => Some((*a, Some(*b))),
+        [a, b, ..]                  => Some((*b, Some(*a))),
+        []                          => None,
+    }
+}
+
+/// The part of a callsign that decides where the station is:
+/// the designator, or the home call with its call-area digit replaced
+/// by a "/<digit>" indicator (W1AW/6 → W6AW).
+fn location_part(up: &str) -> Option<String> {
+    Some(match split_call(up)? {
+        (base, Some(d)) if d.len() == 1 && d.as_bytes()[0].is_ascii_digit() => {
+            match base.find(|c: char| c.is_ascii_digit()) {
+                Some(i) => format!("{}{d}{}", &base[..i], &base[i + 1..]),
+                None    => base.to_string(),
+            }
+        }
+        (_, Some(d)) => d.to_string(),
+        (base, None) => base.to_string(),
+    })
+}
+
+/// CQ WPX prefix: everything up to and including the last digit before the
+/// suffix ("DL1ABC" → "DL1", "PA26WWA" → "PA26").  Calls without a digit get
+/// a "0" after the first two letters; a "/<digit>" indicator replaces the
+/// call-area digit (W1AW/6 → W6) and a portable designator is the prefix
+/// (DL/G4XYZ → DL0, VP2E/W1ABC → VP2E).
+pub fn wpx_prefix(call: &str) -> String {
+    let up = call.trim().to_uppercase();
+    let prefix_of = |c: &str| -> String {
+        match c.rfind(|ch: char| ch.is_ascii_digit()) {
+            Some(i) => c[..=i].to_string(),
+            None    => format!("{}0", c.get(..2).unwrap_or(c)),
+        }
+    };
+    match split_call(&up) {
+        Some((base, Some(d))) if d.len() == 1 && d.as_bytes()[0].is_ascii_digit() => {
+            let bp = prefix_of(base);
+            format!("{}{d}", &bp[..bp.len() - 1])
+        }
+        Some((_, Some(d))) if d.contains(|c: char| c.is_ascii_digit()) => d.to_string(),
+        Some((_, Some(d)))  => format!("{d}0"),
+        Some((base, None))  => prefix_of(base),
+        // /MM and /AM still have a home prefix
+        None => prefix_of(up.split('/').next().unwrap_or("")),
+    }
+}
+
+/// The active table: `<config dir>/cty.dat` if present and valid, otherwise
+/// the embedded one.  Loaded once on first use.
+pub fn db() -> &'static CtyDb {
+    static DB: OnceLock<CtyDb> = OnceLock::new();
+    DB.get_or_init(|| {
+        let user = crate::config::config_dir().join("cty.dat");
+        if let Ok(text) = std::fs::read_to_string(&user) {
+            match CtyDb::parse(&text) {
+                Ok(db) => {
+                    log::info!("cty: {} entities from {}", db.entities.len(), user.display());
+                    return db;
+                }
+                Err(e) => log::warn!("cty: ignoring {}: {e:#}", user.display()),
+            }
+        }
+        CtyDb::parse(EMBEDDED).expect("embedded cty.dat must parse")
+    })
+}
+
+/// Resolve a callsign against the active table.
+pub fn lookup(call: &str) -> Option<CallInfo<'static>> {
+    db().lookup(call)
+}
+
+/// Primary DXCC prefix of a callsign ("DL", "K", "VE", …), or "" if unknown.
+pub fn dxcc_prefix(call: &str) -> &'static str {
+    lookup(call).map_or("", |i| i.entity.prefix.as_str())
+}
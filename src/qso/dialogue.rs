@@ -0,0 +1,246 @@
+// src/qso/dialogue.rs  —  Rag-chew dialogue: topics, a phrase grammar and answers
+//
+// Each SIM operator gets a `Profile` — age, years licensed, weather, QSL
+// habits, other activities — drawn once per QSO.  The chat turns of a
+// rag-chew are planned from it topic by topic, every line put together from
+// interchangeable phrase parts, and the user's direct questions ("UR AGE?",
+// "HW WX?") are answered from the same profile, so the SIM never
+// contradicts itself.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use super::exchanges::SimExchange;
+
+/// What the SIM can talk about
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Topic {
+    Weather,
+    Equipment,
+    Antenna,
+    Age,
+    Qsl,
+    Activities,
+    Key,
+    /// Only asked for — the name and QTH are in the report already
+    Name,
+    Qth,
+}
+
+/// Topics a rag-chew is planned from
+const PLANNED: &[Topic] = &[
+    Topic::Weather, Topic::Equipment, Topic::Antenna, Topic::Age,
+    Topic::Qsl, Topic::Activities, Topic::Key,
+];
+
+/// Facts about one SIM operator, fixed for the QSO
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub age:        u8,
+    /// Years since the operator was licensed
+    pub licensed:   u8,
+    pub retired:    bool,
+    pub job:        &'static str,
+    pub sky:        Sky,
+    /// °C
+    pub temp:       i8,
+    pub windy:      bool,
+    pub qsl:        &'static str,
+    pub activities: Vec<&'static str>,
+    pub key:        &'static str,
+    /// Antenna height in metres
+    pub ant_height: u8,
+    /// Years with the current rig
+    pub rig_years:  u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sky { Sunny, Cloudy, Rain, Snow, Fog }
+
+static JOBS: &[&str] = &[
+    "ENGINEER", "TEACHER", "NURSE", "FARMER", "IT ADMIN", "ELECTRICIAN",
+    "STUDENT", "DOCTOR", "PILOT", "TRUCK DRIVER", "CARPENTER", "CHEMIST",
+];
+
+static QSL: &[&str] = &[
+    "VIA BURO", "VIA LOTW", "DIRECT", "VIA BURO ES LOTW", "VIA EQSL",
+    "VIA CLUBLOG OQRS", "100 PCT VIA BURO",
+];
+
+/// Verb phrases, after "I ALSO" / "BESIDES CW I" …
+static ACTIVITIES: &[&str] = &[
+    "GO UP SOTA SUMMITS IN SUMMER", "DO SOME POTA ACTIVATIONS", "BUILD MY OWN QRP RIGS",
+    "DO A FEW CONTESTS EACH YEAR", "CHASE DX ON 20M", "RUN A 2M RIG IN THE CAR",
+    "TEACH CW AT THE LOCAL CLUB", "COLLECT OLD KEYS", "PLAY WITH FT8 SOMETIMES",
+    "WORK SATELLITES", "RESTORE OLD TUBE RIGS", "GO FISHING", "KEEP BEES",
+    "RIDE MY BIKE A LOT", "SAIL ON WEEKENDS", "QRV ON 160M IN WINTER",
+];
+
+static KEYS: &[&str] = &[
+    "A BENCHER PADDLE", "A STRAIGHT KEY", "A VIBROPLEX BUG", "A BEGALI PADDLE",
+    "A HOMEMADE PADDLE", "AN OLD NAVY KEY", "A SIDESWIPER", "A KENT PADDLE",
+];
+
+impl Profile {
+    pub fn generate<R: Rng>(rng: &mut R) -> Self {
+        let age      = rng.gen_range(16u8..=86);
+        let licensed = rng.gen_range(1..=(age - 14).max(1));
+        let sky = *[Sky::Sunny, Sky::Cloudy, Sky::Rain, Sky::Snow, Sky::Fog]
+            .choose_weighted(rng, |s| match s {
+                Sky::Sunny => 4, Sky::Cloudy => 4, Sky::Rain => 3, Sky::Snow => 1, Sky::Fog => 1,
+            })
+            .unwrap();
+        let temp = match sky {
+            Sky::Snow => rng.gen_range(-12i8..=1),
+            Sky::Sunny => rng.gen_range(5..=34),
+            _ => rng.gen_range(-3..=22),
+        };
+        Self {
+            age,
+            licensed,
+            retired:    age >= 63 && rng.gen_bool(0.9),
+            job:        JOBS.choose(rng).unwrap(),
+            sky,
+            temp,
+            windy:      rng.gen_bool(0.3),
+            qsl:        QSL.choose(rng).unwrap(),
+            activities: ACTIVITIES.choose_multiple(rng, 2).copied().collect(),
+            key:        KEYS.choose(rng).unwrap(),
+            ant_height: rng.gen_range(6..=25),
+            rig_years:  rng.gen_range(1..=15),
+        }
+    }
+}
+
+/// Chat turns for a rag-chew: one line per topic, in random order, each
+/// ending with a question back or HW?.  `extra` lines (e.g. the locator and
+/// QRB) are mixed in.
+pub fn plan<R: Rng>(rng: &mut R, ex: &SimExchange, extra: Vec<String>) -> Vec<String> {
+    let mut topics = PLANNED.to_vec();
+    topics.shuffle(rng);
+    let mut lines: Vec<String> = topics.iter()
+        .map(|&t| format!("{} {} <AR>", say(rng, ex, t), pick(rng, closer(t))))
+        .collect();
+    for line in extra {
+        let at = rng.gen_range(0..=lines.len().min(3));
+        lines.insert(at, line);
+    }
+    lines
+}
+
+/// An answer to a direct question, without a question back
+pub fn answer<R: Rng>(rng: &mut R, ex: &SimExchange, t: Topic) -> String {
+    say(rng, ex, t)
+}
+
+/// Topics the user asks about in `over`: "UR AGE?", "HW WX", "QSL?", "HW UR RIG?"
+pub fn questions(over: &str) -> Vec<Topic> {
+    let words: Vec<String> = over.split_whitespace().map(str::to_uppercase).collect();
+    let mut found = vec![];
+    for (i, w) in words.iter().enumerate() {
+        let asked = w.ends_with('?')
+            || (i >= 1 && words[i - 1] == "HW")
+            || (i >= 2 && words[i - 1] == "UR" && words[i - 2] == "HW");
+        if !asked { continue; }
+        let topic = match w.trim_end_matches('?') {
+            "WX" | "TEMP" | "WEATHER"            => Topic::Weather,
+            "RIG" | "TRX" | "PWR" | "POWER"      => Topic::Equipment,
+            "ANT" | "ANTENNA"                    => Topic::Antenna,
+            "AGE" | "OLD" | "LIC" | "YRS" | "LICENSED" => Topic::Age,
+            "QSL" | "LOTW" | "BURO"              => Topic::Qsl,
+            "QRV" | "BANDS" | "HOBBY" | "HOBBIES" | "ACTIVITIES" => Topic::Activities,
+            "KEY" | "PADDLE"                     => Topic::Key,
+            "NAME" | "OP"                        => Topic::Name,
+            "QTH"                                => Topic::Qth,
+            _                                    => continue,
+        };
+        if !found.contains(&topic) { found.push(topic); }
+    }
+    found
+}
+
+fn pick<'a, R: Rng>(rng: &mut R, options: &[&'a str]) -> &'a str {
+    options.choose(rng).copied().unwrap_or("")
+}
+
+/// Questions back that close a topic line
+fn closer(t: Topic) -> &'static [&'static str] {
+    match t {
+        Topic::Weather    => &["HW UR WX?", "HW IS UR WX?", "HW WX?"],
+        Topic::Equipment  => &["HW UR RIG?", "HW IS UR RIG?", "HW?"],
+        Topic::Antenna    => &["HW UR ANT?", "HW IS UR ANT?", "HW?"],
+        Topic::Age        => &["HW LONG U BEEN A HAM?", "HW ABT U?", "HW?"],
+        Topic::Qsl        => &["OK?", "HW?", "U QSL?"],
+        Topic::Activities => &["WAT ABT U?", "HW ABT U?", "HW?"],
+        Topic::Key        => &["WAT KEY U USE?", "HW?"],
+        Topic::Name | Topic::Qth => &["HW?"],
+    }
+}
+
+/// One statement about `t`, put together from the phrase grammar
+fn say<R: Rng>(rng: &mut R, ex: &SimExchange, t: Topic) -> String {
+    let p = &ex.profile;
+    let parts: Vec<String> = match t {
+        Topic::Weather => {
+            let sky = match p.sky {
+                Sky::Sunny  => pick(rng, &["SUNNY", "SUNNY ES CLEAR", "FINE ES SUNNY", "BRIGHT SUNSHINE"]),
+                Sky::Cloudy => pick(rng, &["CLOUDY", "OVERCAST", "GREY ES CLOUDY", "CLDY"]),
+                Sky::Rain   => pick(rng, &["RAIN", "RAINY", "WET ES RAINY", "SHOWERS ALL DAY"]),
+                Sky::Snow   => pick(rng, &["SNOW", "SNOWING", "WHITE WID SNOW"]),
+                Sky::Fog    => pick(rng, &["FOGGY", "FOG", "MISTY ES GREY"]),
+            };
+            let temp = if p.temp < 0 {
+                format!("MINUS {}C", -p.temp)
+            } else {
+                format!("{}C", p.temp)
+            };
+            vec![
+                pick(rng, &["WX HR", "WX HR IS", "WX TODAY", "HR THE WX IS"]).into(),
+                sky.into(),
+                format!("{} {temp}", pick(rng, &["ES", "TEMP", "ES ABT", "ES TEMP"])),
+                if p.windy { pick(rng, &["ES WINDY", "WID STRONG WIND", "ES A LOT OF WIND"]).into() }
+                else { String::new() },
+            ]
+        }
+        Topic::Equipment => vec![
+            format!("{} {}", pick(rng, &["RIG HR IS", "RIG HR", "MY RIG IS", "USING", "RUNNING"]), ex.rig),
+            format!("{} {}", pick(rng, &["AT", "WID", "RUNNING", "ES"]), ex.pwr),
+            pick(rng, &["", "VY HAPPY WID IT", "FB RIG"]).into(),
+            if rng.gen_bool(0.5) { format!("HAD IT FER {} YRS", p.rig_years) } else { String::new() },
+        ],
+        Topic::Antenna => vec![
+            format!("{} {}", pick(rng, &["ANT HR IS", "ANT HR", "MY ANT IS", "USING"]), ex.ant),
+            format!("{} {}M", pick(rng, &["UP", "AT", "ABT"]), p.ant_height),
+            pick(rng, &["", "WORKS FB", "GOOD FER DX", "NOT BAD FER THE SMALL GARDEN"]).into(),
+        ],
+        Topic::Age => {
+            let job = if p.retired {
+                pick(rng, &["RETIRED NW", "RETIRED SO LOTS OF TIME FER RADIO", "NW RETIRED"]).to_string()
+            } else {
+                format!("{} {}", pick(rng, &["WORK AS", "I AM A", "JOB IS"]), p.job)
+            };
+            vec![
+                format!("{} {}", pick(rng, &["AGE HR", "AGE HR IS", "I AM", "AGE"]), p.age),
+                pick(rng, &["", "YRS"]).into(),
+                format!("{} {} YRS", pick(rng, &["ES LIC", "ES LICENSED", "BEEN A HAM FER", "ES QRV FER"]),
+                        p.licensed),
+                job,
+            ]
+        }
+        Topic::Qsl => vec![
+            pick(rng, &["QSL", "WILL QSL", "MY QSL", "I QSL"]).into(),
+            p.qsl.into(),
+            pick(rng, &["", "NO PROBLEM", "100 PCT"]).into(),
+        ],
+        Topic::Activities => vec![
+            pick(rng, &["I ALSO", "BESIDES CW I", "HR I", "IN MY FREE TIME I"]).into(),
+            p.activities.join(" ES "),
+        ],
+        Topic::Key => vec![
+            pick(rng, &["KEY HR IS", "USING", "SENDING WID", "KEYING WID"]).into(),
+            p.key.into(),
+            pick(rng, &["", "LOVE IT", "VY SMOOTH"]).into(),
+        ],
+        Topic::Name => vec![format!("{} {}", pick(rng, &["NAME HR", "NAME IS", "OP"]), ex.sim_name)],
+        Topic::Qth  => vec![format!("{} {}", pick(rng, &["QTH HR", "QTH IS", "QTH"]), ex.sim_qth)],
+    };
+    parts.into_iter().filter(|s| !s.is_empty()).collect::<Vec<_>>().join(" ")
+}
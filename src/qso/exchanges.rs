@@ -1,11 +1,20 @@
 // src/qso/exchanges.rs  —  Build human-like QSO exchange sentences
 use rand::Rng;
 use super::callsigns::{
-    random_station, random_dl_station, random_wwa_callsign,
-    random_dok, random_rst, random_rig, random_ant, random_pwr,
+    random_station_on, random_dl_station, random_wwa_callsign,
+    random_dok, random_rig, random_ant, random_pwr,
     random_pota_ref, random_sota_ref, random_tota_ref, random_cota_ref,
+    random_skcc_nr, random_naqcc_nr, random_fists_nr, random_station_near,
 };
-use crate::config::{QsoStyle, WhoStarts};
+use super::cty;
+use super::cutnum::Cut;
+use super::dialogue::{self, Profile};
+use super::locator;
+use super::band;
+use super::propagation::{self, Path, Signal};
+use super::radiogram::Radiogram;
+use crate::schedule;
+use crate::config::{AppConfig, QsoStyle, WhoStarts};
 
 pub struct SimExchange {
     pub sim_call:       String,
@@ -24,57 +33,119 @@ pub struct SimExchange {
     pub spc:            String,
     /// POTA park ref (K-XXXX), SOTA summit ref (W1/WR-001), TOTA tower ref (US-XXXX)
     pub activator_ref:  String,
+    /// SKCC number with C/T/S suffix (e.g. "12345T")
+    pub skcc_nr:        String,
+    /// NAQCC number, or QRP power for non-members (e.g. "5W")
+    pub naqcc_nr:       String,
+    /// FISTS number, or power for non-members (e.g. "100W")
+    pub fists_nr:       String,
+    /// Formal message the SIM passes in the radiogram (traffic) style
+    pub radiogram:      Option<Radiogram>,
+    /// 6-character Maidenhead locator of the sim station
+    pub locator:        String,
+    /// CQ zone of the sim station (CQ WW exchange)
+    pub cq_zone:        u8,
+    /// How much this operator cuts the numbers in their exchange
+    pub cut:            Cut,
+    /// The operator behind the call — what they say in a rag-chew
+    pub profile:        Profile,
+    /// The SIM's signal as the user hears it
+    pub signal:         Signal,
+    /// Frequency the QSO is on, kHz
+    pub qrg_khz:        f64,
 }
 
 impl SimExchange {
-    pub fn generate<R: Rng>(rng: &mut R, style: QsoStyle) -> Self {
-        // For DARC CW contest always pick a German station so DOK is never "NM"
-        let st = if style == QsoStyle::DarcCwContest {
-            random_dl_station(rng)
-        } else {
-            random_station(rng)
-        };
+    pub fn generate<R: Rng>(rng: &mut R, cfg: &AppConfig) -> Self {
+        let style = cfg.qso_style;
+        // For DARC CW contest always pick a German station so DOK is never "NM".
+        // VHF/UHF contest: only stations within tropo range of the user's locator.
+        // Stations holding characters due for review are preferred
+        let st = schedule::favour(rng, |rng| match style {
+            QsoStyle::DarcCwContest => random_dl_station(rng),
+            QsoStyle::VhfContest    => random_station_near(rng, &cfg.my_locator, 1200.0),
+            _                       => random_station_on(rng, cfg),
+        }, |st| format!("{} {} {}", st.call, st.name, st.qth));
         // For WWA contest use an official WWA special station callsign
         let sim_call = if style == QsoStyle::WwaContest {
             random_wwa_callsign(rng).to_string()
         } else {
-            st.call.to_string()
+            st.call.clone()
+        };
+        let radiogram = if style == QsoStyle::Radiogram {
+            Some(Radiogram::generate(rng, cfg.utc, &sim_call, &st.qth, &st.name))
+        } else {
+            None
         };
+        let country = cty::dxcc_prefix(&st.call);
         let activator_ref = match style {
-            QsoStyle::Pota => random_pota_ref(rng, st.country),
-            QsoStyle::Sota => random_sota_ref(rng, st.country),
-            QsoStyle::Tota => random_tota_ref(rng, st.country),
-            QsoStyle::Cota => random_cota_ref(rng, st.country),
+            QsoStyle::Pota => random_pota_ref(rng, country),
+            QsoStyle::Sota => random_sota_ref(rng, country),
+            QsoStyle::Tota => random_tota_ref(rng, country),
+            QsoStyle::Cota => random_cota_ref(rng, country),
             _              => String::new(),
         };
+        // VHF contesters operate from hilltops around the city, not from its
+        // centre — move the station up to ~40 km so each QSO has its own locator.
+        let locator = match (style, locator::to_latlon(&st.locator)) {
+            (QsoStyle::VhfContest, Some((lat, lon))) => locator::from_latlon(
+                lat + rng.gen_range(-0.35..0.35),
+                lon + rng.gen_range(-0.5..0.5),
+            ),
+            _ => st.locator.clone(),
+        };
+
+        let zone = cty::lookup(&sim_call).map_or(14, |i| i.cq_zone);
+
+        // Both directions of the path: the SIM heard by the user, and the
+        // user (assumed 100 W) heard by the SIM, which sets the SIM's report
+        let pwr = random_pwr(rng);
+        let (signal, heard_by_sim) = match Path::new(cfg, &sim_call, &locator) {
+            Some(path) => (
+                path.signal(rng, propagation::watts(pwr)),
+                path.signal(rng, 100.0),
+            ),
+            None => (Signal::STRONG, Signal::STRONG),
+        };
+        let rst_to_me = if propagation::honest_reports(style) {
+            heard_by_sim.rst()
+        } else {
+            "599".to_string()
+        };
 
         Self {
             sim_call,
-            sim_name:   st.name.to_string(),
-            sim_qth:    st.qth.to_string(),
+            sim_name:   st.name.clone(),
+            sim_qth:    st.qth.clone(),
             // German (DL) stations are always DARC members — draw a random DOK
             // from the full 1192-code pool so each QSO feels realistic.
-            // All other countries keep their fixed dok field ("NM" for non-members).
-            dok:        if st.country == "DL" {
+            // Everyone else sends "NM" (non-member).
+            dok:        if country == "DL" {
                             random_dok(rng).to_string()
                         } else {
-                            st.dok.to_string()
+                            "NM".to_string()
                         },
-            rst_to_me:  random_rst(rng).to_string(),
+            rst_to_me,
             rig:        random_rig(rng).to_string(),
             ant:        random_ant(rng).to_string(),
-            pwr:        random_pwr(rng).to_string(),
+            pwr:        pwr.to_string(),
             // Sim is already mid-contest — pick a plausible serial (1-250)
             sim_serial: rng.gen_range(1u32..=250),
-            // CWT exchange: members get a random realistic number (1000-9999).
-            // Non-members keep their country/state code (e.g. "G", "CA", "DL").
-            cwt_ex:     if st.cwt_ex.parse::<u32>().is_ok() {
-                            rng.gen_range(1000u32..=9999).to_string()
-                        } else {
-                            st.cwt_ex.to_string()
-                        },
-            spc:        st.spc.to_string(),
+            // CWT exchange: member number, or country/state code (e.g. "G", "CA", "DL")
+            cwt_ex:     st.cwt_ex.clone(),
+            spc:        st.spc.clone(),
             activator_ref,
+            // Member numbers from a call-history file, else a plausible random one
+            skcc_nr:    st.skcc.clone().unwrap_or_else(|| random_skcc_nr(rng)),
+            naqcc_nr:   st.naqcc.clone().unwrap_or_else(|| random_naqcc_nr(rng)),
+            fists_nr:   st.fists.clone().unwrap_or_else(|| random_fists_nr(rng)),
+            radiogram,
+            locator,
+            cq_zone:    zone,
+            cut:        Cut::draw(rng, style),
+            profile:    Profile::generate(rng),
+            signal,
+            qrg_khz:    band::qrg_khz(rng, cfg.band, style),
         }
     }
 }
@@ -94,17 +165,24 @@ impl QsoScript {
     /// `my_serial` is the user's running QSO count (001, 002, …).
     /// It appears in the MWC contest_ex hint and is used as the number
     /// the user should send back to the sim station.
-    /// `cwt_name` / `cwt_nr` are the user's own CWT exchange fields (name + member nr or state/country).
-    /// `who_starts` / `my_ref` control activator-role handling for POTA/SOTA/TOTA/COTA:
+    /// The user's own exchange fields (callsign, CWT name/nr, DOK, club
+    /// member numbers) and the style / who-starts settings come from `cfg`.
+    /// `my_ref` is the user's activator reference for POTA/SOTA/TOTA/COTA:
     /// when `who_starts == Me` the user IS the activator and sends the reference.
-    pub fn build<R: Rng>(rng: &mut R,
-                 mycall: &str, ex: &SimExchange, style: QsoStyle, my_rst: &str, my_serial: u32,
-                 cwt_name: &str, cwt_nr: &str, my_dok: &str,
-                 who_starts: WhoStarts, my_ref: &str) -> Self {
+    pub fn build<R: Rng>(rng: &mut R, cfg: &AppConfig,
+                 ex: &SimExchange, my_rst: &str, my_serial: u32, my_ref: &str) -> Self {
+        let mycall     = cfg.mycall.as_str();
+        let style      = cfg.qso_style;
+        let who_starts = cfg.who_starts;
+        let cwt_name   = cfg.cwt_name.as_str();
+        let cwt_nr     = cfg.cwt_nr.as_str();
+        let my_spc     = cfg.my_spc.as_str();
+        let my_dok     = cfg.my_dok.as_str();
         let sc  = &ex.sim_call;
         let sn  = &ex.sim_name;
         let sq  = &ex.sim_qth;
-        let sr  = &ex.rst_to_me;
+        // The SIM's report, serial and zone as this operator sends them
+        let sr  = &ex.cut.rst(&ex.rst_to_me);
         let dok = &ex.dok;
         let rig = &ex.rig;
         let ant = &ex.ant;
@@ -120,10 +198,10 @@ impl QsoScript {
         //   SIM → <my> TU 73 <SK>          ← combined ack + sign-off
         //   USR → <sim> TU 73 <SK>         ← user echoes, sim waits for this
         if style == QsoStyle::MwcContest {
-            let sim_ser = ex.sim_serial;
+            let sim_ser = ex.cut.number(ex.sim_serial, 3);
             let cq         = format!("CQ CQ TEST {sc} K");
             let answer     = format!("{mycall} DE {sc} {sc} K");
-            let report     = format!("{mycall} UR RST {sr} {sr} {sim_ser:03} K");
+            let report     = format!("{mycall} UR RST {sr} {sr} {sim_ser} K");
             // Combined ack + sign-off — sent after the user's report.
             // The sim goes directly to WaitFor73 after this, no separate sign-off.
             let ack_report = format!("{mycall} TU 73 <SK>");
@@ -206,10 +284,10 @@ impl QsoScript {
         //   USR → <rst> <my_serial> K           ← just RST + serial, no callsign
         //   SIM → TU QSL 73                     ← combined ack + sign-off, QSO done
         if style == QsoStyle::WpxContest {
-            let sim_ser = ex.sim_serial;
+            let sim_ser = ex.cut.number(ex.sim_serial, 3);
             let cq         = format!("CQ WPX TEST {sc} {sc} K");
             let answer     = format!("{mycall} DE {sc} {sc} K");
-            let report     = format!("{mycall} {sr} {sim_ser:03} K");
+            let report     = format!("{mycall} {sr} {sim_ser} K");
             // Combined ack + sign-off — QSO is done immediately after this.
             let ack_report = format!("TU QSL 73");
 
@@ -222,6 +300,27 @@ impl QsoScript {
             };
         }
 
+        // ── CQ WW DX Contest: RST + CQ zone ───────────────────────────────────
+        // Exchange pattern (sim calls CQ, user answers):
+        //   SIM → CQ TEST <sim> <sim> TEST
+        //   USR → <my>
+        //   SIM → <my> <rst> <sim_zone> K
+        //   USR → 599 <my_zone> K
+        //   SIM → TU <sim> TEST                 ← final ack, QSO done
+        if style == QsoStyle::CqWwContest {
+            let zone       = ex.cut.number(u32::from(ex.cq_zone), 2);
+            let my_zone    = cty::lookup(mycall).map_or(14, |i| i.cq_zone);
+            return Self {
+                cq:         format!("CQ TEST {sc} {sc} TEST"),
+                answer:     format!("{mycall} DE {sc} {sc} K"),
+                report:     format!("{mycall} {sr} {zone} K"),
+                ack_report: format!("TU {sc} TEST"),
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for CQ WW
+                contest_ex: format!("599 {my_zone:02} K"),
+            };
+        }
+
         // ── WWA Contest: RST + serial number (sent twice) + BK ────────────────
         // Exchange pattern (sim calls CQ, user answers):
         //   SIM → CQ WWA <sim> <sim> K
@@ -231,10 +330,10 @@ impl QsoScript {
         //   SIM → R TU 73 <SK>          ← combined ack + sign-off
         //   USR → 73                    ← user echoes, sim waits for this
         if style == QsoStyle::WwaContest {
-            let sim_ser = ex.sim_serial;
+            let sim_ser = ex.cut.number(ex.sim_serial, 3);
             let cq         = format!("CQ WWA {sc} {sc} K");
             let answer     = format!("{mycall} DE {sc} {sc} K");
-            let report     = format!("{mycall} DE {sc} {sr} {sim_ser:03} {sim_ser:03} BK");
+            let report     = format!("{mycall} DE {sc} {sr} {sim_ser} {sim_ser} BK");
             // Combined ack + sign-off — sent after the user's report.
             // The sim goes directly to WaitFor73 after this, no separate sign-off.
             let ack_report = format!("R TU 73 <SK>");
@@ -282,11 +381,11 @@ impl QsoScript {
                  HW? <AR>"
             );
 
-            let chat = vec![
-                format!("WX HR FINE TEMP WARM HW UR WX? <AR>"),
+            // The QTT remark first, then the usual rag-chew topics
+            let mut chat = vec![
                 format!("UR SIG VY FB HR NICE QSO ES GD QTT PROCEDURE HW? <AR>"),
-                format!("BEEN OPS MANY YRS NW ENJOY QTT STDS VY MUCH HW? <AR>"),
             ];
+            chat.extend(dialogue::plan(rng, ex, vec![]));
 
             // Sign-off uses "77" (Long Live CW) instead of "73"
             let sign_off = format!("OK {sn} TU FB QSO 77 ES GL DE {sc} <SK>");
@@ -321,7 +420,123 @@ impl QsoScript {
                 chat:       vec![],
                 sign_off:   String::new(),   // not reached for SST
                 // Hint: greeting + SIM name + user name + user SPC
-                contest_ex: format!("GE {sn} {cwt_name} {cwt_nr}"),
+                contest_ex: format!("GE {sn} {cwt_name} {my_spc}"),
+            };
+        }
+
+        // ── SKCC Weekend Sprintathon: RST + SPC + Name + SKCC nr ──────────────
+        // Straight keys and bugs only; the number carries the C/T/S award suffix.
+        // Exchange pattern (sim calls CQ, user answers):
+        //   SIM → CQ WES CQ WES DE <sim> <sim> K
+        //   USR → <sim> DE <my> K
+        //   SIM → <my> DE <sim> TU UR <rst> <rst> <spc> <spc> <name> <name> <skcc> <skcc> BK
+        //   USR → BK TU UR <rst> <spc> <name> <skcc> BK
+        //   SIM → TU <name> 73 DE <sim> <SK>    ← final ack, QSO done
+        if style == QsoStyle::SkccSprint {
+            let nr         = &ex.skcc_nr;
+            let cq         = format!("CQ WES CQ WES DE {sc} {sc} K");
+            let answer     = format!("{mycall} DE {sc} {sc} K");
+            let report     = format!(
+                "{mycall} DE {sc} TU UR {sr} {sr} {spc} {spc} {sn} {sn} {nr} {nr} BK"
+            );
+            let ack_report = format!("TU {cwt_name} 73 DE {sc} <SK>");
+
+            return Self {
+                cq, answer, report, ack_report,
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for SKCC
+                // Hint: RST + SPC + name + SKCC nr, bracketed by BK
+                contest_ex: format!("BK TU UR {my_rst} {my_spc} {cwt_name} {} BK", cfg.my_skcc),
+            };
+        }
+
+        // ── NAQCC Sprint: RST + SPC + NAQCC nr (or power for non-members) ─────
+        // Exchange pattern (sim calls CQ, user answers):
+        //   SIM → CQ NA CQ NA DE <sim> K
+        //   USR → <sim> DE <my> K
+        //   SIM → <my> <rst> <spc> <naqcc> K
+        //   USR → TU <rst> <spc> <naqcc> K
+        //   SIM → R TU 73 <sim>                 ← final ack, QSO done
+        if style == QsoStyle::NaqccSprint {
+            let nr         = &ex.naqcc_nr;
+            let cq         = format!("CQ NA CQ NA DE {sc} K");
+            let answer     = format!("{mycall} DE {sc} K");
+            let report     = format!("{mycall} {sr} {spc} {nr} K");
+            let ack_report = format!("R TU 73 {sc}");
+
+            return Self {
+                cq, answer, report, ack_report,
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for NAQCC
+                contest_ex: format!("TU {my_rst} {my_spc} {} K", cfg.my_naqcc),
+            };
+        }
+
+        // ── FISTS Sprint: RST + SPC + Name + FISTS nr (or power) ──────────────
+        // Exchange pattern (sim calls CQ, user answers):
+        //   SIM → CQ FISTS CQ FISTS DE <sim> <sim> K
+        //   USR → <sim> DE <my> K
+        //   SIM → <my> <rst> <spc> <name> <fists> K
+        //   USR → TU <rst> <spc> <name> <fists> K
+        //   SIM → TU GL 73 DE <sim> <SK>        ← final ack, QSO done
+        if style == QsoStyle::FistsSprint {
+            let nr         = &ex.fists_nr;
+            let cq         = format!("CQ FISTS CQ FISTS DE {sc} {sc} K");
+            let answer     = format!("{mycall} DE {sc} {sc} K");
+            let report     = format!("{mycall} {sr} {spc} {sn} {nr} K");
+            let ack_report = format!("TU GL 73 DE {sc} <SK>");
+
+            return Self {
+                cq, answer, report, ack_report,
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for FISTS
+                contest_ex: format!("TU {my_rst} {my_spc} {cwt_name} {} K", cfg.my_fists),
+            };
+        }
+
+        // ── VHF/UHF Contest (IARU Region 1): RST + serial + locator ───────────
+        // Scored by distance, so the locator is the important part of the exchange.
+        // Exchange pattern (sim calls CQ, user answers):
+        //   SIM → CQ TEST DE <sim> <sim> K
+        //   USR → <sim> DE <my> K
+        //   SIM → <my> 599 <sim_serial> <sim_loc> K
+        //   USR → 599 <my_serial> <my_loc> K
+        //   SIM → TU <sim> TEST                 ← final ack, QSO done
+        if style == QsoStyle::VhfContest {
+            let sim_ser = ex.cut.number(ex.sim_serial, 3);
+            let loc     = &ex.locator;
+            let my_loc  = &cfg.my_locator;
+            return Self {
+                cq:         format!("CQ TEST DE {sc} {sc} K"),
+                answer:     format!("{mycall} DE {sc} {sc} K"),
+                report:     format!("{mycall} {sr} {sim_ser} {loc} K"),
+                ack_report: format!("TU {sc} TEST"),
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for VHF contest
+                contest_ex: format!("599 {my_serial:03} {my_loc} K"),
+            };
+        }
+
+        // ── Radiogram: formal NTS traffic handling ────────────────────────────
+        // Exchange pattern (sim has traffic, user copies):
+        //   SIM → QTC 1 <city> DE <sim> <sim> K
+        //   USR → <sim> DE <my> QRV K
+        //   SIM → <my> DE <sim> NR … <preamble> <address> <BT> <text> <BT> <sig> <AR> K
+        //   USR → WA <word> / WB <word> / AA <word> / AB <word> / BN <w1> <w2>  ← fills
+        //   SIM → <requested words> K
+        //   USR → QSL [<count>] K         ← a count that disagrees with CK gets the text resent
+        //   SIM → TU 73 DE <sim> <SK>     ← QSO done
+        if style == QsoStyle::Radiogram {
+            let rg   = ex.radiogram.as_ref().expect("radiogram style always carries a message");
+            let dest = &rg.dest_city;
+            return Self {
+                cq:         format!("QTC 1 {dest} DE {sc} {sc} K"),
+                answer:     format!("{mycall} DE {sc} QTC 1 {dest} K"),
+                report:     format!("{mycall} DE {sc} {} K", rg.to_cw()),
+                ack_report: format!("TU 73 DE {sc} <SK>"),
+                chat:       vec![],
+                sign_off:   String::new(),   // not reached for radiogram
+                contest_ex: format!("QSL {} K", rg.check),
             };
         }
 
@@ -518,12 +733,17 @@ impl QsoScript {
              HW? <AR>"
         );
 
-        let chat = vec![
-            format!("WX HR FINE TEMP WARM HW UR WX? <AR>"),
-            format!("RIG HR {rig} ANT {ant} PWR {pwr} HW UR RIG? <AR>"),
-            format!("BEEN LIC MANY YRS NW ENJOY CW VY MUCH HW? <AR>"),
-            format!("HR WE HAVE NICE QSB TODAY HW? <AR>"),
-        ];
+        // With the user's locator known the SIM can work out the real path
+        let loc = &ex.locator;
+        let path = locator::distance_km(loc, &cfg.my_locator)
+            .zip(locator::bearing_deg(loc, &cfg.my_locator));
+        let loc_line = match path {
+            Some((km, deg)) => format!(
+                "MY LOC {loc} QRB {:.0} KM ES BEAM HR {:03.0} DEG TO U HW? <AR>", km, deg
+            ),
+            None => format!("MY LOC {loc} HW UR LOC? <AR>"),
+        };
+        let chat = dialogue::plan(rng, ex, vec![loc_line]);
 
         let sign_off = format!("OK {sn} TU FB QSO 73 ES GL DE {sc} <SK>");
         let contest_ex = format!("{mycall} DE {sc} 599 001 001 <AR>");
@@ -0,0 +1,82 @@
+// src/qso/locator.rs  —  Maidenhead locators, QRB and bearing
+//
+// Distances follow the IARU Region 1 VHF contest rules: great-circle distance
+// on a sphere of radius 6371.291 km between the centres of the two locators,
+// one point per started kilometre.
+
+/// Earth radius used by the IARU Region 1 contest robot
+const EARTH_RADIUS_KM: f64 = 6371.291;
+
+/// Normalise a locator to upper case and check the 4- or 6-character format
+/// (field AA-RR, square 00-99, optional subsquare AA-XX).
+pub fn normalize(loc: &str) -> Option<String> {
+    let up = loc.trim().to_uppercase();
+    let b  = up.as_bytes();
+    let ok = matches!(b.len(), 4 | 6)
+        && (b'A'..=b'R').contains(&b[0]) && (b'A'..=b'R').contains(&b[1])
+        && b[2].is_ascii_digit()         && b[3].is_ascii_digit()
+        && (b.len() == 4
+            || ((b'A'..=b'X').contains(&b[4]) && (b'A'..=b'X').contains(&b[5])));
+    if ok { Some(up) } else { None }
+}
+
+/// Centre of the locator square as (latitude, longitude) in degrees.
+pub fn to_latlon(loc: &str) -> Option<(f64, f64)> {
+    let up = normalize(loc)?;
+    let b  = up.as_bytes();
+    let mut lon = (b[0] - b'A') as f64 * 20.0 - 180.0 + (b[2] - b'0') as f64 * 2.0;
+    let mut lat = (b[1] - b'A') as f64 * 10.0 -  90.0 + (b[3] - b'0') as f64;
+    if b.len() == 6 {
+        lon += (b[4] - b'A') as f64 * (2.0 / 24.0) + 1.0 / 24.0;
+        lat += (b[5] - b'A') as f64 * (1.0 / 24.0) + 0.5 / 24.0;
+    } else {
+        lon += 1.0;
+        lat += 0.5;
+    }
+    Some((lat, lon))
+}
+
+/// 6-character locator for a position in degrees.
+pub fn from_latlon(lat: f64, lon: f64) -> String {
+    let lon = (lon + 180.0).clamp(0.0, 359.999_999);
+    let lat = (lat +  90.0).clamp(0.0, 179.999_999);
+    let c = |base: u8, v: f64| (base + v as u8) as char;
+    [
+        c(b'A', lon / 20.0),
+        c(b'A', lat / 10.0),
+        c(b'0', (lon % 20.0) / 2.0),
+        c(b'0', lat % 10.0),
+        c(b'A', (lon % 2.0) * 12.0),
+        c(b'A', (lat % 1.0) * 24.0),
+    ].iter().collect()
+}
+
+/// Great-circle distance in km between two locators.
+pub fn distance_km(from: &str, to: &str) -> Option<f64> {
+    Some(great_circle_km(to_latlon(from)?, to_latlon(to)?))
+}
+
+/// Great-circle distance in km between two (latitude, longitude) positions.
+pub fn great_circle_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (la1, lo1) = (from.0.to_radians(), from.1.to_radians());
+    let (la2, lo2) = (to.0.to_radians(), to.1.to_radians());
+    let cos_d = la1.sin() * la2.sin() + la1.cos() * la2.cos() * (lo2 - lo1).cos();
+    EARTH_RADIUS_KM * cos_d.clamp(-1.0, 1.0).acos()
+}
+
+/// Initial great-circle bearing from `from` towards `to`, 0-360° (0 = north).
+pub fn bearing_deg(from: &str, to: &str) -> Option<f64> {
+    let (la1, lo1) = to_latlon(from)?;
+    let (la2, lo2) = to_latlon(to)?;
+    let (la1, la2) = (la1.to_radians(), la2.to_radians());
+    let dlon = (lo2 - lo1).to_radians();
+    let y = dlon.sin() * la2.cos();
+    let x = la1.cos() * la2.sin() - la1.sin() * la2.cos() * dlon.cos();
+    Some((y.atan2(x).to_degrees() + 360.0) % 360.0)
+}
+
+/// QSO points in an IARU Region 1 VHF/UHF contest: 1 point per started km,
+/// so a QSO inside the same subsquare still counts 1 point.
+pub fn qso_points(km: f64) -> u32 {
+    (km.ceil() as u32).max(1)
+}
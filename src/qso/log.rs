@@ -0,0 +1,56 @@
+// src/qso/log.rs  —  In-memory session log of completed QSOs
+use super::typo::SendError;
+
+/// One completed QSO as it would appear in a contest log.
+#[derive(Debug, Clone)]
+pub struct LoggedQso {
+    /// Sim station callsign
+    pub call:      String,
+    /// Serial number the user sent (1-based running counter)
+    pub my_serial: u32,
+    /// Exchange the SIM sent (its report over)
+    pub rcvd:      String,
+    /// Exchange the user actually sent
+    pub sent:      String,
+    /// Exchange fields relevant for scoring — empty when the style has none
+    pub dok:       String,
+    pub spc:       String,
+    /// POTA / SOTA / TOTA / COTA reference worked
+    pub reference: String,
+    pub cq_zone:   u8,
+    /// Club sprints: the SIM is a member (number rather than power in the exchange)
+    pub member:    bool,
+    /// Distance to the SIM in km, when both locators are known
+    pub km:        Option<f64>,
+    /// Number of exchange fields the style checks (0 = none)
+    pub fields:    usize,
+    /// Exchange fields the user's over missed or got wrong ("NR", "DOK" …)
+    pub busted:    Vec<&'static str>,
+    /// Characters the user sent wrong in those fields ('5' for 1S5)
+    pub char_errors: Vec<char>,
+    /// Text and number fields the user had to send, and whether each was busted
+    pub checked:   Vec<(String, bool)>,
+    /// Band and frequency (kHz) of the QSO
+    pub band:      &'static str,
+    pub qrg_khz:   f64,
+    /// Repeat requests ('?') the user made
+    pub repeats:   u8,
+    /// Sending mistakes the SIM made, corrected or not
+    pub mistakes:  Vec<SendError>,
+}
+
+/// All QSOs of the current session, in the order they were completed.
+#[derive(Debug, Default)]
+pub struct SessionLog {
+    pub qsos: Vec<LoggedQso>,
+    /// Session seed — `--seed <seed>` replays the same stations and exchanges
+    pub seed: u64,
+}
+
+impl SessionLog {
+    pub fn new(seed: u64) -> Self { Self { qsos: vec![], seed } }
+    pub fn push(&mut self, q: LoggedQso) { self.qsos.push(q); }
+    pub fn is_empty(&self) -> bool { self.qsos.is_empty() }
+    /// Next serial number the user sends
+    pub fn next_serial(&self) -> u32 { self.qsos.len() as u32 + 1 }
+}
@@ -0,0 +1,182 @@
+// src/qso/memory.rs  —  What the user told the SIM: name, QTH, rig, antenna, power, weather
+//
+// Every over the user sends in a rag-chew is scanned for the usual keywords
+// (NAME, OP, QTH, RIG, ANT, PWR, WX).  The SIM's later overs use what it
+// learned — "TNX DENNIS", "FB ON UR IC7300", "HAMBURG IS A NICE CITY" — and
+// ask once for whatever the user left out.
+
+/// One thing the SIM can know about the user
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Topic { Name, Qth, Rig, Ant, Pwr, Wx }
+
+impl Topic {
+    /// Keyword on air → topic
+    fn from_keyword(w: &str) -> Option<Self> {
+        match w {
+            "NAME" | "OP"         => Some(Topic::Name),
+            "QTH"                 => Some(Topic::Qth),
+            "RIG" | "TRX"         => Some(Topic::Rig),
+            "ANT"                 => Some(Topic::Ant),
+            "PWR"                 => Some(Topic::Pwr),
+            "WX"                  => Some(Topic::Wx),
+            _                     => None,
+        }
+    }
+
+    fn keyword(self) -> &'static str {
+        match self {
+            Topic::Name => "NAME",
+            Topic::Qth  => "QTH",
+            Topic::Rig  => "RIG",
+            Topic::Ant  => "ANT",
+            Topic::Pwr  => "PWR",
+            Topic::Wx   => "WX",
+        }
+    }
+}
+
+/// Words that end a value ("QTH HAMBURG HAMBURG <BT> RIG …")
+const STOP: &[&str] = &[
+    "K", "KN", "BK", "BT", "HW", "HW?", "ES", "TU", "TNX", "73", "77", "+", "=",
+    "RST", "RSN", "UR", "SO", "DE", "AND", "PSE",
+];
+
+/// Filler between a keyword and its value ("NAME IS DENNIS", "QTH HR HAMBURG")
+const FILLER: &[&str] = &["IS", "HR", "HERE", "NR"];
+
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    name: Option<String>,
+    qth:  Option<String>,
+    rig:  Option<String>,
+    ant:  Option<String>,
+    pwr:  Option<String>,
+    wx:   Option<String>,
+    /// Topics the SIM has remarked on
+    remarked: Vec<Topic>,
+    /// Topics the SIM has asked for
+    asked:    Vec<Topic>,
+}
+
+impl Memory {
+    pub fn get(&self, t: Topic) -> Option<&str> {
+        match t {
+            Topic::Name => self.name.as_deref(),
+            Topic::Qth  => self.qth.as_deref(),
+            Topic::Rig  => self.rig.as_deref(),
+            Topic::Ant  => self.ant.as_deref(),
+            Topic::Pwr  => self.pwr.as_deref(),
+            Topic::Wx   => self.wx.as_deref(),
+        }
+    }
+
+    fn slot(&mut self, t: Topic) -> &mut Option<String> {
+        match t {
+            Topic::Name => &mut self.name,
+            Topic::Qth  => &mut self.qth,
+            Topic::Rig  => &mut self.rig,
+            Topic::Ant  => &mut self.ant,
+            Topic::Pwr  => &mut self.pwr,
+            Topic::Wx   => &mut self.wx,
+        }
+    }
+
+    /// Pick up every `KEYWORD value…` in one of the user's overs.  A later
+    /// over overrides an earlier value ("QTH NR HAMBURG" after "QTH HOME").
+    pub fn learn(&mut self, over: &str) {
+        let words: Vec<String> = over.split_whitespace().map(str::to_uppercase).collect();
+        let mut i = 0;
+        while i < words.len() {
+            let Some(topic) = Topic::from_keyword(&words[i]) else { i += 1; continue };
+            i += 1;
+            while i < words.len() && FILLER.contains(&words[i].as_str()) { i += 1; }
+            let start = i;
+            let ends = |w: &str| STOP.contains(&w) || w.starts_with('<');
+            // The first word is the value even if it looks like a keyword ("NAME OP")
+            if i < words.len() && !ends(&words[i]) { i += 1; }
+            while i < words.len() && Topic::from_keyword(&words[i]).is_none() && !ends(&words[i]) {
+                i += 1;
+            }
+            let mut value = words[start..i].to_vec();
+            // Values are usually sent twice: "HAMBURG HAMBURG", "NEW YORK NEW YORK"
+            let half = value.len() / 2;
+            if value.len().is_multiple_of(2) && half > 0 && value[..half] == value[half..] {
+                value.truncate(half);
+            }
+            if !value.is_empty() {
+                *self.slot(topic) = Some(value.join(" "));
+            }
+        }
+    }
+
+    /// "TNX DENNIS" when the user's name is known, else "TU"
+    pub fn thanks(&self) -> String {
+        self.name.as_ref().map_or_else(|| "TU".to_string(), |n| format!("TNX {n}"))
+    }
+
+    /// Remarks on what the user told the SIM and has not been commented on
+    /// yet, e.g. "FB ON UR IC7300 <BT> HAMBURG IS A NICE CITY".
+    pub fn remarks(&mut self) -> Option<String> {
+        let mut out = vec![];
+        for t in [Topic::Rig, Topic::Qth, Topic::Ant, Topic::Wx] {
+            if self.remarked.contains(&t) { continue; }
+            if let Some(text) = self.remark(t) {
+                out.push(text);
+                self.remarked.push(t);
+            }
+        }
+        (!out.is_empty()).then(|| out.join(" <BT> "))
+    }
+
+    fn remark(&self, t: Topic) -> Option<String> {
+        let v = self.get(t)?;
+        Some(match t {
+            Topic::Rig  => format!("FB ON UR {v}"),
+            // A plain "HOME" is not a place worth remarking on
+            Topic::Qth if v == "HOME" => return None,
+            Topic::Qth  => format!("{v} IS A NICE CITY"),
+            Topic::Ant  => format!("UR {v} WORKS FB"),
+            Topic::Wx   => format!("UR WX {v} SOUNDS GOOD"),
+            Topic::Name | Topic::Pwr => return None,
+        })
+    }
+
+    /// A question for the exchange fields the user left out and the SIM has
+    /// not asked about yet, e.g. "PSE UR QTH ES RIG?".  Asked once only.
+    pub fn questions(&mut self) -> Option<String> {
+        let missing: Vec<Topic> = [Topic::Name, Topic::Qth, Topic::Rig, Topic::Ant, Topic::Pwr]
+            .into_iter()
+            .filter(|&t| self.get(t).is_none() && !self.asked.contains(&t))
+            .collect();
+        if missing.is_empty() { return None; }
+        self.asked.extend(&missing);
+        let words: Vec<&str> = missing.iter().map(|t| t.keyword()).collect();
+        Some(format!("PSE UR {}?", words.join(" ES ")))
+    }
+
+    /// A scripted chat line with its question ("HW UR WX?", "HW UR RIG?")
+    /// turned into a remark when the user has already told the SIM, or
+    /// dropped when the SIM has remarked on it before.
+    pub fn adapt_chat(&mut self, line: &str) -> String {
+        let mut words: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+        let Some(q) = words.iter().position(|w| w.ends_with('?') && w.len() > 1) else {
+            return line.to_string();
+        };
+        let Some(topic) = Topic::from_keyword(words[q].trim_end_matches('?')) else {
+            return line.to_string();
+        };
+        if self.get(topic).is_none() {
+            return line.to_string();
+        }
+        // "HW UR WX?" — the question starts at HW
+        let from = (0..q).rev().find(|&i| words[i] == "HW").unwrap_or(q);
+        let remark = match self.remark(topic) {
+            Some(text) if !self.remarked.contains(&topic) && from > 0 => vec!["<BT>".into(), text],
+            Some(text) if !self.remarked.contains(&topic) => vec![text],
+            _ => vec![],
+        };
+        self.remarked.push(topic);
+        words.splice(from..=q, remark);
+        words.join(" ")
+    }
+}
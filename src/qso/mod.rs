@@ -1,6 +1,21 @@
 // src/qso/mod.rs  —  QSO state machine + callsign list + exchange logic
+pub mod band;
+pub mod callgen;
+pub mod callpool;
 pub mod callsigns;
+pub mod cty;
+pub mod cutnum;
+pub mod dialogue;
 pub mod exchanges;
+pub mod locator;
+pub mod log;
+pub mod memory;
+pub mod personality;
+pub mod propagation;
+pub mod radiogram;
+pub mod scoring;
 pub mod state;
+pub mod typo;
+pub mod validate;
 
 pub use state::{QsoEngine, QsoEvent};
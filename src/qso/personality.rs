@@ -0,0 +1,192 @@
+// src/qso/personality.rs  —  SIM operator personalities: speed, latency, habits, fist
+//
+// Profiles ship embedded (src/qso/personalities.toml).  A `personalities.toml`
+// in the config directory replaces them at runtime.  A station's profile is
+// drawn from its callsign and the session seed, so the same call is the same
+// operator for the whole session.
+use std::sync::OnceLock;
+use anyhow::{bail, Context, Result};
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
+use crate::config::QsoStyle;
+use crate::morse::Fist;
+use super::exchanges::QsoScript;
+
+static EMBEDDED: &str = include_str!("personalities.toml");
+
+/// How much an operator talks before the 73
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verbosity { Terse, Normal, Chatty }
+
+/// One operator type.  Missing fields in the file fall back to the neutral
+/// operator, who behaves exactly as configured.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Personality {
+    pub name:          String,
+    /// Styles this operator appears in; empty = any style
+    pub styles:        Vec<QsoStyle>,
+    pub weight:        u32,
+    /// Factor on sim_wpm
+    pub speed:         f32,
+    /// Factor on min_delay_ms / max_delay_ms
+    pub latency:       f64,
+    pub verbosity:     Verbosity,
+    /// 1.0 = full ham shorthand, 0.0 = every abbreviation spelled out
+    pub abbreviations: f64,
+    /// '?' requests answered with a full repeat
+    pub patience:      u8,
+    /// Factor on typo_rate
+    pub errors:        f64,
+    /// Keying — a quality number or a full fist model
+    pub fist:          FistSpec,
+}
+
+/// `fist = 0.8` (quality, 1.0 = machine-perfect) or
+/// `fist = { weight = 0.1, ratio = 3.6, jitter = 0.1, bug = true, swing = 0.3 }`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+pub enum FistSpec {
+    Quality(f32),
+    Model(Fist),
+}
+
+/// The operator used with `--no-personalities`: configured speed, delays and
+/// typo rate, the scripted wording, perfect keying.
+pub static NEUTRAL: Personality = Personality {
+    name:          String::new(),
+    styles:        Vec::new(),
+    weight:        1,
+    speed:         1.0,
+    latency:       1.0,
+    verbosity:     Verbosity::Normal,
+    abbreviations: 1.0,
+    patience:      u8::MAX,
+    errors:        1.0,
+    fist:          FistSpec::Quality(1.0),
+};
+
+impl Default for Personality {
+    fn default() -> Self { NEUTRAL.clone() }
+}
+
+/// Abbreviations the scripts use and what an operator who avoids them sends
+static ABBREVIATIONS: &[(&str, &str)] = &[
+    ("ES",   "AND"),        ("TNX",  "THANKS"),      ("TU",   "THANK YOU"),
+    ("UR",   "YOUR"),       ("HR",   "HERE"),        ("FB",   "FINE"),
+    ("WX",   "WEATHER"),    ("PSE",  "PLEASE"),      ("GL",   "GOOD LUCK"),
+    ("VY",   "VERY"),       ("YRS",  "YEARS"),       ("NW",   "NOW"),
+    ("LIC",  "LICENSED"),   ("GE",   "GOOD EVENING"),("ANT",  "ANTENNA"),
+    ("PWR",  "POWER"),      ("TEMP", "TEMPERATURE"), ("SIG",  "SIGNAL"),
+    ("GD",   "GOOD"),       ("FER",  "FOR"),         ("HW?",  "HOW COPY?"),
+];
+
+/// Rag-chew turns of an operator of normal verbosity; a chatty one goes
+/// through every planned topic, a terse one takes a single turn
+const NORMAL_TURNS: usize = 4;
+
+#[derive(Deserialize)]
+struct ProfileFile {
+    personality: Vec<Personality>,
+}
+
+impl Personality {
+    /// Parse a personalities file (`[[personality]]` tables).
+    pub fn parse_all(text: &str) -> Result<Vec<Self>> {
+        let file: ProfileFile = toml::from_str(text)?;
+        if file.personality.is_empty() { bail!("no [[personality]] entries"); }
+        for p in &file.personality {
+            if p.speed <= 0.0 || p.latency < 0.0 || p.errors < 0.0
+                || !(0.0..=1.0).contains(&p.abbreviations)
+            {
+                bail!("{:?}: speed must be > 0, latency and errors ≥ 0, \
+                       abbreviations within 0.0 – 1.0", p.name);
+            }
+            match p.fist {
+                FistSpec::Quality(q) if !(0.0..=1.0).contains(&q) =>
+                    bail!("{:?}: fist quality must be within 0.0 – 1.0", p.name),
+                FistSpec::Model(f) => f.validate().with_context(|| format!("{:?}", p.name))?,
+                FistSpec::Quality(_) => {}
+            }
+        }
+        Ok(file.personality)
+    }
+
+    /// SIM speed for a configured `sim_wpm`
+    pub fn wpm(&self, sim_wpm: u8) -> u8 {
+        (f32::from(sim_wpm) * self.speed).round().clamp(5.0, 50.0) as u8
+    }
+
+    pub fn keying(&self) -> Fist {
+        match self.fist {
+            FistSpec::Quality(q) => Fist::from_quality(q),
+            FistSpec::Model(f)   => f,
+        }
+    }
+
+    /// Rework a built script to this operator's habits — how long they chat
+    /// and how much they abbreviate.  Only conversational styles (those with
+    /// chat turns) change; contest exchanges keep their fixed format.
+    pub fn shape<R: Rng>(&self, script: &mut QsoScript, rng: &mut R) {
+        if script.chat.is_empty() { return; }
+        match self.verbosity {
+            Verbosity::Terse  => script.chat.truncate(1),
+            Verbosity::Normal => script.chat.truncate(NORMAL_TURNS),
+            Verbosity::Chatty => {}
+        }
+        if self.abbreviations >= 1.0 { return; }
+        let texts = [&mut script.report, &mut script.ack_report, &mut script.sign_off]
+            .into_iter()
+            .chain(script.chat.iter_mut());
+        for text in texts {
+            *text = text.split_whitespace()
+                .map(|w| match ABBREVIATIONS.iter().find(|(a, _)| *a == w) {
+                    Some((_, full)) if rng.gen_bool(1.0 - self.abbreviations) => *full,
+                    _ => w,
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+    }
+}
+
+/// The active profiles: `<config dir>/personalities.toml` if present and
+/// valid, otherwise the embedded ones.  Loaded once on first use.
+pub fn all() -> &'static [Personality] {
+    static ALL: OnceLock<Vec<Personality>> = OnceLock::new();
+    ALL.get_or_init(|| {
+        let user = crate::config::config_dir().join("personalities.toml");
+        if let Ok(text) = std::fs::read_to_string(&user) {
+            match Personality::parse_all(&text) {
+                Ok(all) => {
+                    log::info!("personalities: {} profiles from {}", all.len(), user.display());
+                    return all;
+                }
+                Err(e) => log::warn!("personalities: ignoring {}: {e:#}", user.display()),
+            }
+        }
+        Personality::parse_all(EMBEDDED).expect("embedded personalities.toml must parse")
+    })
+}
+
+/// The operator behind `call` in a `style` session with `seed`.  Profiles
+/// listing the style and all-rounders (no `styles`) compete by weight.
+pub fn for_call(call: &str, style: QsoStyle, seed: u64) -> &'static Personality {
+    let all = all();
+    let fitting: Vec<&Personality> = all.iter()
+        .filter(|p| p.styles.is_empty() || p.styles.contains(&style))
+        .collect();
+    let mut rng = SmallRng::seed_from_u64(seed ^ fnv1a(call));
+    let p = fitting.choose_weighted(&mut rng, |p| p.weight).copied()
+        .unwrap_or(&all[0]);
+    log::debug!("{call} is played by {:?}", p.name);
+    p
+}
+
+/// Stable hash of a callsign, independent of the Rust version
+fn fnv1a(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ u64::from(b)).wrapping_mul(0x100_0000_01b3))
+}
@@ -0,0 +1,212 @@
+// src/qso/propagation.rs  —  Simple HF propagation: signal level from path, band and time
+//
+// Not a real ionospheric model — a table of where each band is open by day
+// and by night, good enough to make a 40 m QSO across town loud, a 10 m path
+// at midnight hopeless and 20 m to the other side of the world workable in
+// daylight.  Day or night is the local solar time at the path midpoint,
+// taken from the session clock (--utc) so that a replay hears the same bands.
+// VHF contest paths use tropo: the signal falls off steadily with distance.
+use rand::Rng;
+use crate::config::{AppConfig, Band, QsoStyle};
+use super::{cty, locator};
+
+/// Where a band is open, in km: ground wave up to `ground`, skip propagation
+/// from `from` to `to`
+#[derive(Debug, Clone, Copy)]
+struct Window { ground: f64, from: f64, to: f64 }
+
+/// Band openings by day and by night
+fn windows(band: Band) -> (Window, Window) {
+    let w = |ground, from, to| Window { ground, from, to };
+    match band {
+        Band::B160 => (w(200.0, 0.0,    300.0), w(200.0,    0.0,  5000.0)),
+        Band::B80  => (w(150.0, 0.0,    700.0), w(150.0,    0.0,  7000.0)),
+        Band::B60  => (w(120.0, 0.0,   1000.0), w(120.0,  100.0,  8000.0)),
+        Band::B40  => (w( 80.0, 100.0, 1500.0), w( 80.0,  300.0, 12000.0)),
+        Band::B30  => (w( 60.0, 300.0, 3000.0), w( 60.0,  500.0, 12000.0)),
+        Band::B20  => (w( 40.0, 600.0, 15000.0), w(40.0, 1500.0,  6000.0)),
+        Band::B17  => (w( 30.0, 800.0, 15000.0), w(30.0, 2000.0,  4000.0)),
+        Band::B15  => (w( 30.0, 1000.0, 15000.0), w(30.0,   0.0,     0.0)),
+        Band::B12  => (w( 25.0, 1500.0, 12000.0), w(25.0,   0.0,     0.0)),
+        Band::B10  => (w( 25.0, 1500.0, 12000.0), w(25.0,   0.0,     0.0)),
+    }
+}
+
+/// Band noise in S-units (atmospheric and man-made noise falls with frequency)
+fn noise_s(band: Band) -> f64 {
+    match band {
+        Band::B160 => 5.0, Band::B80 => 4.5, Band::B60 => 4.0, Band::B40 => 3.5,
+        Band::B30  => 3.0, Band::B20 => 2.5, Band::B17 => 2.0, Band::B15 => 2.0,
+        Band::B12  => 1.5, Band::B10 => 1.5,
+    }
+}
+
+/// Deepest QSB on the band, as a fraction of the signal: long skip paths on
+/// the high bands fade most, ground wave and short low-band skip least
+fn qsb_max(band: Band) -> f32 {
+    match band {
+        Band::B160 => 0.25, Band::B80 => 0.3, Band::B60 => 0.3, Band::B40 => 0.4,
+        Band::B30  => 0.45, Band::B20 => 0.5, Band::B17 => 0.55, Band::B15 => 0.6,
+        Band::B12  => 0.6,  Band::B10 => 0.65,
+    }
+}
+
+/// One signal as received: S-meter reading, signal-to-noise ratio and fading
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Signal {
+    /// S-meter reading; above 9 is "S9 plus" (10 = S9+6 dB)
+    pub s:      f64,
+    pub snr_db: f64,
+    /// QSB depth, 0 (steady) – 1 (fades out completely)
+    pub qsb:    f32,
+}
+
+impl Signal {
+    /// A strong, clean signal (used when the path is unknown)
+    pub const STRONG: Self = Self { s: 9.0, snr_db: 30.0, qsb: 0.0 };
+
+    /// The RST an operator would give this signal: readability from the
+    /// SNR, strength from the S-meter, tone always 9
+    pub fn rst(&self) -> String {
+        let r = match self.snr_db {
+            x if x >= 12.0 => 5,
+            x if x >= 6.0  => 4,
+            x if x >= 2.0  => 3,
+            _              => 2,
+        };
+        format!("{r}{}9", self.s_digit())
+    }
+
+    /// S digit of the report (the "5" in 559)
+    pub fn s_digit(&self) -> u8 { self.s.round().clamp(1.0, 9.0) as u8 }
+
+    /// Tone amplitude, 0.3 – 1.0 of the configured volume: the receiver's
+    /// AGC keeps weak signals audible
+    pub fn amplitude(&self) -> f32 {
+        (0.3 + 0.7 * (self.s - 1.0) / 8.0).clamp(0.3, 1.0) as f32
+    }
+
+    /// Noise amplitude relative to the configured volume
+    pub fn noise(&self) -> f32 {
+        (0.4 * 10f64.powf(-self.snr_db / 20.0)).clamp(0.0, 0.6) as f32
+    }
+}
+
+/// The path between the user and one SIM station
+#[derive(Debug, Clone, Copy)]
+pub struct Path {
+    pub km:   f64,
+    band:     Band,
+    /// Local solar hour at the path midpoint, 0 – 24
+    solar:    f64,
+    tropo:    bool,
+}
+
+impl Path {
+    /// Path from the user (locator, else their callsign's country) to a SIM
+    /// station at `sim_locator` (else its country), at the session clock.
+    /// None when either end cannot be placed.
+    pub fn new(cfg: &AppConfig, sim_call: &str, sim_locator: &str) -> Option<Self> {
+        let me  = home(cfg)?;
+        let sim = position(sim_locator, sim_call)?;
+        let km  = locator::great_circle_km(me, sim);
+        let mid_lon = mid_longitude(me.1, sim.1);
+        Some(Self {
+            km,
+            band:  cfg.band,
+            solar: (utc_hours(cfg.utc) + mid_lon / 15.0).rem_euclid(24.0),
+            tropo: cfg.qso_style == QsoStyle::VhfContest,
+        })
+    }
+
+    /// How well the path works, 0 (closed) – 1 (as loud as it gets)
+    fn quality(&self) -> f64 {
+        if self.tropo {
+            return (1.0 - self.km / 1200.0).clamp(0.05, 1.0);
+        }
+        let (day, night) = windows(self.band);
+        let d = daylight(self.solar);
+        d * window_quality(day, self.km) + (1.0 - d) * window_quality(night, self.km)
+    }
+
+    /// Signal received from a station running `watts`, with a random
+    /// spread of about ±1 S-unit for antennas, QSB and the day's conditions
+    pub fn signal<R: Rng>(&self, rng: &mut R, watts: f64) -> Signal {
+        // 6 dB per S-unit; 100 W is the reference
+        let pwr = 10.0 * (watts.max(0.1) / 100.0).log10() / 6.0;
+        let s = (1.0 + 8.5 * self.quality() + pwr + rng.gen_range(-1.0..1.0)).clamp(0.5, 11.0);
+        let noise = if self.tropo { 1.0 } else { noise_s(self.band) };
+        // Ground wave hardly fades; tropo a little
+        let qsb = if self.tropo { 0.15 } else if self.km <= windows(self.band).0.ground { 0.05 }
+                  else { qsb_max(self.band) };
+        Signal { s, snr_db: ((s - noise) * 6.0).max(0.0), qsb: rng.gen_range(0.0..=qsb) }
+    }
+}
+
+fn window_quality(w: Window, km: f64) -> f64 {
+    if km <= w.ground {
+        0.9
+    } else if w.to <= 0.0 {
+        0.05                         // band closed
+    } else if km < w.from {
+        0.25                         // skip zone
+    } else if km <= w.to {
+        0.85 - 0.35 * (km - w.from) / (w.to - w.from).max(1.0)
+    } else {
+        0.5 * (-(km - w.to) / 3000.0).exp()
+    }
+}
+
+/// 1 in daylight, 0 at night, with an hour of grey line either side of 06 and 18
+fn daylight(solar: f64) -> f64 {
+    ((solar - 5.5).min(18.5 - solar)).clamp(0.0, 1.0)
+}
+
+/// How much of the user's own sky is daylight at the session clock, 1 (day) –
+/// 0 (night); noon UTC when the user cannot be placed
+pub fn home_daylight(cfg: &AppConfig) -> f64 {
+    let lon = home(cfg).map_or(0.0, |p| p.1);
+    daylight((utc_hours(cfg.utc) + lon / 15.0).rem_euclid(24.0))
+}
+
+/// The user's position: their locator, else the centre of their country
+pub fn home(cfg: &AppConfig) -> Option<(f64, f64)> {
+    position(&cfg.my_locator, &cfg.mycall)
+}
+
+/// A station's position: its locator, else the centre of its country
+fn position(loc: &str, call: &str) -> Option<(f64, f64)> {
+    locator::to_latlon(loc).or_else(|| {
+        cty::lookup(call).map(|i| (i.entity.lat, i.entity.lon))
+    })
+}
+
+/// Longitude halfway between two, taking the short way round
+fn mid_longitude(a: f64, b: f64) -> f64 {
+    let d = (b - a + 540.0).rem_euclid(360.0) - 180.0;
+    a + d / 2.0
+}
+
+/// Hours since 00:00 UTC of a Unix time
+fn utc_hours(secs: u64) -> f64 {
+    (secs % 86_400) as f64 / 3600.0
+}
+
+/// Styles where operators give honest reports; everywhere else the
+/// contest convention is 599 whatever the signal
+pub fn honest_reports(style: QsoStyle) -> bool {
+    matches!(style,
+        QsoStyle::Ragchew | QsoStyle::QttAward | QsoStyle::CqDx | QsoStyle::Random
+        | QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+        | QsoStyle::SkccSprint | QsoStyle::NaqccSprint | QsoStyle::FistsSprint)
+}
+
+/// Transmit power in watts from an exchange value such as "100W" or "1KW"
+pub fn watts(pwr: &str) -> f64 {
+    let up = pwr.to_uppercase();
+    let (num, scale) = match up.strip_suffix("KW") {
+        Some(n) => (n, 1000.0),
+        None    => (up.trim_end_matches('W'), 1.0),
+    };
+    num.parse::<f64>().map_or(100.0, |n| n * scale)
+}
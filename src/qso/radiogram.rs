@@ -0,0 +1,153 @@
+// src/qso/radiogram.rs  —  ARRL / NTS radiogram generator + fill handling
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Precedences as sent in the preamble.  ROUTINE dominates real NTS traffic.
+static PRECEDENCES: &[&str] = &["R", "R", "R", "R", "W", "P", "EMERGENCY"];
+
+/// HX handling instructions (optional — most messages carry none)
+static HX_CODES: &[&str] = &["HXA50", "HXB12", "HXC", "HXD", "HXE", "HXF", "HXG"];
+
+static MONTHS: &[&str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Addressees: name, street, city, state, ZIP, phone
+static ADDRESSES: &[(&str, &str, &str, &str, &str, &str)] = &[
+    ("JOHN MILLER",   "42 OAK ST",        "SPRINGFIELD", "IL", "62701", "217 555 0143"),
+    ("MARY JONES",    "118 ELM AVE",      "DAYTON",      "OH", "45402", "937 555 0187"),
+    ("ROBERT BROWN",  "7 HARBOR RD",      "PORTLAND",    "ME", "04101", "207 555 0112"),
+    ("LINDA DAVIS",   "2210 PINE DR",     "BOISE",       "ID", "83702", "208 555 0166"),
+    ("JAMES WILSON",  "15 MAPLE LN",      "NASHUA",      "NH", "03060", "603 555 0175"),
+    ("SUSAN MOORE",   "901 LAKE BLVD",    "MADISON",     "WI", "53703", "608 555 0129"),
+    ("DAVID TAYLOR",  "33 RIVER RD",      "TULSA",       "OK", "74103", "918 555 0138"),
+    ("KAREN CLARK",   "64 HILL ST",       "FRESNO",      "CA", "93721", "559 555 0191"),
+    ("PAUL LEWIS",    "480 MAIN ST",      "HARTFORD",    "CT", "06103", "860 555 0104"),
+    ("NANCY HALL",    "12 CEDAR CT",      "RALEIGH",     "NC", "27601", "919 555 0150"),
+];
+
+/// Message texts.  "X" is the radiogram period and counts as one word.
+static TEXTS: &[&str] = &[
+    "ARRIVED SAFELY X WILL CALL SUNDAY X LOVE",
+    "HAPPY BIRTHDAY X HOPE TO SEE YOU AT FIELD DAY X 73",
+    "NET MEETS TUESDAY 1900 LOCAL ON 3580 X PLEASE CHECK IN",
+    "SHELTER OPEN AT HIGH SCHOOL X NEED COTS AND WATER X PLEASE ADVISE",
+    "ALL WELL HERE X STORM DAMAGE LIGHT X POWER RESTORED",
+    "CONGRATULATIONS ON YOUR NEW LICENSE X WELCOME TO AMATEUR RADIO",
+    "MEET TOMORROW AT CLUB STATION 1800 X BRING LOGS",
+    "EXERCISE TRAFFIC X PLEASE RELAY TO SECTION MANAGER X THANKS",
+    "FLIGHT DELAYED X ARRIVING FRIDAY NOON X DO NOT WORRY",
+    "REPEATER BACK ON AIR X NEW ANTENNA WORKS FB X THANKS FOR HELP",
+];
+
+/// A complete radiogram: preamble, address, text and signature.
+#[derive(Debug, Clone)]
+pub struct Radiogram {
+    pub number:     u32,
+    pub precedence: &'static str,
+    pub hx:         Option<&'static str>,
+    pub origin:     String,
+    /// Word count of the text as stated in the preamble
+    pub check:      usize,
+    pub place:      String,
+    pub time:       String,
+    pub date:       String,
+    pub address:    Vec<String>,
+    pub text:       Vec<String>,
+    pub signature:  String,
+    /// City the message is addressed to — announced in "QTC 1 <city>"
+    pub dest_city:  String,
+}
+
+impl Radiogram {
+    /// `origin` is the originating station's callsign, `place` its QTH; `now`
+    /// is the session clock (Unix time).
+    /// The check is always derived from the text so it matches the word count.
+    pub fn generate<R: Rng>(rng: &mut R, now: u64, origin: &str, place: &str, signature: &str) -> Self {
+        let (name, street, city, state, zip, phone) = *ADDRESSES.choose(rng).unwrap();
+        let text: Vec<String> = TEXTS.choose(rng).unwrap()
+            .split_whitespace().map(str::to_string).collect();
+
+        // Time filed: sometime in the last few hours, UTC — the date is the
+        // day it was filed, which may be yesterday
+        let filed = now.saturating_sub(rng.gen_range(5u64..=240) * 60);
+        let (_, month, day, hour, minute, _) = crate::report::civil_utc(filed);
+
+        let address = [name, street, &format!("{city} {state} {zip}"), phone]
+            .iter().flat_map(|l| l.split_whitespace()).map(str::to_string).collect();
+
+        Self {
+            number:     rng.gen_range(1u32..=999),
+            precedence: PRECEDENCES.choose(rng).unwrap(),
+            hx:         if rng.gen_bool(0.3) { Some(HX_CODES.choose(rng).unwrap()) } else { None },
+            origin:     origin.to_string(),
+            check:      text.len(),
+            place:      place.to_string(),
+            time:       format!("{hour:02}{minute:02}Z"),
+            date:       format!("{} {}", MONTHS[month as usize - 1], day),
+            address,
+            text,
+            signature:  signature.to_string(),
+            dest_city:  city.to_string(),
+        }
+    }
+
+    /// "NR 57 R HXG DL1ABC 9 BERLIN 1830Z OCT 18"
+    pub fn preamble(&self) -> String {
+        let mut p = format!("NR {} {}", self.number, self.precedence);
+        if let Some(hx) = self.hx { p.push(' '); p.push_str(hx); }
+        format!("{p} {} {} {} {} {}", self.origin, self.check, self.place, self.time, self.date)
+    }
+
+    /// The whole message as sent on CW:
+    /// preamble, address, `<BT>` text `<BT>`, signature, `<AR>`.
+    pub fn to_cw(&self) -> String {
+        format!("{} {} <BT> {} <BT> {} <AR>",
+            self.preamble(), self.address.join(" "), self.text.join(" "), self.signature)
+    }
+
+    /// Number of words actually in the text — what the receiving operator counts.
+    pub fn word_count(&self) -> usize { self.text.len() }
+
+    /// Fill-able words: address, text and signature in transmission order.
+    fn words(&self) -> Vec<&str> {
+        self.address.iter()
+            .chain(self.text.iter())
+            .map(String::as_str)
+            .chain(self.signature.split_whitespace())
+            .collect()
+    }
+
+    /// Answer a fill request, or `None` if `input` is not one we understand.
+    ///
+    ///   WA <w>        word after <w>
+    ///   WB <w>        word before <w>
+    ///   AA <w>        all after <w>
+    ///   AB <w>        all before <w>
+    ///   BN <w1> <w2>  all between <w1> and <w2>
+    ///
+    /// The reply repeats the reference word(s) so the receiver can place the fill.
+    pub fn fill(&self, input: &str) -> Option<String> {
+        let req: Vec<&str> = input.split_whitespace().collect();
+        let words = self.words();
+        let find = |w: &str| words.iter().position(|x| *x == w);
+
+        let pos = req.iter().position(|w| matches!(*w, "WA" | "WB" | "AA" | "AB" | "BN"))?;
+        let kind = req[pos];
+        let a    = *req.get(pos + 1)?;
+        let i    = find(a)?;
+
+        let fill: Vec<&str> = match kind {
+            "WA" => vec![a, *words.get(i + 1)?],
+            "WB" => vec![*words.get(i.checked_sub(1)?)?, a],
+            "AA" => words[i..].to_vec(),
+            "AB" => words[..=i].to_vec(),
+            _ => {
+                let b = *req.get(pos + 2)?;
+                let j = words[i + 1..].iter().position(|x| *x == b)? + i + 1;
+                words[i..=j].to_vec()
+            }
+        };
+        Some(format!("{} K", fill.join(" ")))
+    }
+}
@@ -0,0 +1,156 @@
+// src/qso/scoring.rs  —  Live contest scoring: QSO points + multipliers
+use std::collections::HashSet;
+use crate::config::{Band, QsoStyle};
+use super::cty;
+use super::log::LoggedQso;
+use super::locator;
+
+/// How the claimed score is formed from points and multipliers
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rule {
+    /// points × multipliers (WPX, CQ WW, DARC, CWT, SST, sprints)
+    PointsTimesMults,
+    /// points only (generic contest, MWC, VHF km, activating a park/summit)
+    PointsOnly,
+    /// number of distinct references worked (hunting parks/summits/towers/castles)
+    MultsOnly,
+}
+
+/// Snapshot shown in the TUI score panel and printed at session end
+#[derive(Debug, Clone, Default)]
+pub struct ScoreSummary {
+    pub qsos:    u32,
+    pub points:  u32,
+    pub mults:   u32,
+    pub claimed: u64,
+    /// Multipliers in the order they were worked
+    pub mult_list: Vec<String>,
+    /// Last QSO: callsign, points, new multipliers (empty if none), dupe flag
+    pub last: Option<(String, u32, Vec<String>, bool)>,
+}
+
+pub struct Scorer {
+    style:        QsoStyle,
+    /// The session's band — WPX counts double on the low bands
+    band:         Band,
+    rule:         Rule,
+    my_country:   &'static str,
+    my_continent: &'static str,
+    worked:       HashSet<String>,
+    mults:        Vec<String>,
+    summary:      ScoreSummary,
+}
+
+impl Scorer {
+    /// Returns None for styles that are not scored (rag-chew, QTT, CQ DX, radiogram).
+    /// `activating` is true when the user is the POTA/SOTA/TOTA/COTA activator.
+    pub fn new(style: QsoStyle, band: Band, mycall: &str, activating: bool) -> Option<Self> {
+        let rule = match style {
+            QsoStyle::WpxContest | QsoStyle::CqWwContest | QsoStyle::DarcCwContest
+            | QsoStyle::CwtContest | QsoStyle::SstContest | QsoStyle::WwaContest
+            | QsoStyle::SkccSprint | QsoStyle::NaqccSprint | QsoStyle::FistsSprint
+                => Rule::PointsTimesMults,
+            QsoStyle::Contest | QsoStyle::DxPileup | QsoStyle::MwcContest | QsoStyle::VhfContest
+                => Rule::PointsOnly,
+            QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+                => if activating { Rule::PointsOnly } else { Rule::MultsOnly },
+            QsoStyle::Ragchew | QsoStyle::QttAward | QsoStyle::CqDx
+            | QsoStyle::Radiogram | QsoStyle::Random => return None,
+        };
+        let me = cty::lookup(mycall);
+        Some(Self {
+            style,
+            band,
+            rule,
+            my_country:   me.as_ref().map_or("", |i| i.entity.prefix.as_str()),
+            my_continent: me.as_ref().map_or("", |i| i.continent),
+            worked:  HashSet::new(),
+            mults:   Vec::new(),
+            summary: ScoreSummary::default(),
+        })
+    }
+
+    /// Score one completed QSO and return the updated summary.
+    /// A second QSO with the same station is a dupe: logged, but worth nothing.
+    pub fn add(&mut self, q: &LoggedQso) -> &ScoreSummary {
+        let dupe = !self.worked.insert(q.call.clone());
+        let (points, keys) = if dupe { (0, vec![]) } else { self.rate(q) };
+
+        let mut new_mults = vec![];
+        for k in keys {
+            if !k.is_empty() && !self.mults.contains(&k) {
+                self.mults.push(k.clone());
+                new_mults.push(k);
+            }
+        }
+
+        let s = &mut self.summary;
+        s.qsos   += 1;
+        s.points += points;
+        s.mults   = self.mults.len() as u32;
+        s.claimed = match self.rule {
+            Rule::PointsTimesMults => s.points as u64 * s.mults as u64,
+            Rule::PointsOnly       => s.points as u64,
+            Rule::MultsOnly        => s.mults as u64,
+        };
+        s.mult_list = self.mults.clone();
+        s.last = Some((q.call.clone(), points, new_mults, dupe));
+        &self.summary
+    }
+
+    pub fn summary(&self) -> &ScoreSummary { &self.summary }
+
+    /// QSO points and multiplier keys for one (non-dupe) QSO.
+    fn rate(&self, q: &LoggedQso) -> (u32, Vec<String>) {
+        let info = cty::lookup(&q.call);
+        let country   = info.as_ref().map_or("", |i| i.entity.prefix.as_str());
+        let continent = info.as_ref().map_or("", |i| i.continent);
+        match self.style {
+            // CQ WPX: 3 pts other continent, 1 pt same continent (2 within NA),
+            // 0 pts own country, all doubled on 40, 80 and 160 m; one
+            // multiplier per distinct prefix
+            QsoStyle::WpxContest => (
+                self.dx_points(country, continent)
+                    * if matches!(self.band, Band::B40 | Band::B80 | Band::B160) { 2 } else { 1 },
+                vec![info.as_ref().map_or_else(|| cty::wpx_prefix(&q.call), |i| i.wpx.clone())],
+            ),
+            // CQ WW: same QSO points; zones and DXCC entities are separate multipliers
+            QsoStyle::CqWwContest => (
+                self.dx_points(country, continent),
+                vec![format!("Z{:02}", q.cq_zone), country.to_string()],
+            ),
+            // DARC: 1 pt per QSO, each DOK is a multiplier ("NM" is not)
+            QsoStyle::DarcCwContest => {
+                let dok = if q.dok == "NM" { String::new() } else { q.dok.clone() };
+                (1, vec![dok])
+            }
+            // CWT: QSOs × distinct callsigns
+            QsoStyle::CwtContest => (1, vec![q.call.clone()]),
+            // WWA: every distinct WWA special station counts as a multiplier
+            QsoStyle::WwaContest => (1, vec![q.call.clone()]),
+            // SST / SKCC WES: 1 pt per QSO, SPC multipliers
+            QsoStyle::SstContest | QsoStyle::SkccSprint => (1, vec![q.spc.clone()]),
+            // NAQCC: 2 pts for members, 1 pt otherwise; FISTS: 5 / 2 pts
+            QsoStyle::NaqccSprint => (if q.member { 2 } else { 1 }, vec![q.spc.clone()]),
+            QsoStyle::FistsSprint => (if q.member { 5 } else { 2 }, vec![q.spc.clone()]),
+            // IARU Region 1 VHF/UHF: 1 pt per km
+            QsoStyle::VhfContest => (q.km.map_or(0, locator::qso_points), vec![]),
+            // Hunter: distinct references count; activator: QSOs count
+            QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+                => (1, vec![q.reference.clone()]),
+            _ => (1, vec![]),
+        }
+    }
+
+    fn dx_points(&self, country: &str, continent: &str) -> u32 {
+        if country == self.my_country {
+            0
+        } else if continent != self.my_continent {
+            3
+        } else if continent == "NA" {
+            2
+        } else {
+            1
+        }
+    }
+}
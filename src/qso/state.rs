@@ -3,11 +3,21 @@ use rand::{Rng, SeedableRng};
 use rand::rngs::SmallRng;
 use std::time::{Duration, Instant};
 use crate::config::{AppConfig, QsoStyle, WhoStarts};
+use crate::morse::Fist;
 use super::callsigns::{
-    random_rst, country_from_callsign,
     random_pota_ref, random_sota_ref, random_tota_ref, random_cota_ref,
 };
+use super::cty;
+use super::dialogue;
 use super::exchanges::{QsoScript, SimExchange};
+use super::locator;
+use super::log::LoggedQso;
+use super::memory::{Memory, Topic};
+use super::personality::{self, Personality};
+use super::band;
+use super::propagation::{self, Signal};
+use super::typo::{self, SendError};
+use super::validate::{self, Field};
 
 /// Events produced by the engine for the UI / audio layer
 #[derive(Debug, Clone)]
@@ -29,13 +39,20 @@ enum Phase {
     SimSendsReport,
     WaitMyReport,
     SimAcksReport,
+    SimSendsFill(String),   // Radiogram: answer a fill request, then back to WaitMyReport
     Chat { turn: usize },
-    WaitChatReply,
+    WaitChatReply { turn: usize },
     SignOff,
     WaitFor73,   // DarcCwContest / MwcContest / WwaContest: wait for user to send 73 after SIM sign-off
     Done,
 }
 
+/// Seed of one QSO in a session.  Each QSO gets its own stream, so QSO 3 of
+/// an exercise is the same however long QSO 2 took or how often it was repeated.
+fn qso_seed(session_seed: u64, my_serial: u32) -> u64 {
+    session_seed ^ u64::from(my_serial).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
 pub struct QsoEngine {
     phase:       Phase,
     script:      QsoScript,
@@ -46,6 +63,12 @@ pub struct QsoEngine {
     min_delay:   Duration,
     max_delay:   Duration,
     rng:         SmallRng,
+    /// The operator playing the SIM station
+    personality: &'static Personality,
+    /// SIM speed for this station (WPM)
+    sim_wpm:     u8,
+    /// '?' requests answered so far
+    repeats:     u8,
     pub mycall:  String,
     pub style:   QsoStyle,
     pub typo_rate: f64,
@@ -54,6 +77,28 @@ pub struct QsoEngine {
     pub who_starts: WhoStarts,
     /// The user's own activator reference (park/summit/tower/castle) when who_starts=Me
     my_activator_ref: String,
+    /// The user's Maidenhead locator (empty when not configured)
+    my_locator:  String,
+    /// The user's running serial number for this QSO
+    my_serial:   u32,
+    /// Band label for the log ("20m")
+    band:        &'static str,
+    /// The exchange over the user actually sent — kept for the session log
+    my_exchange: String,
+    /// Fields the user's exchange has to carry in this style
+    expect:      Vec<Field>,
+    /// Fields missing from or wrong in the user's exchange
+    busted:      Vec<&'static str>,
+    /// Expected characters the user sent wrong in those fields
+    char_errors: Vec<char>,
+    /// Text and number fields of the user's exchange, and whether each was busted
+    checked:     Vec<(String, bool)>,
+    /// Sending mistakes the SIM made in this QSO
+    mistakes:    Vec<SendError>,
+    /// What the user has told the SIM so far (rag-chew styles)
+    memory:      Memory,
+    /// Questions in the user's last over, answered in the SIM's next one
+    asked:       Vec<dialogue::Topic>,
 }
 
 impl QsoEngine {
@@ -61,14 +106,25 @@ impl QsoEngine {
     /// It is embedded in MWC contest exchange hints so the user knows
     /// which serial number to send back to the sim station.
     pub fn new(cfg: &AppConfig, my_serial: u32) -> Self {
-        let mut rng = SmallRng::from_entropy();
-        let ex      = SimExchange::generate(&mut rng, cfg.qso_style);
-        let my_rst  = random_rst(&mut rng).to_string();
+        let mut rng = SmallRng::seed_from_u64(qso_seed(cfg.seed, my_serial));
+        let ex      = SimExchange::generate(&mut rng, cfg);
+        // The report the user should give: what the SIM's signal is worth,
+        // or the contest 599
+        let my_rst  = if propagation::honest_reports(cfg.qso_style) {
+            ex.signal.rst()
+        } else {
+            "599".to_string()
+        };
+        let p = if cfg.personalities {
+            personality::for_call(&ex.sim_call, cfg.qso_style, cfg.seed)
+        } else {
+            &personality::NEUTRAL
+        };
 
         // When the user is the activator, generate their own park/summit/tower/castle ref
         // based on their callsign prefix so the reference country matches their call.
         let my_activator_ref = if cfg.who_starts == WhoStarts::Me {
-            let my_country = country_from_callsign(&cfg.mycall);
+            let my_country = cty::dxcc_prefix(&cfg.mycall);
             match cfg.qso_style {
                 QsoStyle::Pota => random_pota_ref(&mut rng, my_country),
                 QsoStyle::Sota => random_sota_ref(&mut rng, my_country),
@@ -80,12 +136,11 @@ impl QsoEngine {
             String::new()
         };
 
-        let script = QsoScript::build(
-            &mut rng,
-            &cfg.mycall, &ex, cfg.qso_style, &my_rst, my_serial,
-            &cfg.cwt_name, &cfg.cwt_nr, &cfg.my_dok,
-            cfg.who_starts, &my_activator_ref,
+        let mut script = QsoScript::build(
+            &mut rng, cfg, &ex, &my_rst, my_serial, &my_activator_ref,
         );
+        p.shape(&mut script, &mut rng);
+        let expect = validate::expected(cfg, my_serial, &my_activator_ref, &my_rst);
 
         let phase = match cfg.who_starts {
             WhoStarts::Sim => Phase::Init,
@@ -97,17 +152,33 @@ impl QsoEngine {
             my_rst,
             last_tx: String::new(),
             next_tx_at: Instant::now(),
-            min_delay: Duration::from_millis(cfg.min_delay_ms),
-            max_delay: Duration::from_millis(cfg.max_delay_ms),
+            min_delay: Duration::from_millis(cfg.min_delay_ms).mul_f64(p.latency),
+            max_delay: Duration::from_millis(cfg.max_delay_ms).mul_f64(p.latency),
             mycall: cfg.mycall.clone(),
             style:  cfg.qso_style,
-            typo_rate: cfg.typo_rate,
+            typo_rate: (cfg.typo_rate * p.errors).clamp(0.0, 1.0),
             my_dok: cfg.my_dok.clone(),
             who_starts: cfg.who_starts,
             my_activator_ref,
+            my_locator: cfg.my_locator.clone(),
+            my_serial,
+            band: band::label(cfg.band, cfg.qso_style),
+            my_exchange: String::new(),
+            expect,
+            busted: vec![],
+            char_errors: vec![],
+            checked:     vec![],
+            mistakes: vec![],
+            memory: Memory::default(),
+            asked: vec![],
             script,
             exchange: ex,
             rng,
+            personality: p,
+            // The VHF contest is on 2 m, not the HF band
+            sim_wpm: p.wpm(if cfg.qso_style == QsoStyle::VhfContest { cfg.sim_wpm }
+                           else { band::wpm(cfg.band, cfg.sim_wpm) }),
+            repeats: 0,
         }
     }
 
@@ -115,12 +186,16 @@ impl QsoEngine {
     /// `user_input` is the trimmed, uppercased content of the last completed word
     /// from the user's paddle.  Empty string means nothing new was received.
     pub fn tick(&mut self, user_input: &str) -> Option<QsoEvent> {
+        // Whatever the user sends shows whether they caught the SIM's slips
+        if !user_input.is_empty() {
+            for m in &mut self.mistakes { m.hear(user_input); }
+        }
         // Handle '?' at any phase — repeat last transmission.
         // Only a *standalone* '?' word (the IMI prosign ..--..) triggers repeat.
         // A '?' embedded inside another word (e.g. "HW?" in a QTT exchange)
         // must NOT match — otherwise demo mode would loop forever on QTT.
         if user_input.split_whitespace().any(|w| w == "?") && !self.last_tx.is_empty() {
-            return Some(QsoEvent::SimTransmit(self.last_tx.clone()));
+            return Some(QsoEvent::SimTransmit(self.repeat_last()));
         }
 
         let now = Instant::now();
@@ -191,9 +266,40 @@ impl QsoEngine {
                 } else { None }
             }
 
+            Phase::WaitMyReport if self.style == QsoStyle::Radiogram => {
+                match self.radiogram_reply(user_input) {
+                    Some(next) => {
+                        self.my_exchange = user_input.to_string();
+                        self.phase = next;
+                        self.schedule_delay();
+                        None
+                    }
+                    None => Some(QsoEvent::WaitingForUser),
+                }
+            }
+
+            Phase::SimSendsFill(fill) => {
+                if now >= self.next_tx_at {
+                    let tx = fill.clone();
+                    self.last_tx = tx.clone();
+                    self.phase   = Phase::WaitMyReport;
+                    Some(QsoEvent::SimTransmit(tx))
+                } else { None }
+            }
+
             Phase::WaitMyReport => {
                 // Accept any meaningful exchange (at least 2 chars — RST, name, etc.)
                 if user_input.len() >= 2 {
+                    self.my_exchange = user_input.to_string();
+                    self.memory.learn(user_input);
+                    self.asked = dialogue::questions(user_input);
+                    // A busted exchange does not stop the QSO — it is noted for the log
+                    self.busted = validate::check(&self.expect, user_input);
+                    self.char_errors = validate::char_errors(&self.expect, &self.busted, user_input);
+                    self.checked = validate::checked(&self.expect, &self.busted);
+                    if !self.busted.is_empty() {
+                        log::info!("exchange {user_input:?}: check {}", self.busted.join(" "));
+                    }
                     self.phase = Phase::SimAcksReport;
                     self.schedule_delay();
                     None
@@ -204,18 +310,26 @@ impl QsoEngine {
 
             Phase::SimAcksReport => {
                 if now >= self.next_tx_at {
-                    let tx = self.maybe_typo(&self.script.ack_report.clone());
+                    let ack = if self.conversational() {
+                        self.personal_ack(&self.script.ack_report.clone())
+                    } else {
+                        self.script.ack_report.clone()
+                    };
+                    let tx = self.maybe_typo(&ack);
                     self.last_tx = tx.clone();
                     let next_phase = match self.style {
                         // MWC: ack_report IS the sign-off ("TU 73 <SK>"), so
                         // skip the separate SignOff phase and wait for the user's 73.
                         // WWA: same pattern — ack_report is "R TU 73 <SK>", then wait for user 73.
                         QsoStyle::MwcContest | QsoStyle::WwaContest => Phase::WaitFor73,
-                        // CWT / WPX / SST / CqDx / POTA / SOTA / TOTA: ack_report is the
-                        // final transmission — QSO done immediately.
-                        QsoStyle::CwtContest | QsoStyle::WpxContest | QsoStyle::SstContest
+                        // CWT / WPX / SST / CqDx / POTA / SOTA / TOTA / club sprints:
+                        // ack_report is the final transmission — QSO done immediately.
+                        QsoStyle::CwtContest | QsoStyle::WpxContest | QsoStyle::CqWwContest
+                        | QsoStyle::SstContest
                         | QsoStyle::CqDx
-                        | QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota => Phase::Done,
+                        | QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+                        | QsoStyle::SkccSprint | QsoStyle::NaqccSprint | QsoStyle::FistsSprint
+                        | QsoStyle::Radiogram | QsoStyle::VhfContest => Phase::Done,
                         QsoStyle::Contest | QsoStyle::DxPileup | QsoStyle::DarcCwContest => Phase::SignOff,
                         _ => Phase::Chat { turn: 0 },
                     };
@@ -228,26 +342,40 @@ impl QsoEngine {
             Phase::Chat { turn } => {
                 let t = *turn;
                 if now >= self.next_tx_at {
+                    let answers = self.answers();
                     if t >= self.script.chat.len() {
-                        self.phase = Phase::SignOff;
-                        self.schedule_delay();
-                        return None;
+                        if answers.is_empty() {
+                            self.phase = Phase::SignOff;
+                            self.schedule_delay();
+                            return None;
+                        }
+                        // Out of topics, but the user asked something — answer first
+                        let tx = self.maybe_typo(&format!("{answers} <AR>"));
+                        self.last_tx = tx.clone();
+                        self.phase   = Phase::WaitChatReply { turn: t };
+                        return Some(QsoEvent::SimTransmit(tx));
                     }
-                    let tx = self.maybe_typo(&self.script.chat[t].clone());
+                    // Answer the user's questions, remark on anything they told
+                    // since the last over, then the next topic
+                    let line = self.memory.adapt_chat(&self.script.chat[t].clone());
+                    let line = [answers, self.memory.remarks().unwrap_or_default(), line]
+                        .into_iter()
+                        .filter(|s| !s.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" <BT> ");
+                    let tx = self.maybe_typo(&line);
                     self.last_tx = tx.clone();
-                    self.phase   = Phase::WaitChatReply;
+                    self.phase   = Phase::WaitChatReply { turn: t };
                     Some(QsoEvent::SimTransmit(tx))
                 } else { None }
             }
 
-            Phase::WaitChatReply => {
+            Phase::WaitChatReply { turn } => {
                 // Accept any reply of at least 2 chars
                 if user_input.len() >= 2 {
-                    let next_turn = self.script.chat.iter()
-                        .position(|m| m == &self.last_tx)
-                        .map(|i| i + 1)
-                        .unwrap_or(self.script.chat.len());
-                    self.phase = Phase::Chat { turn: next_turn };
+                    self.memory.learn(user_input);
+                    self.asked = dialogue::questions(user_input);
+                    self.phase = Phase::Chat { turn: turn + 1 };
                     self.schedule_delay();
                     None
                 } else {
@@ -257,7 +385,16 @@ impl QsoEngine {
 
             Phase::SignOff => {
                 if now >= self.next_tx_at {
-                    let tx = self.maybe_typo(&self.script.sign_off.clone());
+                    let sign_off = self.script.sign_off.clone();
+                    // "OK DENNIS TU FB QSO 73 …" once the SIM knows the user's name
+                    let sign_off = match (sign_off.split_once(' '), self.memory.get(Topic::Name)) {
+                        (Some(("OK", rest)), Some(name)) if self.conversational() => {
+                            let rest = rest.split_once(' ').map_or("", |(_, r)| r);
+                            format!("OK {name} {rest}")
+                        }
+                        _ => sign_off,
+                    };
+                    let tx = self.maybe_typo(&sign_off);
                     self.last_tx = tx.clone();
                     // DARC CW Contest: sim sends 73 then waits for the user to
                     // reply with 73 before the QSO is considered done.
@@ -305,17 +442,76 @@ impl QsoEngine {
             && (up.contains("CQ") || up.contains(&self.mycall.to_uppercase()))
     }
 
-    /// Simulate human typo: randomly insert <HH> + repeat
+    /// Radiogram receiver over: a fill request gets the requested words,
+    /// "QSL" (optionally with the receiver's word count) confirms the message.
+    /// A count that disagrees with the check makes the SIM resend the text.
+    /// Returns the next phase, or None to keep waiting.
+    fn radiogram_reply(&self, input: &str) -> Option<Phase> {
+        let rg = self.exchange.radiogram.as_ref()?;
+        if let Some(fill) = rg.fill(input) {
+            return Some(Phase::SimSendsFill(fill));
+        }
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let confirm = words.iter().position(|w| *w == "QSL" || *w == "CK")?;
+        let count = words.get(confirm + 1).and_then(|w| w.parse::<usize>().ok());
+        match count {
+            Some(n) if n != rg.word_count() => Some(Phase::SimSendsFill(
+                format!("CK {} <BT> {} <BT> K", rg.check, rg.text.join(" "))
+            )),
+            _ => Some(Phase::SimAcksReport),
+        }
+    }
+
+    /// Answers to the questions in the user's last over, e.g.
+    /// "AGE HR 54 ES LIC 30 YRS RETIRED NW"; empty when nothing was asked.
+    fn answers(&mut self) -> String {
+        let asked = std::mem::take(&mut self.asked);
+        asked.into_iter()
+            .map(|t| dialogue::answer(&mut self.rng, &self.exchange, t))
+            .collect::<Vec<_>>()
+            .join(" <BT> ")
+    }
+
+    /// Rag-chew styles, where the SIM talks about what the user told it
+    fn conversational(&self) -> bool {
+        matches!(self.style, QsoStyle::Ragchew | QsoStyle::QttAward | QsoStyle::Random)
+    }
+
+    /// The scripted ack ("TU DD6DS UR 579 579 NAME …") addressed to the user
+    /// by name, with remarks on their station and a question for whatever
+    /// they left out: "DD6DS TNX DENNIS <BT> FB ON UR IC7300 <BT> UR 579 …
+    /// HW? PSE UR QTH? <AR>".
+    fn personal_ack(&mut self, ack: &str) -> String {
+        let addressed = ["TU", "THANK YOU"].iter()
+            .map(|t| format!("{t} {}", self.mycall))
+            .find_map(|head| ack.strip_prefix(head.as_str()));
+        let Some(rest) = addressed else { return ack.to_string() };
+        let mut words: Vec<&str> = rest.split_whitespace().collect();
+        let last = words.pop().unwrap_or("<AR>");
+        let mut out = format!("{} {}", self.mycall, self.memory.thanks());
+        if let Some(r) = self.memory.remarks() {
+            out = format!("{out} <BT> {r} <BT>");
+        }
+        out = format!("{out} {}", words.join(" "));
+        if let Some(q) = self.memory.questions() {
+            out = format!("{out} {q}");
+        }
+        format!("{out} {last}")
+    }
+
+    /// Now and then mangle one word of an over, the way a hand on a key
+    /// slips; the mistake is kept for the log.
     fn maybe_typo(&mut self, text: &str) -> String {
-        if self.rng.gen_bool(self.typo_rate) && text.len() > 4 {
-            let words: Vec<&str> = text.split_whitespace().collect();
-            let idx = self.rng.gen_range(0..words.len().max(1));
-            let before: Vec<&str> = words[..=idx].to_vec();
-            let after:  Vec<&str> = words.to_vec();
-            // Insert HH after some word, then continue
-            format!("{} <HH> {}", before.join(" "), after.join(" "))
-        } else {
-            text.to_string()
+        if !self.rng.gen_bool(self.typo_rate) {
+            return text.to_string();
+        }
+        match typo::inject(&mut self.rng, text) {
+            Some((tx, err)) => {
+                log::debug!("SIM sends {:?} for {:?} ({:?})", err.sent, err.intended, err.correction);
+                self.mistakes.push(err);
+                tx
+            }
+            None => text.to_string(),
         }
     }
 
@@ -326,7 +522,79 @@ impl QsoEngine {
         self.next_tx_at = Instant::now() + Duration::from_millis(ms);
     }
 
+    /// The last over again.  Once the operator's patience is used up the
+    /// doubled words go ("NAME HANS HANS" → "NAME HANS").
+    fn repeat_last(&mut self) -> String {
+        self.repeats = self.repeats.saturating_add(1);
+        if self.repeats <= self.personality.patience {
+            return self.last_tx.clone();
+        }
+        let mut words: Vec<&str> = self.last_tx.split_whitespace().collect();
+        words.dedup();
+        words.join(" ")
+    }
+
     pub fn sim_callsign(&self) -> &str { &self.exchange.sim_call }
+
+    /// Speed the SIM operator sends at (before any QRS / QRQ)
+    pub fn sim_wpm(&self) -> u8 { self.sim_wpm }
+
+    /// Keying of the SIM operator
+    pub fn fist(&self) -> Fist { self.personality.keying() }
+
+    /// Band of the QSO ("20m")
+    pub fn band(&self) -> &'static str { self.band }
+
+    /// Frequency of the QSO in kHz
+    pub fn qrg_khz(&self) -> f64 { self.exchange.qrg_khz }
+
+    /// The SIM's signal at the user's receiver
+    pub fn signal(&self) -> Signal { self.exchange.signal }
+
+    /// Everything the SIM station is and sends — for the head-copy quiz
+    pub fn exchange(&self) -> &SimExchange { &self.exchange }
+
+    /// Sending mistakes the SIM made so far in this QSO
+    pub fn mistakes(&self) -> &[SendError] { &self.mistakes }
+
+    /// Log entry for this QSO — call once the engine reports `QsoComplete`.
+    pub fn logged_qso(&self) -> LoggedQso {
+        let ex = &self.exchange;
+        LoggedQso {
+            call:      ex.sim_call.clone(),
+            my_serial: self.my_serial,
+            rcvd:      self.script.report.clone(),
+            sent:      self.my_exchange.clone(),
+            dok:       ex.dok.clone(),
+            spc:       ex.spc.clone(),
+            // When the user activates, the SIM is a hunter and has no reference
+            reference: if self.who_starts == WhoStarts::Me { String::new() }
+                       else { ex.activator_ref.clone() },
+            cq_zone:   ex.cq_zone,
+            member:    match self.style {
+                QsoStyle::NaqccSprint => ex.naqcc_nr.parse::<u32>().is_ok(),
+                QsoStyle::FistsSprint => ex.fists_nr.parse::<u32>().is_ok(),
+                _                     => true,
+            },
+            km:        self.qrb().map(|(km, _)| km),
+            fields:    self.expect.len(),
+            busted:    self.busted.clone(),
+            char_errors: self.char_errors.clone(),
+            checked:   self.checked.clone(),
+            mistakes:  self.mistakes.clone(),
+            repeats:   self.repeats,
+            band:      self.band,
+            qrg_khz:   ex.qrg_khz,
+        }
+    }
+
+    /// Distance (km) and beam heading (degrees) from the user to the sim
+    /// station, or None when the user's locator is not configured.
+    pub fn qrb(&self) -> Option<(f64, f64)> {
+        let km  = locator::distance_km(&self.my_locator, &self.exchange.locator)?;
+        let deg = locator::bearing_deg(&self.my_locator, &self.exchange.locator)?;
+        Some((km, deg))
+    }
     pub fn is_done(&self) -> bool { self.phase == Phase::Done }
 
     /// Returns a plausible auto-response for the current phase.
@@ -337,6 +605,7 @@ impl QsoEngine {
             // SST: just callsign; POTA/TOTA: callsign with DE; SOTA: /P suffix on sim call
             Phase::WaitForMyAnswer => Some(match self.style {
                 QsoStyle::SstContest => format!("{} K", self.mycall),
+                QsoStyle::Radiogram  => format!("{} DE {} QRV K", self.exchange.sim_call, self.mycall),
                 QsoStyle::Sota       => format!("{}/P DE {} K", self.exchange.sim_call, self.mycall),
                 _                    => format!("{} DE {} K", self.exchange.sim_call, self.mycall),
             }),
@@ -356,15 +625,27 @@ impl QsoEngine {
                         // contest_ex already holds "TU <name> <nr>"; append K
                         format!("{} K", self.script.contest_ex.trim())
                     }
+                    QsoStyle::Radiogram => {
+                        // Receiver confirms with QSL + the word count they copied
+                        self.script.contest_ex.clone()
+                    }
+                    QsoStyle::VhfContest | QsoStyle::CqWwContest => {
+                        // RST + serial + locator / RST + zone, no callsign
+                        self.script.contest_ex.clone()
+                    }
+                    QsoStyle::SkccSprint | QsoStyle::NaqccSprint | QsoStyle::FistsSprint => {
+                        // contest_ex already holds the full member exchange incl. BK / K
+                        self.script.contest_ex.clone()
+                    }
                     QsoStyle::MwcContest => {
-                        format!("{sc} UR RST 599 599 001 K")
+                        format!("{sc} UR RST 599 599 {:03} K", self.my_serial)
                     }
                     QsoStyle::WwaContest => {
-                        format!("{sc} DE {} 599 001 001 BK", self.mycall)
+                        format!("{sc} DE {} 599 {ser:03} {ser:03} BK", self.mycall, ser = self.my_serial)
                     }
                     QsoStyle::WpxContest => {
                         // WPX: user sends only RST + their serial (no callsign)
-                        format!("599 001 K")
+                        format!("599 {:03} K", self.my_serial)
                     }
                     QsoStyle::DarcCwContest => {
                         format!("{sc} TU RST 599 DOK {} {} K", self.my_dok, self.my_dok)
@@ -374,16 +655,16 @@ impl QsoEngine {
                                 self.mycall, my_rst = self.my_rst)
                     }
                     QsoStyle::Contest | QsoStyle::DxPileup => {
-                        format!("{sc} UR RST 599 001 K")
+                        format!("{sc} UR RST 599 {:03} K", self.my_serial)
                     }
                     QsoStyle::QttAward => {
                         // QTT: RSN (not RST) + name + QTH + PWR + ANT, end with KN
-                        format!("{sc} DE {} TU RSN 599 NAME OP QTH HOME PWR 100W ANT DIPOLE HW? KN",
-                                self.mycall)
+                        format!("{sc} DE {} TU RSN {} NAME OP QTH HOME PWR 100W ANT DIPOLE HW? KN",
+                                self.mycall, self.my_rst)
                     }
                     QsoStyle::SstContest => {
-                        // SST: greeting + SIM name + user name + user SPC (no RST!)
-                        format!("GE {} OP MA", &self.exchange.sim_name)
+                        // SST: contest_ex holds greeting + SIM name + user name + user SPC (no RST!)
+                        self.script.contest_ex.clone()
                     }
                     QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota => {
                         if self.who_starts == WhoStarts::Me {
@@ -391,12 +672,12 @@ impl QsoEngine {
                             format!("{sc} {} {} K", self.my_rst, self.my_activator_ref)
                         } else {
                             // User is the hunter — just acknowledge RST
-                            format!("TU 599 K")
+                            format!("TU {} K", self.my_rst)
                         }
                     }
                     _ => {
                         // Ragchew
-                        format!("UR RST 579 NAME OP QTH HOME RIG IC7300 ANT DIPOLE K")
+                        format!("UR RST {} NAME OP QTH HOME RIG IC7300 ANT DIPOLE K", self.my_rst)
                     }
                 })
             }
@@ -406,7 +687,7 @@ impl QsoEngine {
                 _                  => "73 K".to_string(),
             }),
             // Rag-chew conversation turn
-            Phase::WaitChatReply => Some("FB OM TNX ES 73 K".to_string()),
+            Phase::WaitChatReply { .. } => Some("FB OM TNX WX HR SUNNY ES 73 K".to_string()),
             _ => None,
         }
     }
@@ -0,0 +1,141 @@
+// src/qso/typo.rs  —  SIM sending mistakes: mangled words, <HH> / EEEEEEE corrections
+//
+// A real operator who slips sends one word wrong — a wrong letter, a dropped
+// dit or dah, or a character one element away (S/H, D/B) — and then either
+// sends the error sign and the word again or carries on without noticing.
+// Every injected mistake is recorded so the user's copy can be scored: a
+// slip left uncorrected counts as caught when the user's later overs carry
+// the intended word, and as copied when they carry the slip.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::morse::encoder::char_to_morse;
+
+/// Characters one element apart — the classic slips of a hand on a key
+static NEARBY: &[(char, char)] = &[
+    ('S', 'H'), ('D', 'B'), ('I', 'S'), ('U', 'V'), ('N', 'D'), ('A', 'W'),
+    ('R', 'L'), ('K', 'C'), ('G', 'Z'), ('M', 'O'), ('E', 'I'), ('T', 'M'),
+    ('W', 'J'), ('5', 'H'), ('6', 'B'), ('4', 'V'), ('9', '0'), ('1', 'J'),
+];
+
+/// What the operator did about the slip
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Correction {
+    /// `<HH>` and the word again
+    ErrorSign,
+    /// A string of dits (EEEEEEE) and the word again
+    Dits,
+    /// Not noticed — the user has to catch it
+    None,
+}
+
+/// One mistake in a SIM over
+#[derive(Debug, Clone)]
+pub struct SendError {
+    /// The word as it should have been sent
+    pub intended:   String,
+    /// The word as it went out
+    pub sent:       String,
+    pub correction: Correction,
+    /// What the user sent after the slip
+    pub reply:      String,
+}
+
+/// What the user made of a slip the SIM left uncorrected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Catch {
+    /// They sent the intended word
+    Caught,
+    /// They sent the slip back
+    Copied,
+    /// Neither word came up in what they sent
+    Unseen,
+}
+
+impl SendError {
+    /// Note an over the user sent after the slip
+    pub fn hear(&mut self, over: &str) {
+        self.reply.push(' ');
+        self.reply.push_str(over);
+    }
+
+    /// How the user dealt with the slip; None when the SIM corrected it
+    pub fn catch(&self) -> Option<Catch> {
+        if self.correction != Correction::None { return None; }
+        let has = |w: &str| self.reply.split_whitespace().any(|r| r == w);
+        Some(if has(&self.intended) { Catch::Caught }
+             else if has(&self.sent) { Catch::Copied }
+             else { Catch::Unseen })
+    }
+}
+
+/// `text` with one word mangled and possibly corrected, and the mistake made.
+/// None when the over has no word worth mangling (only prosigns and short words).
+pub fn inject<R: Rng>(rng: &mut R, text: &str) -> Option<(String, SendError)> {
+    let mut words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+    let candidates: Vec<usize> = (0..words.len())
+        .filter(|&i| !words[i].starts_with('<')
+            && words[i].chars().filter(char::is_ascii_alphanumeric).count() >= 2)
+        .collect();
+    let &idx = candidates.choose(rng)?;
+    let intended = words[idx].clone();
+    let sent = mangle(rng, &intended);
+
+    let correction = *[Correction::ErrorSign, Correction::Dits, Correction::None]
+        .choose_weighted(rng, |c| match c {
+            Correction::ErrorSign => 45,
+            Correction::Dits      => 25,
+            Correction::None      => 30,
+        })
+        .unwrap();
+    words[idx] = match correction {
+        Correction::ErrorSign => format!("{sent} <HH> {intended}"),
+        Correction::Dits      => format!("{sent} EEEEEEE {intended}"),
+        Correction::None      => sent.clone(),
+    };
+    Some((words.join(" "), SendError { intended, sent, correction, reply: String::new() }))
+}
+
+/// `word` with one character wrong
+fn mangle<R: Rng>(rng: &mut R, word: &str) -> String {
+    let mut chars: Vec<char> = word.chars().collect();
+    let positions: Vec<usize> = (0..chars.len())
+        .filter(|&i| chars[i].is_ascii_alphanumeric())
+        .collect();
+    let &pos = positions.choose(rng).expect("inject only picks words with letters or digits");
+    let c = chars[pos];
+    // Try the slips in random order; a wrong letter always works
+    let mut kinds = [0u8, 1, 2];
+    kinds.shuffle(rng);
+    chars[pos] = kinds.iter()
+        .find_map(|k| match k {
+            0 => nearby(rng, c),
+            1 => dropped_element(rng, c),
+            _ => Some(wrong_char(rng, c)),
+        })
+        .unwrap_or(c);
+    chars.into_iter().collect()
+}
+
+/// A character one element away (S → H, B → D)
+fn nearby<R: Rng>(rng: &mut R, c: char) -> Option<char> {
+    let options: Vec<char> = NEARBY.iter()
+        .filter_map(|&(a, b)| if a == c { Some(b) } else if b == c { Some(a) } else { None })
+        .collect();
+    options.choose(rng).copied()
+}
+
+/// The character left when one dit or dah is not sent (H → S, 5 → H)
+fn dropped_element<R: Rng>(rng: &mut R, c: char) -> Option<char> {
+    let code = char_to_morse(c)?;
+    if code.len() < 2 { return None; }
+    let drop = rng.gen_range(0..code.len());
+    let short: String = code.char_indices().filter(|&(i, _)| i != drop).map(|(_, e)| e).collect();
+    ('A'..='Z').chain('0'..='9').find(|&d| char_to_morse(d) == Some(short.as_str()))
+}
+
+/// Any other letter for a letter, any other digit for a digit
+fn wrong_char<R: Rng>(rng: &mut R, c: char) -> char {
+    let pool: Vec<char> = if c.is_ascii_digit() { ('0'..='9').collect() } else { ('A'..='Z').collect() };
+    let others: Vec<char> = pool.into_iter().filter(|&d| d != c).collect();
+    *others.choose(rng).unwrap()
+}
@@ -0,0 +1,178 @@
+// src/qso/validate.rs  —  Check the user's exchange against what the style requires
+//
+// The fields a style's exchange must carry are listed per style; the user's
+// over is split into words and each field claims the first unused word that
+// matches.  Report and number fields read cut numbers, so "5NN TT7" matches
+// "599 007" and "ENN ATT" matches "599 100"; text fields (name, QTH, SPC …)
+// are compared as sent, and a report must look like one (R 2-5, S and T
+// 1-9), so words such as ANN or TEN are never taken for numbers there.
+use crate::config::{AppConfig, QsoStyle, WhoStarts};
+use super::{cty, cutnum, propagation};
+
+/// How a field is compared
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// Any plausible contest report (the 599): 5, 1-9, 1-9
+    Rst,
+    /// A plausible report within two S-units of the signal heard
+    Report,
+    /// Compared by value — 007, 7 and TT7 are all seven
+    Number,
+    /// Compared word for word (name, DOK, SPC, reference, locator)
+    Text,
+}
+
+/// One field of the user's exchange
+#[derive(Debug, Clone)]
+pub struct Field {
+    /// Short name shown in the log ("RST", "NR", "DOK" …)
+    pub label: &'static str,
+    pub value: String,
+    pub kind:  Kind,
+}
+
+impl Field {
+    fn new(label: &'static str, value: impl Into<String>, kind: Kind) -> Self {
+        Self { label, value: value.into().to_uppercase(), kind }
+    }
+
+    fn rst() -> Self { Self::new("RST", "599", Kind::Rst) }
+
+    /// The report for the signal the user hears (`rst` = what it is worth)
+    fn report(rst: &str) -> Self { Self::new("RST", rst, Kind::Report) }
+
+    fn nr(n: u32) -> Self { Self::new("NR", n.to_string(), Kind::Number) }
+
+    /// A club number or power ("1234", "5W"): numeric when it is a number
+    fn nr_or_text(label: &'static str, value: &str) -> Self {
+        let kind = if value.parse::<u32>().is_ok() { Kind::Number } else { Kind::Text };
+        Self::new(label, value, kind)
+    }
+
+    fn matches(&self, word: &str) -> bool {
+        match self.kind {
+            Kind::Rst => rst_s(word).is_some_and(|(r, _)| r == 5),
+            Kind::Report => rst_s(word)
+                .zip(rst_s(&self.value))
+                .is_some_and(|((_, got), (_, want))| got.abs_diff(want) <= 2),
+            Kind::Number => cutnum::expand(word)
+                .and_then(|d| d.parse::<u32>().ok())
+                .is_some_and(|n| self.value.parse::<u32>() == Ok(n)),
+            Kind::Text => word == self.value,
+        }
+    }
+}
+
+/// R and S digits of a plausible report (R 2-5, S 1-9, T 1-9), cut numbers
+/// expanded
+fn rst_s(word: &str) -> Option<(u8, u8)> {
+    let d = cutnum::expand(word)?;
+    let d = d.as_bytes();
+    let ok = d.len() == 3 && (b'2'..=b'5').contains(&d[0])
+        && (b'1'..=b'9').contains(&d[1]) && (b'1'..=b'9').contains(&d[2]);
+    ok.then(|| (d[0] - b'0', d[1] - b'0'))
+}
+
+/// Fields the user has to send in `cfg.qso_style` with serial `my_serial`.
+/// `my_ref` is the user's own reference when they activate; `my_rst` the
+/// report the SIM's signal is worth, checked where reports are honest.
+/// Styles without a fixed exchange (rag-chew, QTT, radiogram) have none.
+pub fn expected(cfg: &AppConfig, my_serial: u32, my_ref: &str, my_rst: &str) -> Vec<Field> {
+    let rst  = || if propagation::honest_reports(cfg.qso_style) {
+        Field::report(my_rst)
+    } else {
+        Field::rst()
+    };
+    let name = || Field::new("NAME", &cfg.cwt_name, Kind::Text);
+    let spc  = || Field::new("SPC", &cfg.my_spc, Kind::Text);
+    let fields = match cfg.qso_style {
+        QsoStyle::Contest | QsoStyle::DxPileup | QsoStyle::MwcContest
+        | QsoStyle::WwaContest | QsoStyle::WpxContest
+            => vec![rst(), Field::nr(my_serial)],
+        QsoStyle::CqWwContest => {
+            let zone = cty::lookup(&cfg.mycall).map_or(14, |i| i.cq_zone);
+            vec![rst(), Field::new("ZONE", zone.to_string(), Kind::Number)]
+        }
+        QsoStyle::DarcCwContest => vec![rst(), Field::new("DOK", &cfg.my_dok, Kind::Text)],
+        QsoStyle::VhfContest => vec![
+            rst(), Field::nr(my_serial), Field::new("LOC", &cfg.my_locator, Kind::Text),
+        ],
+        QsoStyle::CwtContest => vec![name(), Field::nr_or_text("NR", &cfg.cwt_nr)],
+        QsoStyle::SstContest => vec![name(), spc()],
+        QsoStyle::SkccSprint => vec![
+            rst(), spc(), name(), Field::nr_or_text("SKCC", &cfg.my_skcc),
+        ],
+        QsoStyle::NaqccSprint => vec![rst(), spc(), Field::nr_or_text("NAQCC", &cfg.my_naqcc)],
+        QsoStyle::FistsSprint => vec![
+            rst(), spc(), name(), Field::nr_or_text("FISTS", &cfg.my_fists),
+        ],
+        QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota
+            if cfg.who_starts == WhoStarts::Me
+            => vec![rst(), Field::new("REF", my_ref, Kind::Text)],
+        QsoStyle::Pota | QsoStyle::Sota | QsoStyle::Tota | QsoStyle::Cota | QsoStyle::CqDx
+            => vec![rst()],
+        QsoStyle::Ragchew | QsoStyle::QttAward | QsoStyle::Radiogram | QsoStyle::Random
+            => vec![],
+    };
+    // A field the user has not configured (no locator) cannot be checked
+    fields.into_iter().filter(|f| !f.value.is_empty()).collect()
+}
+
+/// Labels of the fields missing from or wrong in `input`, in exchange order.
+/// Each word of the input counts for one field only.
+pub fn check(fields: &[Field], input: &str) -> Vec<&'static str> {
+    let words: Vec<String> = input.split_whitespace().map(str::to_uppercase).collect();
+    let mut used = vec![false; words.len()];
+    let mut bad = vec![];
+    for f in fields {
+        match (0..words.len()).find(|&i| !used[i] && f.matches(&words[i])) {
+            Some(i) => used[i] = true,
+            None    => bad.push(f.label),
+        }
+    }
+    bad
+}
+
+/// Values of the text and number fields — the ones copied character by
+/// character — each with whether it is among the `busted`
+pub fn checked(fields: &[Field], busted: &[&str]) -> Vec<(String, bool)> {
+    fields.iter()
+        .filter(|f| matches!(f.kind, Kind::Text | Kind::Number))
+        .map(|f| (f.value.clone(), busted.contains(&f.label)))
+        .collect()
+}
+
+/// Characters the user got wrong in the `busted` fields of `input`.  Each
+/// busted text or number field is lined up with the most similar word of the
+/// same length (at least half the characters in place); every position that
+/// differs counts against the expected character.  A field with no similar
+/// word was left out rather than miscopied and counts nothing.
+pub fn char_errors(fields: &[Field], busted: &[&str], input: &str) -> Vec<char> {
+    let words: Vec<String> = input.split_whitespace().map(str::to_uppercase).collect();
+    let mut errors = vec![];
+    for f in fields.iter().filter(|f| busted.contains(&f.label)) {
+        let norm = |w: &str| -> Option<String> {
+            match f.kind {
+                Kind::Text   => Some(w.to_string()),
+                Kind::Number => cutnum::expand(w).map(|d| d.trim_start_matches('0').to_string()),
+                Kind::Rst | Kind::Report => None,
+            }
+        };
+        let Some(want) = norm(&f.value) else { continue };
+        let want: Vec<char> = want.chars().collect();
+        let best = words.iter()
+            .filter_map(|w| norm(w))
+            .map(|w| w.chars().collect::<Vec<char>>())
+            .filter(|w| w.len() == want.len())
+            .map(|w| {
+                let same = w.iter().zip(&want).filter(|(a, b)| a == b).count();
+                (same, w)
+            })
+            .filter(|(same, _)| *same * 2 >= want.len() && *same > 0)
+            .max_by_key(|(same, _)| *same);
+        if let Some((_, got)) = best {
+            errors.extend(want.iter().zip(&got).filter(|(a, b)| a != b).map(|(a, _)| *a));
+        }
+    }
+    errors
+}
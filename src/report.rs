@@ -0,0 +1,219 @@
+// src/report.rs  —  Per-QSO and per-session performance report
+//
+// Shown when the program ends and written to
+// <config dir>/reports/<UTC date>-<time>.txt.  Each QSO gets the user's
+// measured sending speed, timing quality, undecodable characters, busted
+// exchange fields, repeat requests, reply latencies and total time; the
+// session block adds totals and whether things got better from the first
+// half of the session to the second.
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::morse::analysis::KeyingSummary;
+use crate::qso::log::LoggedQso;
+use crate::qso::typo::{Catch, SendError};
+
+/// One QSO's measurements
+#[derive(Debug, Clone)]
+pub struct QsoReport {
+    pub call:      String,
+    pub band:      &'static str,
+    /// False when the user quit in the middle of the QSO
+    pub completed: bool,
+    pub keying:    KeyingSummary,
+    /// Exchange fields checked, those busted, and the characters sent wrong
+    pub fields:    usize,
+    pub busted:    Vec<&'static str>,
+    pub char_errors: Vec<char>,
+    /// Text and number field values, and whether each was busted
+    pub checked:   Vec<(String, bool)>,
+    pub repeats:   u8,
+    /// What the user made of each slip the SIM left uncorrected
+    pub slips:     Vec<Catch>,
+    /// Time from the end of each SIM over to the user's first element or key
+    pub latencies: Vec<Duration>,
+    pub duration:  Duration,
+}
+
+impl QsoReport {
+    pub fn new(q: &LoggedQso, completed: bool, keying: KeyingSummary,
+               latencies: Vec<Duration>, duration: Duration) -> Self {
+        Self {
+            call: q.call.clone(),
+            band: q.band,
+            completed,
+            keying,
+            fields: q.fields,
+            busted: q.busted.clone(),
+            char_errors: q.char_errors.clone(),
+            checked: q.checked.clone(),
+            repeats: q.repeats,
+            slips: q.mistakes.iter().filter_map(SendError::catch).collect(),
+            latencies,
+            duration,
+        }
+    }
+
+    pub fn mean_latency(&self) -> Option<f64> {
+        mean(self.latencies.iter().map(Duration::as_secs_f64))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SessionReport {
+    pub qsos: Vec<QsoReport>,
+}
+
+impl SessionReport {
+    pub fn push(&mut self, r: QsoReport) { self.qsos.push(r); }
+    pub fn is_empty(&self) -> bool { self.qsos.is_empty() }
+
+    /// The whole report as text
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "Performance report");
+        for (i, q) in self.qsos.iter().enumerate() {
+            let _ = writeln!(out, "\n  QSO {}  {}  {}{}", i + 1, q.call, q.band,
+                             if q.completed { "" } else { "  (not completed)" });
+            let k = &q.keying;
+            let _ = writeln!(out, "    Sending speed     {}", opt(k.wpm, |w| format!("{w:.1} WPM")));
+            let _ = writeln!(out, "    Dit/dah ratio     {}", opt(k.ratio, |r| format!("1:{r:.2}  (1:3 ideal)")));
+            let _ = writeln!(out, "    Character gaps    {}", opt(k.char_gap, |(m, sd)| format!("{m:.1} ± {sd:.1} units  (3 ideal)")));
+            let _ = writeln!(out, "    Word gaps         {}", opt(k.word_gap, |(m, sd)| format!("{m:.1} ± {sd:.1} units  (7 ideal)")));
+            let _ = writeln!(out, "    Undecodable       {} of {} characters", k.undecodable, k.chars + k.undecodable);
+            let _ = writeln!(out, "    Exchange errors   {}",
+                             if q.busted.is_empty() { "none".to_string() } else { q.busted.join(" ") });
+            let _ = writeln!(out, "    Repeat requests   {}", q.repeats);
+            if !q.slips.is_empty() {
+                let _ = writeln!(out, "    SIM slips caught  {}", slips(&q.slips));
+            }
+            let lat: Vec<String> = q.latencies.iter().map(|d| format!("{:.1}s", d.as_secs_f64())).collect();
+            let _ = writeln!(out, "    Reply latency     {}",
+                             if lat.is_empty() { "—".to_string() } else { lat.join(" ") });
+            let _ = writeln!(out, "    QSO time          {}", clock(q.duration));
+        }
+        if self.qsos.len() > 1 {
+            self.render_session(&mut out);
+        }
+        out
+    }
+
+    fn render_session(&self, out: &mut String) {
+        let qs = &self.qsos;
+        let total: Duration = qs.iter().map(|q| q.duration).sum();
+        let done = qs.iter().filter(|q| q.completed).count();
+        let busted: usize = qs.iter().map(|q| q.busted.len()).sum();
+        let repeats: u32 = qs.iter().map(|q| u32::from(q.repeats)).sum();
+        let undecodable: u32 = qs.iter().map(|q| q.keying.undecodable).sum();
+        let _ = writeln!(out, "\n  Session: {} QSOs ({done} completed) in {}", qs.len(), clock(total));
+        let _ = writeln!(out, "    Exchange errors   {busted}");
+        let _ = writeln!(out, "    Repeat requests   {repeats}");
+        let _ = writeln!(out, "    Undecodable       {undecodable}");
+        let all: Vec<Catch> = qs.iter().flat_map(|q| q.slips.iter().copied()).collect();
+        if !all.is_empty() {
+            let _ = writeln!(out, "    SIM slips caught  {}", slips(&all));
+        }
+        let _ = writeln!(out, "    Average QSO time  {}", clock(total / qs.len() as u32));
+
+        // Trends: first half of the session against the second
+        let (first, second) = qs.split_at(qs.len() / 2);
+        let _ = writeln!(out, "    Trend (first half → second half)");
+        trend(out, "Sending speed", first, second, |q| q.keying.wpm, "WPM", true);
+        trend(out, "Dit/dah off 1:3", first, second, |q| q.keying.ratio.map(|r| (r - 3.0).abs()), "", false);
+        trend(out, "Char gap spread", first, second, |q| q.keying.char_gap.map(|g| g.1), "units", false);
+        trend(out, "Reply latency", first, second, QsoReport::mean_latency, "s", false);
+        trend(out, "Exchange errors", first, second, |q| Some(q.busted.len() as f64), "per QSO", false);
+    }
+
+    /// Write the report under the config directory; returns the file written
+    pub fn write(&self) -> Result<PathBuf> {
+        let dir = crate::config::config_dir().join("reports");
+        std::fs::create_dir_all(&dir).with_context(|| format!("Creating {:?}", dir))?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let (y, mo, d, h, mi, sec) = civil_utc(now);
+        let path = dir.join(format!("{y:04}{mo:02}{d:02}-{h:02}{mi:02}{sec:02}.txt"));
+        std::fs::write(&path, self.render()).with_context(|| format!("Writing {:?}", path))?;
+        Ok(path)
+    }
+}
+
+/// One trend line: the mean of `value` in each half, and whether it improved.
+/// `higher_better` says which way is good.
+fn trend(out: &mut String, label: &str, first: &[QsoReport], second: &[QsoReport],
+         value: impl Fn(&QsoReport) -> Option<f64>, unit: &str, higher_better: bool) {
+    let (Some(a), Some(b)) = (mean(first.iter().filter_map(&value)),
+                              mean(second.iter().filter_map(&value))) else { return };
+    let verdict = if (b - a).abs() < 0.05 * a.abs().max(0.1) { "steady" }
+                  else if (b > a) == higher_better { "better" }
+                  else { "worse" };
+    let _ = writeln!(out, "      {label:<16} {a:.1} → {b:.1} {unit}  ({verdict})");
+}
+
+pub fn mean(v: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, n) = v.fold((0.0, 0u32), |(s, n), x| (s + x, n + 1));
+    (n > 0).then(|| sum / f64::from(n))
+}
+
+/// "1 of 2  (1 not sent back)" — only a slip the user sent back, right or
+/// as copied, shows whether they caught it
+fn slips(v: &[Catch]) -> String {
+    let n = |c: Catch| v.iter().filter(|&&x| x == c).count();
+    let unseen = n(Catch::Unseen);
+    let mut s = format!("{} of {}", n(Catch::Caught), v.len() - unseen);
+    if unseen > 0 { let _ = write!(s, "  ({unseen} not sent back)"); }
+    s
+}
+
+fn opt<T>(v: Option<T>, f: impl Fn(T) -> String) -> String {
+    v.map_or_else(|| "—".to_string(), f)
+}
+
+/// "4:07"
+fn clock(d: Duration) -> String {
+    let s = d.as_secs();
+    format!("{}:{:02}", s / 60, s % 60)
+}
+
+/// Year, month, day, hour, minute, second (UTC) of a Unix time
+pub fn civil_utc(secs: u64) -> (i64, i64, i64, u64, u64, u64) {
+    let (days, rest) = (secs / 86_400, secs % 86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+/// Unix time of "2024-06-15T14:00" (UTC; a space may stand for the T); None
+/// unless it is a real date and time
+pub fn parse_utc(s: &str) -> Option<u64> {
+    let (date, time) = s.trim().trim_end_matches('Z').split_once(['T', ' '])?;
+    let mut d = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (y, mo, day) = (d.next()??, d.next()??, d.next()??);
+    let (h, mi) = time.split_once(':')?;
+    let (h, mi): (u64, u64) = (h.parse().ok()?, mi.parse().ok()?);
+    if !(1970..=9999).contains(&y) || h > 23 || mi > 59 { return None; }
+    // Days since 1970-01-01 from a civil date (Howard Hinnant's algorithm)
+    let yy = y - i64::from(mo <= 2);
+    let era = yy.div_euclid(400);
+    let yoe = yy - era * 400;
+    let doy = (153 * (mo + if mo > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let days = era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468;
+    let secs = u64::try_from(days).ok()? * 86_400 + h * 3600 + mi * 60;
+    // 2024-02-30 comes back as March 1st
+    let (ry, rmo, rd, ..) = civil_utc(secs);
+    ((ry, rmo, rd) == (y, mo, day)).then_some(secs)
+}
+
+/// "2024-06-15T14:00", the form --utc takes
+pub fn utc_stamp(secs: u64) -> String {
+    let (y, mo, d, h, mi, _) = civil_utc(secs);
+    format!("{y:04}-{mo:02}-{d:02}T{h:02}:{mi:02}")
+}
@@ -0,0 +1,189 @@
+// src/schedule.rs  —  Spaced repetition of weak characters and words
+//
+// Every record in the practice history says which characters and words were
+// practised and which went wrong: copy and send practice, the other drills,
+// and the exchanges of QSO sessions.  Replaying the records in order puts
+// each item that ever went wrong into a Leitner box.  A miss sends it back to
+// box 0, due at once; practising it cleanly once it is due moves it up a box
+// and out by 1, 3, 7, 16 and then 35 days, and clean past the last box it
+// counts as learnt.  While an item is due the drills draw it more often —
+// the lower its box, the more — and the QSO generator prefers SIM stations
+// whose call, name and QTH contain it.
+//
+// The schedule is built once at startup.  It is left out with --no-adapt and
+// when a session is replayed (--seed, --exercise), which must come out the
+// same whatever the user's history holds.
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::OnceLock;
+use crate::history::{self, History};
+
+/// Days until an item is due again after a clean pass in box 0, 1, …
+const INTERVAL_DAYS: [u64; 5] = [1, 3, 7, 16, 35];
+const DAY: u64 = 86_400;
+/// Extra weight of a due item in box 0; less in each box above it
+const DUE_WEIGHT: f64 = 3.0;
+/// Stations drawn for the generator to choose from
+const CANDIDATES: usize = 4;
+
+/// Where one weak item stands
+#[derive(Debug, Clone, Copy)]
+pub struct Card {
+    /// Leitner box, 0 = just missed
+    pub level:  usize,
+    /// Unix time it is due again
+    pub due:    u64,
+    /// Times it went wrong
+    pub lapses: u32,
+}
+
+/// Characters (one-character keys) and words (longer keys) that went wrong
+#[derive(Debug, Default)]
+pub struct Schedule {
+    cards: BTreeMap<String, Card>,
+    now:   u64,
+}
+
+/// One practice record, as the schedule sees it
+struct Run<'a> {
+    time:   u64,
+    seen:   &'a BTreeMap<String, u32>,
+    missed: Vec<&'a String>,
+}
+
+impl Schedule {
+    pub fn from_history(h: &History, now: u64) -> Self {
+        let mut runs: Vec<Run> = h.session.iter()
+            .map(|s| Run { time: s.time, seen: &s.seen, missed: s.char_errors.keys().chain(s.word_errors.keys()).collect() })
+            .chain(h.drill.iter()
+                .map(|d| Run { time: d.time, seen: &d.seen, missed: d.char_errors.keys().chain(d.word_errors.keys()).collect() }))
+            .collect();
+        runs.sort_by_key(|r| r.time);
+
+        let mut cards: BTreeMap<String, Card> = BTreeMap::new();
+        for run in &runs {
+            for &item in &run.missed {
+                let c = cards.entry(item.clone()).or_insert(Card { level: 0, due: run.time, lapses: 0 });
+                c.level = 0;
+                c.due = run.time;
+                c.lapses += 1;
+            }
+            for item in run.seen.keys().filter(|i| !run.missed.contains(i)) {
+                let Some(c) = cards.get_mut(item) else { continue };
+                if c.due > run.time { continue; }
+                match INTERVAL_DAYS.get(c.level) {
+                    Some(days) => {
+                        c.due = run.time + days * DAY;
+                        c.level += 1;
+                    }
+                    None => { cards.remove(item); }
+                }
+            }
+        }
+        Self { cards, now }
+    }
+
+    fn due_card(&self, item: &str) -> Option<&Card> {
+        self.cards.get(item).filter(|c| c.due <= self.now)
+    }
+
+    /// How often a character or word should come up against the rest: 1
+    /// unless it is due
+    pub fn weight(&self, item: &str) -> f64 {
+        self.due_card(item).map_or(1.0, |c| {
+            let boxes = INTERVAL_DAYS.len() + 1;
+            1.0 + DUE_WEIGHT * (boxes - c.level) as f64 / boxes as f64
+        })
+    }
+
+    /// How much due material a text holds: 0 for none
+    pub fn weakness(&self, text: &str) -> f64 {
+        let chars = text.chars().map(|c| self.weight(&c.to_string()) - 1.0);
+        let words = text.split_whitespace().map(word).filter(|w| w.len() > 1).map(|w| self.weight(w) - 1.0);
+        chars.chain(words).sum()
+    }
+
+    pub fn any_due(&self) -> bool {
+        self.cards.values().any(|c| c.due <= self.now)
+    }
+
+    /// Due items, lowest box first
+    pub fn due(&self) -> Vec<(&str, &Card)> {
+        let mut v: Vec<(&str, &Card)> = self.cards.iter()
+            .filter(|(_, c)| c.due <= self.now)
+            .map(|(k, c)| (k.as_str(), c))
+            .collect();
+        v.sort_by(|a, b| a.1.level.cmp(&b.1.level).then(b.1.lapses.cmp(&a.1.lapses)));
+        v
+    }
+
+    /// The schedule for --stats
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        if self.cards.is_empty() { return out; }
+        let due = self.due();
+        let list = |words: bool| -> Vec<String> {
+            due.iter().filter(|(k, _)| (k.chars().count() > 1) == words).take(12)
+                .map(|(k, c)| format!("{k} (box {})", c.level)).collect()
+        };
+        let _ = writeln!(out, "\nReview schedule — {} weak, {} due now", self.cards.len(), due.len());
+        let chars = list(false);
+        if !chars.is_empty() { let _ = writeln!(out, "  characters: {}", chars.join("  ")); }
+        let words = list(true);
+        if !words.is_empty() { let _ = writeln!(out, "  words:      {}", words.join("  ")); }
+        if let Some(next) = self.cards.values().filter(|c| c.due > self.now).map(|c| c.due).min() {
+            let days = (next - self.now).div_ceil(DAY);
+            let _ = writeln!(out, "  next due in {days} day{}", if days == 1 { "" } else { "s" });
+        }
+        out
+    }
+}
+
+/// A word as the schedule keeps it: "LOOP." is LOOP
+pub fn word(w: &str) -> &str {
+    w.trim_matches(|c: char| !c.is_ascii_alphanumeric())
+}
+
+// ── The session's schedule ───────────────────────────────────────────────────
+
+static SCHEDULE: OnceLock<Schedule> = OnceLock::new();
+
+/// Build the schedule from the stored history.  Call once at startup; without
+/// it every item weighs the same.
+pub fn init() {
+    match History::load() {
+        Ok(h)  => { let _ = SCHEDULE.set(Schedule::from_history(&h, history::now())); }
+        Err(e) => log::warn!("No review schedule: {e:#}"),
+    }
+}
+
+pub fn get() -> Option<&'static Schedule> {
+    SCHEDULE.get().filter(|s| s.any_due())
+}
+
+/// Weight of a character or word: 1 unless it is due
+pub fn weight(item: &str) -> f64 {
+    get().map_or(1.0, |s| s.weight(item))
+}
+
+/// One of `items`, those holding due material more often; a plain pick
+/// when nothing is due
+pub fn choose<'a, R: Rng, T>(rng: &mut R, items: &'a [T], text: impl Fn(&T) -> String) -> Option<&'a T> {
+    match get() {
+        Some(s) => items.choose_weighted(rng, |i| 1.0 + s.weakness(&text(i))).ok(),
+        None    => items.choose(rng),
+    }
+}
+
+/// Draw a few of something — stations, phrases — and keep the one holding the
+/// most due material, by weight; a single draw when nothing is due
+pub fn favour<R: Rng, T>(rng: &mut R, mut draw: impl FnMut(&mut R) -> T, text: impl Fn(&T) -> String) -> T {
+    let Some(s) = get() else { return draw(rng) };
+    let mut drawn: Vec<T> = (0..CANDIDATES).map(|_| draw(rng)).collect();
+    let weights: Vec<f64> = drawn.iter().map(|t| 1.0 + s.weakness(&text(t))).collect();
+    let i = (0..drawn.len()).collect::<Vec<_>>()
+        .choose_weighted(rng, |&i| weights[i]).copied().unwrap_or(0);
+    drawn.swap_remove(i)
+}
@@ -10,12 +10,21 @@ use ratatui::{
     Terminal,
 };
 use crate::AppState;
+use crate::morse::timeline::{Gap, Timeline, Warning};
+use crate::practice::{Mark, View};
 use std::io::stdout;
 
 // ── Translated UI label set ────────────────────────────────────────────────────
 struct Labels {
     my:               &'static str,
     you:              &'static str,
+    points:           &'static str,
+    score_title:      &'static str,
+    mults:            &'static str,
+    claimed:          &'static str,
+    last_qso:         &'static str,
+    dupe:             &'static str,
+    new_mult:         &'static str,
     sim_tx_title:     &'static str,
     your_input_title: &'static str,
     decoded:          &'static str,
@@ -26,6 +35,26 @@ struct Labels {
     footer_demo:      &'static str,
     footer_text:      &'static str,
     footer_keyer:     &'static str,
+    your_copy_title:  &'static str,
+    footer_practice_text:  &'static str,
+    footer_practice_keyer: &'static str,
+    your_keying_title:     &'static str,
+    footer_practice_send:  &'static str,
+    keying_title:     &'static str,
+    keying_you:       &'static str,
+    keying_ideal:     &'static str,
+    keying_ok:        &'static str,
+    keying_waiting:   &'static str,
+    hist_dit:         &'static str,
+    hist_dah:         &'static str,
+    hist_space:       &'static str,
+    warn_merged:      &'static str,
+    warn_char:        &'static str,
+    warn_word:        &'static str,
+    warn_ratio:       &'static str,
+    warn_run_together: &'static str,
+    units:            &'static str,
+    should_be:        &'static str,
 }
 
 impl Labels {
@@ -34,6 +63,13 @@ impl Labels {
             "de" => Self {
                 my:               "MEIN",
                 you:              "DU",
+                points:           "PKT",
+                score_title:      " PUNKTE ",
+                mults:            "MULT",
+                claimed:          "SUMME",
+                last_qso:         "LETZTES:",
+                dupe:             "DUPE",
+                new_mult:         "NEU",
                 sim_tx_title:     " SIM SENDET ",
                 your_input_title: " DEINE EINGABE ",
                 decoded:          "DEKODIERT:",
@@ -43,11 +79,38 @@ impl Labels {
                 decode_off:       "[ DEKODIERUNG AUS ]",
                 footer_demo:  " DEMO-MODUS — SIM spielt das gesamte QSO automatisch   ESC = Beenden",
                 footer_text:  " Rufzeichen/Austausch tippen   Leerzeichen = Wort   Enter = Over senden (K)   Esc = Beenden",
-                footer_keyer: " Hardware-Keyer aktiv   Q = Beenden   Esc = Beenden",
+                footer_keyer: " Hardware-Keyer aktiv   Tab = Timing-Analyse   Q = Beenden   Esc = Beenden",
+                keying_title:     " TIMING-ANALYSE (Einheiten bei deinem Tempo) ",
+                keying_you:       "Du    ",
+                keying_ideal:     "Ideal ",
+                keying_ok:        "Timing in Ordnung",
+                keying_waiting:   "Gib ein paar Wörter, um dein Timing zu sehen",
+                hist_dit:         "Pkt+P",
+                hist_dah:         "Str+P",
+                hist_space:       "Pause",
+                warn_merged:      "Lücken von 1,5 Einheiten oder mehr in einem Zeichen — Zeichen laufen ineinander",
+                warn_char:        "Zeichenabstand",
+                warn_word:        "Wortabstand",
+                warn_ratio:       "Strich/Punkt-Verhältnis",
+                warn_run_together: "Zeichen laufen ineinander",
+                units:            "Einheiten",
+                should_be:        "soll",
+                your_copy_title:       " DEINE MITSCHRIFT ",
+                footer_practice_text:  " Mitschreiben   Leerzeichen = Gruppe   Enter = fertig / weiter   Esc = Beenden",
+                footer_practice_keyer: " Mitschrift geben   Enter = fertig / weiter   Q = Beenden   Esc = Beenden",
+                your_keying_title:     " DEINE GEBEWEISE ",
+                footer_practice_send:  " Text oben geben   Enter = fertig / weiter   Q = Beenden   Esc = Beenden",
             },
             "fr" => Self {
                 my:               "MOI",
                 you:              "VOUS",
+                points:           "PTS",
+                score_title:      " SCORE ",
+                mults:            "MULT",
+                claimed:          "TOTAL",
+                last_qso:         "DERNIER:",
+                dupe:             "DOUBLON",
+                new_mult:         "NOUVEAU",
                 sim_tx_title:     " SIM TX ",
                 your_input_title: " VOTRE SAISIE ",
                 decoded:          "DÉCODÉ:  ",
@@ -57,11 +120,38 @@ impl Labels {
                 decode_off:       "[ DÉCODAGE DÉSACTIVÉ ]",
                 footer_demo:  " MODE DÉMO — SIM joue le QSO complet automatiquement   ESC = quitter",
                 footer_text:  " Saisir l'indicatif/échange   Espace = mot   Entrée = fin d'over (K)   Esc = quitter",
-                footer_keyer: " Manipulateur actif   Q = quitter   Esc = quitter",
+                footer_keyer: " Manipulateur actif   Tab = analyse du timing   Q = quitter   Esc = quitter",
+                keying_title:     " ANALYSE DU TIMING (unités à votre vitesse) ",
+                keying_you:       "Vous  ",
+                keying_ideal:     "Idéal ",
+                keying_ok:        "Timing correct",
+                keying_waiting:   "Manipulez quelques mots pour voir votre timing",
+                hist_dit:         "ti+esp",
+                hist_dah:         "ta+esp",
+                hist_space:       "espace",
+                warn_merged:      "espaces de 1,5 unité ou plus dans un caractère — les caractères se touchent",
+                warn_char:        "espacement des caractères",
+                warn_word:        "espacement des mots",
+                warn_ratio:       "rapport ta/ti",
+                warn_run_together: "les caractères se touchent",
+                units:            "unités",
+                should_be:        "idéal",
+                your_copy_title:       " VOTRE COPIE ",
+                footer_practice_text:  " Saisir ce que vous entendez   Espace = groupe   Entrée = terminé / suivant   Esc = quitter",
+                footer_practice_keyer: " Manipuler ce que vous entendez   Entrée = terminé / suivant   Q = quitter   Esc = quitter",
+                your_keying_title:     " VOTRE MANIPULATION ",
+                footer_practice_send:  " Manipuler le texte ci-dessus   Entrée = terminé / suivant   Q = quitter   Esc = quitter",
             },
             "it" => Self {
                 my:               "MIO",
                 you:              "TU",
+                points:           "PUNTI",
+                score_title:      " PUNTEGGIO ",
+                mults:            "MOLT",
+                claimed:          "TOTALE",
+                last_qso:         "ULTIMO:",
+                dupe:             "DOPPIO",
+                new_mult:         "NUOVO",
                 sim_tx_title:     " SIM TX ",
                 your_input_title: " TUA IMMISSIONE ",
                 decoded:          "DECODIF: ",
@@ -71,11 +161,38 @@ impl Labels {
                 decode_off:       "[ DECODIFICA DISATTIVATA ]",
                 footer_demo:  " MODALITÀ DEMO — SIM riproduce il QSO automaticamente   ESC = uscita",
                 footer_text:  " Digita nominativo/scambio   Spazio = parola   Invio = fine over (K)   Esc = uscita",
-                footer_keyer: " Manipolatore attivo   Q = uscita   Esc = uscita",
+                footer_keyer: " Manipolatore attivo   Tab = analisi del timing   Q = uscita   Esc = uscita",
+                keying_title:     " ANALISI DEL TIMING (unità alla tua velocità) ",
+                keying_you:       "Tu    ",
+                keying_ideal:     "Ideale",
+                keying_ok:        "Timing corretto",
+                keying_waiting:   "Manipola qualche parola per vedere il tuo timing",
+                hist_dit:         "ti+sp",
+                hist_dah:         "ta+sp",
+                hist_space:       "spazio",
+                warn_merged:      "pause di 1,5 unità o più dentro un carattere — i caratteri si fondono",
+                warn_char:        "spaziatura caratteri",
+                warn_word:        "spaziatura parole",
+                warn_ratio:       "rapporto linea/punto",
+                warn_run_together: "i caratteri si fondono",
+                units:            "unità",
+                should_be:        "ideale",
+                your_copy_title:       " LA TUA COPIA ",
+                footer_practice_text:  " Scrivi ciò che senti   Spazio = gruppo   Invio = fatto / avanti   Esc = uscita",
+                footer_practice_keyer: " Manipola ciò che senti   Invio = fatto / avanti   Q = uscita   Esc = uscita",
+                your_keying_title:     " LA TUA MANIPOLAZIONE ",
+                footer_practice_send:  " Manipola il testo sopra   Invio = fatto / avanti   Q = uscita   Esc = uscita",
             },
             _ => Self {  // English (default)
                 my:               "MY",
                 you:              "YOU",
+                points:           "PTS",
+                score_title:      " SCORE ",
+                mults:            "MULT",
+                claimed:          "SCORE",
+                last_qso:         "LAST:",
+                dupe:             "DUPE",
+                new_mult:         "NEW",
                 sim_tx_title:     " SIM TX ",
                 your_input_title: " YOUR INPUT ",
                 decoded:          "DECODED: ",
@@ -85,7 +202,27 @@ impl Labels {
                 decode_off:       "[ DECODE OFF ]",
                 footer_demo:  " DEMO MODE — SIM plays the full QSO automatically   ESC = exit",
                 footer_text:  " Type callsign/exchange   Space = word   Enter = send over (K)   Esc = quit",
-                footer_keyer: " Hardware keyer active   Q = quit   Esc = quit",
+                footer_keyer: " Hardware keyer active   Tab = keying analysis   Q = quit   Esc = quit",
+                keying_title:     " KEYING ANALYSIS (units at your speed) ",
+                keying_you:       "You   ",
+                keying_ideal:     "Ideal ",
+                keying_ok:        "Timing looks good",
+                keying_waiting:   "Key a few words to see your timing",
+                hist_dit:         "dit+sp",
+                hist_dah:         "dah+sp",
+                hist_space:       "space",
+                warn_merged:      "gaps of 1.5 units or more inside a character — characters running together",
+                warn_char:        "character spacing",
+                warn_word:        "word spacing",
+                warn_ratio:       "dah/dit ratio",
+                warn_run_together: "characters run together",
+                units:            "units",
+                should_be:        "should be",
+                your_copy_title:       " YOUR COPY ",
+                footer_practice_text:  " Type what you hear   Space = group   Enter = done / next   Esc = quit",
+                footer_practice_keyer: " Key what you hear   Enter = done / next   Q = quit   Esc = quit",
+                your_keying_title:     " YOUR KEYING ",
+                footer_practice_send:  " Key the text above   Enter = done / next   Q = quit   Esc = quit",
             },
         }
     }
@@ -115,21 +252,32 @@ impl Tui {
         let lb = &self.labels;
         self.terminal.draw(|f| {
             let area = f.area();
+            let score_h = if s.score.is_some() { 4 } else { 0 };
+            let keying_h = if s.keying.is_some() { 11 } else { 0 };
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(3),  // header / status bar
-                    Constraint::Min(6),     // SIM TX log
-                    Constraint::Min(4),     // YOUR decoded text
-                    Constraint::Length(3),  // footer hints
+                    Constraint::Length(3),        // header / status bar
+                    Constraint::Min(6),           // SIM TX log
+                    Constraint::Length(score_h),  // contest score (scored styles only)
+                    Constraint::Length(keying_h), // keying analysis (Tab)
+                    Constraint::Min(4),           // YOUR decoded text
+                    Constraint::Length(3),        // footer hints
                 ])
                 .split(area);
 
             // ── Header ────────────────────────────────────────────────────
-            let header = Paragraph::new(format!(
+            let mut header_text = format!(
                 " CW QSO Simulator  |  {}: {}  ←→  SIM: {}  |  SIM: {}WPM  {}: {}WPM  {}Hz",
                 lb.my, s.mycall, s.sim_call, s.sim_wpm, lb.you, s.user_wpm, s.tone_hz
-            ))
+            );
+            if !s.qrg.is_empty() {
+                header_text.push_str(&format!("  |  {}", s.qrg));
+            }
+            if let Some((km, deg)) = s.qrb {
+                header_text.push_str(&format!("  |  QRB {km}km {deg:03}°"));
+            }
+            let header = Paragraph::new(header_text)
             .style(Style::default().fg(Color::Black).bg(Color::Cyan)
                    .add_modifier(Modifier::BOLD));
             f.render_widget(header, chunks[0]);
@@ -150,12 +298,57 @@ impl Tui {
             };
             let sim_block = Paragraph::new(sim_text)
                 .block(Block::default()
-                    .title(format!("{}({}) ", lb.sim_tx_title, s.sim_call))
+                    .title(if s.sim_dxcc.is_empty() {
+                        format!("{}({}) ", lb.sim_tx_title, s.sim_call)
+                    } else {
+                        format!("{}({} · {}) ", lb.sim_tx_title, s.sim_call, s.sim_dxcc)
+                    })
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(Color::Green)))
                 .wrap(Wrap { trim: true });
             f.render_widget(sim_block, chunks[1]);
 
+            // ── Score ─────────────────────────────────────────────────────
+            if let Some(sc) = &s.score {
+                let mut line = vec![Span::styled(
+                    format!(" QSO {}   {} {}   {} {}   {} {}",
+                        sc.qsos, lb.points, sc.points, lb.mults, sc.mults, lb.claimed, sc.claimed),
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                )];
+                if let Some((call, pts, new, dupe)) = &sc.last {
+                    let last = if *dupe {
+                        format!("   {} {call} {}", lb.last_qso, lb.dupe)
+                    } else if new.is_empty() {
+                        format!("   {} {call} +{pts}", lb.last_qso)
+                    } else {
+                        format!("   {} {call} +{pts}  {} {}", lb.last_qso, lb.new_mult, new.join(" "))
+                    };
+                    line.push(Span::styled(last, Style::default().fg(Color::Cyan)));
+                }
+                // Newest multiplier first so a narrow terminal clips the old ones
+                let recent: Vec<&str> = sc.mult_list.iter().rev().map(String::as_str).collect();
+                let mults = Line::from(Span::styled(
+                    format!(" {}", recent.join(" ")),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                let score_block = Paragraph::new(vec![Line::from(line), mults])
+                    .block(Block::default()
+                        .title(lb.score_title)
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Blue)));
+                f.render_widget(score_block, chunks[2]);
+            }
+
+            // ── Keying analysis ───────────────────────────────────────────
+            if let Some(t) = &s.keying {
+                let keying_block = Paragraph::new(keying_lines(t, chunks[3].width.saturating_sub(2) as usize, lb))
+                    .block(Block::default()
+                        .title(lb.keying_title)
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan)));
+                f.render_widget(keying_block, chunks[3]);
+            }
+
             // ── User decoded ──────────────────────────────────────────────
             let user_lines: Vec<Line> = if s.no_decode {
                 vec![
@@ -193,7 +386,7 @@ impl Tui {
                     .title(lb.your_input_title)
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(Color::Yellow)));
-            f.render_widget(user_block, chunks[2]);
+            f.render_widget(user_block, chunks[4]);
 
             // ── Footer ────────────────────────────────────────────────────
             let footer_text = if s.demo {
@@ -205,8 +398,190 @@ impl Tui {
             };
             let footer = Paragraph::new(footer_text)
             .style(Style::default().fg(Color::DarkGray).bg(Color::Black));
+            f.render_widget(footer, chunks[5]);
+        })?;
+        Ok(())
+    }
+
+    /// Practice drill screen: header, drill panel, the user's copy, footer
+    pub fn draw_practice(&mut self, v: &View, text_mode: bool) -> Result<()> {
+        let lb = &self.labels;
+        self.terminal.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),   // header
+                    Constraint::Min(6),      // drill panel
+                    Constraint::Length(5),   // your copy
+                    Constraint::Length(3),   // footer hints
+                ])
+                .split(f.area());
+
+            let header = Paragraph::new(v.header.clone())
+                .style(Style::default().fg(Color::Black).bg(Color::Cyan)
+                       .add_modifier(Modifier::BOLD));
+            f.render_widget(header, chunks[0]);
+
+            let lines: Vec<Line> = v.lines.iter()
+                .map(|l| Line::from(l.iter()
+                    .map(|(t, m)| Span::styled(t.clone(), mark_style(*m)))
+                    .collect::<Vec<_>>()))
+                .collect();
+            let drill = Paragraph::new(lines)
+                .block(Block::default()
+                    .title(v.title.clone())
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Green)))
+                .wrap(Wrap { trim: false });
+            f.render_widget(drill, chunks[1]);
+
+            let copy = Paragraph::new(vec![
+                Line::from(Span::styled(v.input.clone(), Style::default().fg(Color::White))),
+                Line::from(vec![
+                    Span::styled(format!("{} ", lb.current), Style::default().fg(Color::DarkGray)),
+                    Span::styled(v.code.clone(), Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(vec![
+                    Span::styled(format!("{} ", lb.status), Style::default().fg(Color::DarkGray)),
+                    Span::styled(v.status.clone(), Style::default().fg(Color::Magenta)),
+                ]),
+            ])
+                .block(Block::default()
+                    .title(if v.sending { lb.your_keying_title } else { lb.your_copy_title })
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)))
+                .wrap(Wrap { trim: false });
+            f.render_widget(copy, chunks[2]);
+
+            let hint = if v.sending { lb.footer_practice_send }
+                       else if text_mode { lb.footer_practice_text }
+                       else { lb.footer_practice_keyer };
+            let footer = Paragraph::new(hint)
+                .style(Style::default().fg(Color::DarkGray).bg(Color::Black));
             f.render_widget(footer, chunks[3]);
         })?;
         Ok(())
     }
 }
+
+// ── Keying analysis panel ────────────────────────────────────────────────────
+
+/// Timeline columns per Morse unit
+const STRIP_COLS: f64 = 2.0;
+/// Histogram columns per unit, and the units shown
+const HIST_COLS:  usize = 4;
+const HIST_UNITS: usize = 12;
+const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Elements keyed before the timing is judged
+const JUDGE_AFTER: usize = 20;
+
+/// Timeline of the newest elements against ideal timing, histograms of
+/// dit, dah and space lengths, and what to fix
+fn keying_lines(t: &Timeline, width: usize, lb: &Labels) -> Vec<Line<'static>> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let els = t.elements();
+    if els.len() < 2 {
+        return vec![Line::from(Span::styled(lb.keying_waiting, dim))];
+    }
+
+    // ── Timeline: the newest elements that fit, as keyed and as PARIS has them
+    let cols = |units: f64| (units * STRIP_COLS).round() as usize;
+    let room = width.saturating_sub(lb.keying_you.chars().count());
+    let (mut used, mut from) = (0, els.len());
+    for (i, e) in els.iter().enumerate().rev() {
+        let w = cols(e.units()) + e.gap.map_or(2, |(_, g)| cols(g));
+        if used + w > room { break; }
+        used += w;
+        from = i;
+    }
+    let mut you   = vec![Span::styled(lb.keying_you, dim)];
+    let mut ideal = vec![Span::styled(lb.keying_ideal, dim)];
+    for e in els.iter().skip(from) {
+        match e.gap {
+            Some((kind, g)) => {
+                let style = if kind.off(g) { Style::default().fg(Color::Red).add_modifier(Modifier::BOLD) } else { dim };
+                you.push(Span::styled("·".repeat(cols(g)), style));
+                ideal.push(Span::styled("·".repeat(cols(kind.ideal())), dim));
+            }
+            None => {
+                you.push(Span::styled("┆ ", dim));
+                ideal.push(Span::styled("┆ ", dim));
+            }
+        }
+        let mark = "█".repeat(cols(e.units()));
+        you.push(Span::styled(mark.clone(), Style::default().fg(Color::White)));
+        ideal.push(Span::styled(mark, Style::default().fg(Color::Green)));
+    }
+    let mut lines = vec![Line::from(you), Line::from(ideal), Line::from("")];
+
+    // ── Histograms, with the textbook lengths in green
+    let (dits, dahs) = t.periods();
+    let mut spaces = t.gaps(Gap::Char);
+    spaces.extend(t.gaps(Gap::Word));
+    let rows: [(&str, Vec<f64>, &[f64]); 3] = [
+        (lb.hist_dit, dits, &[2.0]),
+        (lb.hist_dah, dahs, &[4.0]),
+        (lb.hist_space, spaces, &[3.0, 7.0]),
+    ];
+    let bins = HIST_UNITS * HIST_COLS;
+    for (label, values, ideals) in rows {
+        let mut counts = vec![0usize; bins];
+        for v in &values {
+            counts[((v * HIST_COLS as f64) as usize).min(bins - 1)] += 1;
+        }
+        let max = counts.iter().copied().max().unwrap_or(0).max(1);
+        let mut row = vec![Span::styled(format!("{label:<6} "), dim)];
+        for (i, &n) in counts.iter().enumerate() {
+            let centre = (i as f64 + 0.5) / HIST_COLS as f64;
+            let good = ideals.iter().any(|&u| (centre - u).abs() <= 0.5);
+            let bar = BARS[(n * 8).div_ceil(max)];
+            row.push(Span::styled(bar.to_string(), Style::default().fg(if good { Color::Green } else { Color::Yellow })));
+        }
+        if !values.is_empty() {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            row.push(Span::styled(format!("  ø {mean:.1}  n {}", values.len()), dim));
+        }
+        lines.push(Line::from(row));
+    }
+    let axis: String = (0..HIST_UNITS).map(|u| format!("{u:<width$}", width = HIST_COLS)).collect();
+    lines.push(Line::from(Span::styled(format!("       {axis}"), dim)));
+
+    // ── What to fix
+    let warnings = t.warnings();
+    if els.len() < JUDGE_AFTER {
+        lines.push(Line::from(Span::styled(lb.keying_waiting, dim)));
+    } else if warnings.is_empty() {
+        lines.push(Line::from(Span::styled(format!("✓ {}", lb.keying_ok), Style::default().fg(Color::Green))));
+    } else {
+        for w in warnings.iter().take(2) {
+            lines.push(Line::from(Span::styled(format!("⚠ {}", warning_text(*w, lb)), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))));
+        }
+    }
+    lines
+}
+
+/// A timing warning in the user's language
+fn warning_text(w: Warning, lb: &Labels) -> String {
+    match w {
+        Warning::Merged(n) => format!("{n} {}", lb.warn_merged),
+        Warning::CharSpacing { units, tight } => {
+            let tail = if tight { format!(" — {}", lb.warn_run_together) } else { String::new() };
+            format!("{} {units:.1} {} ({} 3){tail}", lb.warn_char, lb.units, lb.should_be)
+        }
+        Warning::WordSpacing(m) => format!("{} {m:.1} {} ({} 7)", lb.warn_word, lb.units, lb.should_be),
+        Warning::Ratio(r)       => format!("{} {r:.1} ({} 3)", lb.warn_ratio, lb.should_be),
+    }
+}
+
+fn mark_style(m: Mark) -> Style {
+    match m {
+        Mark::Plain  => Style::default().fg(Color::White),
+        Mark::Dim    => Style::default().fg(Color::DarkGray),
+        Mark::Info   => Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Mark::Good   => Style::default().fg(Color::Green),
+        Mark::Bad    => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Mark::Missed => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        Mark::Extra  => Style::default().fg(Color::Magenta),
+        Mark::Spacing => Style::default().fg(Color::LightBlue).add_modifier(Modifier::BOLD),
+    }
+}
@@ -10,5 +10,6 @@ pub struct Tui;
 impl Tui {
     pub fn new(_lang: &str) -> anyhow::Result<Self> { Ok(Self) }
     pub fn draw(&mut self, _state: &crate::AppState) -> anyhow::Result<()> { Ok(()) }
+    pub fn draw_practice(&mut self, _view: &crate::practice::View, _text_mode: bool) -> anyhow::Result<()> { Ok(()) }
     pub fn cleanup(&mut self) {}
 }